@@ -0,0 +1,111 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Counts the heap allocations performed by `Selection` edits.
+//!
+//! Run with `cargo bench --bench allocations`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use normalize_interval::Interval;
+use normalize_interval::Selection;
+
+// Standard library imports.
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CountingAllocator
+////////////////////////////////////////////////////////////////////////////////
+/// A `GlobalAlloc` which counts calls to `alloc` and `realloc`.
+struct CountingAllocator;
+
+/// The number of allocations performed so far.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize)
+        -> *mut u8
+    {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of edits performed by each benchmark.
+const ITERATIONS: usize = 10_000;
+
+/// Returns a `Selection` of `count` disjoint intervals.
+fn disjoint_selection(count: i32) -> Selection<i32> {
+    (0..count).map(|i| Interval::closed(i * 10, i * 10 + 5)).collect()
+}
+
+/// Returns the average number of allocations performed by `edit`.
+fn allocations_per_edit<F>(mut edit: F) -> f64
+    where F: FnMut(usize)
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for i in 0..ITERATIONS {
+        edit(i);
+    }
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    #[allow(clippy::cast_precision_loss)]
+    let average = (after - before) as f64 / ITERATIONS as f64;
+    average
+}
+
+/// Reports the allocations performed by repeated `union_in_place` and
+/// `minus_in_place` calls on a `Selection` of `count` intervals.
+fn report(count: i32) {
+    let mut selection = disjoint_selection(count);
+    let point = Interval::point(count * 5 + 7);
+    let interval = Interval::closed(count * 5 + 6, count * 5 + 8);
+
+    let average = allocations_per_edit(|i| if i % 2 == 0 {
+        selection.union_in_place(point);
+    } else {
+        selection.minus_in_place(point);
+    });
+    println!("{count:>4} intervals, point edit:    {average:.2} allocations");
+
+    let average = allocations_per_edit(|i| if i % 2 == 0 {
+        selection.union_in_place(interval);
+    } else {
+        selection.minus_in_place(interval);
+    });
+    println!("{count:>4} intervals, interval edit: {average:.2} allocations");
+}
+
+fn main() {
+    for count in [4, 16, 256] {
+        report(count);
+    }
+}
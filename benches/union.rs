@@ -0,0 +1,79 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Compares unions of many `Selection`s by a k-way merge against successive
+//! pairwise unions.
+//!
+//! Run with `cargo bench --bench union`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use normalize_interval::Interval;
+use normalize_interval::Selection;
+
+// Standard library imports.
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Benchmarks
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of times each union is computed.
+const ITERATIONS: u32 = 20;
+
+/// The number of minutes in a day.
+const DAY: i32 = 24 * 60;
+
+/// Returns a `Selection` of busy minutes for each of `count` days, each with
+/// a few intervals which may run into the next day.
+fn daily_selections(count: i32) -> Vec<Selection<i32>> {
+    (0..count)
+        .map(|day| (0..8)
+            .map(|slot| {
+                let start = day * DAY + slot * 180 + (day * 37 + slot) % 60;
+                Interval::closed(start, start + 120 + (day * 11) % 90)
+            })
+            .collect())
+        .collect()
+}
+
+/// Returns the average time taken by `union`.
+fn time_per_union<F>(mut union: F) -> Duration
+    where F: FnMut() -> Selection<i32>
+{
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let _ = black_box(union());
+    }
+    start.elapsed() / ITERATIONS
+}
+
+/// Reports the time taken to union `count` daily `Selection`s.
+fn report(count: i32) {
+    let selections = daily_selections(count);
+
+    let pairwise = time_per_union(|| selections
+        .iter()
+        .fold(Selection::new(), |acc, sel| acc.union(sel)));
+    let merged = time_per_union(||
+        Selection::union_from_sorted_selections(&selections));
+
+    println!("{count:>5} selections, pairwise: {pairwise:>12?}   \
+        merged: {merged:>12?}");
+}
+
+fn main() {
+    for count in [10, 100, 1000] {
+        report(count);
+    }
+}
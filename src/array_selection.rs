@@ -0,0 +1,472 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a fixed-capacity selection type which stores its `Interval`s
+//! inline, for use where allocation is unavailable.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::slice;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ArraySelection
+////////////////////////////////////////////////////////////////////////////////
+/// A selection of [`Finite`] points stored as at most `N` disjoint
+/// `Interval`s held inline, without allocation.
+///
+/// Operations which would need more than `N` `Interval`s either fail without
+/// modifying the selection, or saturate by merging the two `Interval`s
+/// separated by the narrowest gap. Saturating operations never remove
+/// selected points, but may select the points of the merged gap.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::array_selection::ArraySelection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut reserved: ArraySelection<u32, 2> = ArraySelection::new();
+/// reserved.try_union_in_place(Interval::closed(0x0000, 0x0fff))?;
+/// reserved.try_union_in_place(Interval::closed(0x8000, 0x8fff))?;
+///
+/// assert!(reserved.contains(&0x8010));
+/// assert!(reserved
+///     .try_union_in_place(Interval::closed(0x4000, 0x40ff))
+///     .is_err());
+///
+/// reserved.union_in_place_saturating(Interval::closed(0x9000, 0x90ff));
+/// assert_eq!(reserved.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(0x0000, 0x0fff),
+///     Interval::closed(0x8000, 0x90ff),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ArraySelection<T, const N: usize> {
+    /// The lower and upper points of each `Interval`, in ascending order. Only
+    /// the first `len` are used, and no two are adjacent.
+    bounds: [(T, T); N],
+    /// The number of `Interval`s.
+    len: usize,
+}
+
+impl<T, const N: usize> ArraySelection<T, N> where T: Finite + Ord + Clone {
+    /// Constructs a new empty `ArraySelection`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bounds: [(); N].map(|()| (T::MINIMUM, T::MINIMUM)),
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of `Interval`s which can be stored.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of `Interval`s stored.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no points are selected.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the given point is selected.
+    #[must_use]
+    pub fn contains(&self, point: &T) -> bool {
+        let idx = self.used().partition_point(|(_, u)| u < point);
+        self.used().get(idx).is_some_and(|(l, _)| l <= point)
+    }
+
+    /// Returns an iterator over the `Interval`s in ascending order.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(self.used().iter())
+    }
+
+    /// Returns the selected points as a `Selection`.
+    #[must_use]
+    pub fn to_selection(&self) -> Selection<T> {
+        self.interval_iter().collect()
+    }
+
+    /// Removes all points from the selection.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the selection to only those points in the given `Interval`.
+    /// This never needs more `Interval`s, so it cannot fail.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        let Some((a, b)) = points(interval) else { return self.clear() };
+        let start = self.used().partition_point(|(_, u)| *u < a);
+        let end = self.used().partition_point(|(l, _)| *l <= b);
+        if start < end {
+            let first = &mut self.bounds[start].0;
+            if *first < a { *first = a; }
+            let last = &mut self.bounds[end - 1].1;
+            if *last > b { *last = b; }
+        }
+        self.remove_range(end, self.len);
+        self.remove_range(0, start);
+    }
+
+    /// Adds all points in the given `Interval` to the selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] without modifying the selection if the
+    /// result would need more than `N` `Interval`s.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    pub fn try_union_in_place(&mut self, interval: Interval<T>)
+        -> Result<(), CapacityError>
+    {
+        let Some((a, b)) = points(interval) else { return Ok(()) };
+        let (start, end) = self.touching(&a, &b);
+        if start == end && self.len == N { return Err(CapacityError); }
+        self.union_range(start, end, a, b);
+        Ok(())
+    }
+
+    /// Removes all points in the given `Interval` from the selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] without modifying the selection if the
+    /// result would need more than `N` `Interval`s.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    pub fn try_minus_in_place(&mut self, interval: Interval<T>)
+        -> Result<(), CapacityError>
+    {
+        let Some((a, b)) = points(interval) else { return Ok(()) };
+        if self.splits(&a, &b).is_some() && self.len == N {
+            return Err(CapacityError);
+        }
+        self.minus_points(&a, &b);
+        Ok(())
+    }
+
+    /// Returns the lower and upper points of the used `Interval`s.
+    fn used(&self) -> &[(T, T)] {
+        &self.bounds[..self.len]
+    }
+
+    /// Returns the index range of the `Interval`s overlapping or adjacent to
+    /// the points from `a` to `b`.
+    fn touching(&self, a: &T, b: &T) -> (usize, usize) {
+        let start = self.used()
+            .partition_point(|(_, u)| u < a && u.succ().as_ref() != Some(a));
+        let end = self.used()
+            .partition_point(|(l, _)| l <= b || b.succ().as_ref() == Some(l));
+        (start, end)
+    }
+
+    /// Returns the index of the `Interval` which removing the points from `a`
+    /// to `b` would split in two, if any.
+    fn splits(&self, a: &T, b: &T) -> Option<usize> {
+        let idx = self.used().partition_point(|(_, u)| u < a);
+        self.used()
+            .get(idx)
+            .filter(|(l, u)| l < a && u > b)
+            .map(|_| idx)
+    }
+
+    /// Replaces the `Interval`s in the given index range, which must all
+    /// touch the points from `a` to `b`, with their union with those points.
+    /// Requires a free slot if the range is empty.
+    fn union_range(&mut self, start: usize, end: usize, a: T, b: T) {
+        if start == end {
+            self.bounds[start..=self.len].rotate_right(1);
+            self.bounds[start] = (a, b);
+            self.len += 1;
+            return;
+        }
+        let (first, last) = (&self.bounds[start].0, &self.bounds[end - 1].1);
+        let lower = if a < *first { a } else { first.clone() };
+        let upper = if b > *last { b } else { last.clone() };
+        self.bounds[start] = (lower, upper);
+        self.remove_range(start + 1, end);
+    }
+
+    /// Removes the points from `a` to `b`. Requires a free slot if this
+    /// splits an `Interval`.
+    fn minus_points(&mut self, a: &T, b: &T) {
+        if let Some(idx) = self.splits(a, b) {
+            let upper = self.bounds[idx].1.clone();
+            self.bounds[idx].1 = a.pred().expect("point below split");
+            let lower = b.succ().expect("point above split");
+            self.bounds[idx + 1..=self.len].rotate_right(1);
+            self.bounds[idx + 1] = (lower, upper);
+            self.len += 1;
+            return;
+        }
+        let mut start = self.used().partition_point(|(_, u)| u < a);
+        let mut end = self.used().partition_point(|(l, _)| l <= b);
+        if start < end && self.bounds[start].0 < *a {
+            self.bounds[start].1 = a.pred().expect("point below overlap");
+            start += 1;
+        }
+        if start < end && self.bounds[end - 1].1 > *b {
+            self.bounds[end - 1].0 = b.succ().expect("point above overlap");
+            end -= 1;
+        }
+        self.remove_range(start, end);
+    }
+
+    /// Removes the `Interval`s in the given index range.
+    fn remove_range(&mut self, start: usize, end: usize) {
+        if start >= end { return; }
+        self.bounds[start..self.len].rotate_left(end - start);
+        self.truncate(self.len - (end - start));
+    }
+
+    /// Removes the `Interval`s after the first `len`, resetting their slots.
+    fn truncate(&mut self, len: usize) {
+        for slot in &mut self.bounds[len..self.len] {
+            *slot = (T::MINIMUM, T::MINIMUM);
+        }
+        self.len = len;
+    }
+}
+
+impl<T, const N: usize> ArraySelection<T, N>
+    where
+        T: Finite + Ord + Clone + Measurable,
+        T::Length: PartialOrd,
+{
+    /// Adds all points in the given `Interval` to the selection. If the
+    /// result would need more than `N` `Interval`s, the narrowest gap is
+    /// selected instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero and the `Interval` is not empty.
+    pub fn union_in_place_saturating(&mut self, interval: Interval<T>) {
+        let Some((a, b)) = points(interval) else { return };
+        let (start, end) = self.touching(&a, &b);
+        if start == end && self.len == N {
+            assert!(N > 0, "union with a zero-capacity ArraySelection");
+            let below = start.checked_sub(1)
+                .map(|idx| T::span(&self.bounds[idx].1, &a));
+            let above = self.used()
+                .get(start)
+                .map(|(l, _)| T::span(&b, l));
+            let narrowest = self.narrowest_gap();
+
+            // Merge into a neighbor if its gap is the narrowest.
+            let gap = narrowest.as_ref().map(|(_, gap)| gap);
+            if below.as_ref().is_some_and(|below|
+                gap.is_none_or(|gap| below <= gap) &&
+                above.as_ref().is_none_or(|above| below <= above))
+            {
+                self.bounds[start - 1].1 = b;
+                return;
+            }
+            if above.as_ref().is_some_and(|above|
+                gap.is_none_or(|gap| above <= gap))
+            {
+                self.bounds[start].0 = a;
+                return;
+            }
+            let (idx, _) = narrowest.expect("full selection has a gap");
+            self.merge_gap(idx);
+            let (start, end) = self.touching(&a, &b);
+            self.union_range(start, end, a, b);
+            return;
+        }
+        self.union_range(start, end, a, b);
+    }
+
+    /// Removes all points in the given `Interval` from the selection. If the
+    /// result would need more than `N` `Interval`s, the narrowest gap is
+    /// selected instead.
+    pub fn minus_in_place_saturating(&mut self, interval: Interval<T>) {
+        let Some((a, b)) = points(interval) else { return };
+        if self.splits(&a, &b).is_some() && self.len == N {
+            match self.narrowest_gap() {
+                Some((idx, gap)) if gap < split_gap(&a, &b) => {
+                    self.merge_gap(idx);
+                },
+                _ => return,
+            }
+        }
+        self.minus_points(&a, &b);
+    }
+
+    /// Returns the index of the `Interval` before the narrowest gap, and the
+    /// width of the gap.
+    fn narrowest_gap(&self) -> Option<(usize, T::Length)> {
+        let mut narrowest: Option<(usize, T::Length)> = None;
+        for (idx, pair) in self.used().windows(2).enumerate() {
+            let gap = T::span(&pair[0].1, &pair[1].0);
+            if narrowest.as_ref().is_none_or(|(_, least)| gap < *least) {
+                narrowest = Some((idx, gap));
+            }
+        }
+        narrowest
+    }
+
+    /// Merges the `Interval` at the given index with the one after it.
+    fn merge_gap(&mut self, idx: usize) {
+        self.bounds[idx].1 = self.bounds[idx + 1].1.clone();
+        self.remove_range(idx + 1, idx + 2);
+    }
+}
+
+/// Returns the lower and upper points of a normalized `Finite` `Interval`, or
+/// `None` if it is empty.
+fn points<T>(interval: Interval<T>) -> Option<(T, T)> where T: Clone {
+    match interval.0 {
+        RawInterval::Point(p)     => Some((p.clone(), p)),
+        RawInterval::Closed(l, u) => Some((l, u)),
+        _                         => None,
+    }
+}
+
+/// Returns the width of the gap left by splitting an `Interval` to remove the
+/// points from `a` to `b`.
+fn split_gap<T>(a: &T, b: &T) -> T::Length where T: Finite + Measurable {
+    T::span(
+        &a.pred().expect("point below split"),
+        &b.succ().expect("point above split"))
+}
+
+impl<T, const N: usize> Default for ArraySelection<T, N>
+    where T: Finite + Ord + Clone
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> PartialEq for ArraySelection<T, N>
+    where T: Finite + Ord + Clone
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.used() == other.used()
+    }
+}
+
+impl<T, const N: usize> Eq for ArraySelection<T, N>
+    where T: Finite + Ord + Clone
+{}
+
+impl<T, const N: usize> fmt::Debug for ArraySelection<T, N>
+    where T: Finite + Ord + Clone + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.interval_iter()).finish()
+    }
+}
+
+impl<T, const N: usize> TryFrom<&Selection<T>> for ArraySelection<T, N>
+    where T: Finite + Ord + Clone
+{
+    type Error = CapacityError;
+
+    fn try_from(selection: &Selection<T>) -> Result<Self, Self::Error> {
+        let mut array = Self::new();
+        for interval in selection.interval_iter() {
+            array.try_union_in_place(interval)?;
+        }
+        Ok(array)
+    }
+}
+
+impl<T, const N: usize> From<&ArraySelection<T, N>> for Selection<T>
+    where T: Finite + Ord + Clone
+{
+    fn from(array: &ArraySelection<T, N>) -> Self {
+        array.to_selection()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of an `ArraySelection` in ascending
+/// order.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'a, T>(slice::Iter<'a, (T, T)>);
+
+impl<T> Iterator for IntervalIter<'_, T> where T: Finite + Ord + Clone {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(l, u)| Interval::closed(l.clone(), u.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntervalIter<'_, T>
+    where T: Finite + Ord + Clone
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(l, u)| Interval::closed(l.clone(), u.clone()))
+    }
+}
+
+impl<T> ExactSizeIterator for IntervalIter<'_, T>
+    where T: Finite + Ord + Clone
+{}
+
+impl<T> FusedIterator for IntervalIter<'_, T> where T: Finite + Ord + Clone {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CapacityError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when an [`ArraySelection`] operation would need more
+/// `Interval`s than it can store.
+///
+/// [`ArraySelection`]: struct.ArraySelection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "selection capacity exceeded")
+    }
+}
+
+impl std::error::Error for CapacityError {}
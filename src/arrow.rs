@@ -0,0 +1,231 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides conversions between `Selection`s and columnar [Arrow] arrays of
+//! `Interval` endpoints. Requires the `arrow` feature.
+//!
+//! A `Selection` is exported as a [`SpanArrays`], holding one row per
+//! `Interval`: a start and end point, and whether each endpoint is included.
+//! The arrays can be placed directly into a record batch for use by other
+//! Arrow-based tools.
+//!
+//! Exported `Interval`s are always closed and bounded, since the points are
+//! [`Finite`]. Imported rows may also have excluded endpoints, and a null
+//! start or end denotes an unbounded `Interval`, so that rows produced by
+//! other tools, such as half-open `[start, end)` ranges, can be read without
+//! conversion.
+//!
+//! [Arrow]: https://arrow.apache.org/
+//! [`SpanArrays`]: struct.SpanArrays.html
+//! [`Finite`]: ../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use arrow_array::Array;
+use arrow_array::ArrowPrimitiveType;
+use arrow_array::BooleanArray;
+use arrow_array::PrimitiveArray;
+use arrow_array::types::Int64Type;
+use arrow_array::types::UInt64Type;
+
+// Standard library imports.
+use std::fmt;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ArrowPoint
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the Arrow primitive type used to store points.
+pub trait ArrowPoint: Finite + Ord + Clone + Send + Sync {
+    /// The Arrow primitive type with points as its native type.
+    type ArrowType: ArrowPrimitiveType<Native=Self>;
+}
+
+impl ArrowPoint for i64 {
+    type ArrowType = Int64Type;
+}
+
+impl ArrowPoint for u64 {
+    type ArrowType = UInt64Type;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SpanArrays
+////////////////////////////////////////////////////////////////////////////////
+/// The `Interval`s of a `Selection`, stored as columnar Arrow arrays of their
+/// endpoints.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::arrow::SpanArrays;
+/// # use arrow_array::BooleanArray;
+/// # use arrow_array::Int64Array;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<i64> = Selection::from(Interval::closed(10, 20));
+/// sel.union_in_place(Interval::closed(40, 50));
+///
+/// let arrays = SpanArrays::from_selection(&sel);
+/// assert_eq!(arrays.starts, Int64Array::from(vec![10, 40]));
+/// assert_eq!(arrays.ends, Int64Array::from(vec![20, 50]));
+/// assert_eq!(arrays.to_selection()?, sel);
+///
+/// // Half-open rows from another tool, with an unbounded end.
+/// let rows: SpanArrays<i64> = SpanArrays {
+///     starts: Int64Array::from(vec![Some(0), Some(100)]),
+///     ends: Int64Array::from(vec![Some(5), None]),
+///     start_closed: BooleanArray::from(vec![true, true]),
+///     end_closed: BooleanArray::from(vec![false, false]),
+/// };
+/// let imported = rows.to_selection()?;
+/// assert!(imported.contains(&4));
+/// assert!(!imported.contains(&5));
+/// assert!(imported.contains(&i64::MAX));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanArrays<T> where T: ArrowPoint {
+    /// The start point of each `Interval`, or null if it is unbounded below.
+    pub starts: PrimitiveArray<T::ArrowType>,
+    /// The end point of each `Interval`, or null if it is unbounded above.
+    pub ends: PrimitiveArray<T::ArrowType>,
+    /// Whether each start point is included. Null is treated as included.
+    pub start_closed: BooleanArray,
+    /// Whether each end point is included. Null is treated as included.
+    pub end_closed: BooleanArray,
+}
+
+impl<T> SpanArrays<T>
+    where
+        T: ArrowPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Exports the `Interval`s of the given `Selection` in ascending order,
+    /// as closed `Interval`s with no null entries.
+    #[must_use]
+    pub fn from_selection(selection: &Selection<T>) -> Self {
+        let mut bounds: Vec<(T, T)> = Vec::new();
+        for interval in selection.interval_iter() {
+            let (Some(lower), Some(upper)) = (
+                interval.infimum(),
+                interval.supremum()) else { continue };
+            match bounds.last_mut() {
+                // Adjacent `Interval`s are merged so that each row is a
+                // maximal `Interval`.
+                Some((_, last)) if last.succ().as_ref() == Some(&lower) => {
+                    *last = upper;
+                },
+                _ => bounds.push((lower, upper)),
+            }
+        }
+
+        let (starts, ends): (Vec<T>, Vec<T>) = bounds.into_iter().unzip();
+        let len = starts.len();
+        Self {
+            starts: PrimitiveArray::from_iter_values(starts),
+            ends: PrimitiveArray::from_iter_values(ends),
+            start_closed: BooleanArray::from(vec![true; len]),
+            end_closed: BooleanArray::from(vec![true; len]),
+        }
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Returns the `Interval` described by the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds for any of the arrays.
+    #[must_use]
+    pub fn interval(&self, row: usize) -> Interval<T> {
+        let start_closed = self.start_closed.is_null(row)
+            || self.start_closed.value(row);
+        let end_closed = self.end_closed.is_null(row)
+            || self.end_closed.value(row);
+        let lower = endpoint(&self.starts, row, start_closed);
+        let upper = endpoint(&self.ends, row, end_closed);
+        Interval::new(lower, upper)
+    }
+
+    /// Imports the `Selection` of points in any of the rows. The rows may be
+    /// in any order, and may overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SpanArraysError`] if the arrays have different lengths.
+    ///
+    /// [`SpanArraysError`]: struct.SpanArraysError.html
+    pub fn to_selection(&self) -> Result<Selection<T>, SpanArraysError> {
+        let len = self.len();
+        if self.ends.len() != len
+            || self.start_closed.len() != len
+            || self.end_closed.len() != len
+        {
+            return Err(SpanArraysError);
+        }
+        Ok((0..len).map(|row| self.interval(row)).collect())
+    }
+}
+
+/// Returns the `Bound` stored at the given row of an endpoint array.
+fn endpoint<T>(array: &PrimitiveArray<T::ArrowType>, row: usize, closed: bool)
+    -> Bound<T>
+    where T: ArrowPoint
+{
+    if array.is_null(row) {
+        Bound::Infinite
+    } else if closed {
+        Bound::Include(array.value(row))
+    } else {
+        Bound::Exclude(array.value(row))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SpanArraysError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned by failure to import a `Selection` from `SpanArrays`
+/// whose arrays have different lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpanArraysError;
+
+impl fmt::Display for SpanArraysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "span arrays have different lengths")
+    }
+}
+
+impl std::error::Error for SpanArraysError {}
@@ -0,0 +1,137 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides `Interval`s and `Selection`s of arbitrary precision [`BigInt`]s
+//! and exact [`Decimal`]s. Requires the `num` feature.
+//!
+//! Neither type has a [`Finite`] set of points, so their intervals are
+//! already normalized, and keep their open bounds as given. This makes them
+//! suitable for bucketing exact amounts into ranges such as `[0.00,10.00)`.
+//! As with [`Dense`] points, `(3,4)` is not empty, even for `BigInt`s.
+//!
+//! The [`Measurable`] span between two points is their difference, computed
+//! from borrowed points so that neither point is cloned. `Decimal` spans
+//! which exceed its range saturate at `Decimal::MAX`.
+//!
+//! [`BigInt`]: https://docs.rs/num-bigint/0.4/num_bigint/struct.BigInt.html
+//! [`Decimal`]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+//! [`Finite`]: ../normalize/trait.Finite.html
+//! [`Dense`]: ../normalize/struct.Dense.html
+//! [`Measurable`]: ../measure/trait.Measurable.html
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use normalize_interval::Interval;
+//! # use normalize_interval::Selection;
+//! # use rust_decimal::Decimal;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! let small = Interval::right_open(
+//!     Decimal::new(0, 2),
+//!     Decimal::new(1000, 2));
+//! let large = Interval::unbounded_from(Decimal::new(1000, 2));
+//!
+//! let amount = Decimal::new(999, 2);
+//! assert!(small.contains(&amount));
+//! assert!(!large.contains(&amount));
+//! assert_eq!(small.size(), Some(Decimal::new(10, 0)));
+//!
+//! let covered = Selection::from(small).union(&Selection::from(large));
+//! assert_eq!(covered.interval_iter().count(), 1);
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::measure::CheckedAdd;
+use crate::measure::Measurable;
+use crate::measure::Ratio;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalize implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Big integers are not `Finite`, so their intervals are already normalized.
+impl Normalize for RawInterval<BigInt> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+// Decimals are not `Finite`, so their intervals are already normalized.
+impl Normalize for RawInterval<Decimal> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BigInt measure implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl Measurable for BigInt {
+    type Length = Self;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper - lower
+    }
+}
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+// Lengths are converted to `f64` before dividing, so lengths which exceed its
+// range are rounded to infinity.
+impl Ratio for BigInt {
+    fn ratio(&self, whole: &Self) -> f64 {
+        self.to_f64().zip(whole.to_f64()).map_or(f64::NAN, |(l, w)| l / w)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Decimal measure implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl Measurable for Decimal {
+    type Length = Self;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper.checked_sub(*lower).unwrap_or(Self::MAX)
+    }
+}
+
+impl CheckedAdd for Decimal {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::checked_add(*self, *other)
+    }
+}
+
+impl Ratio for Decimal {
+    fn ratio(&self, whole: &Self) -> f64 {
+        self.checked_div(*whole)
+            .and_then(|ratio| ratio.to_f64())
+            .unwrap_or(f64::NAN)
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a reservation table tracking the busy time of several resources.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+#[cfg(feature="serde")] use serde::Deserialize;
+#[cfg(feature="serde")] use serde::Serialize;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bookings
+////////////////////////////////////////////////////////////////////////////////
+/// A table of reservations, mapping each resource to the `Selection` of
+/// points at which it is busy.
+///
+/// Reservations of a resource may touch but never overlap, and a reservation
+/// which would overlap an existing one is rejected without modifying the
+/// table. Resources with no reservations are not stored.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::booking::Bookings;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut rooms: Bookings<&str, u32> = Bookings::new();
+/// rooms.try_reserve("lab", Interval::right_open(900, 1000))?;
+/// rooms.try_reserve("lab", Interval::right_open(1000, 1130))?;
+///
+/// let conflict = rooms
+///     .try_reserve("lab", Interval::right_open(1100, 1200))
+///     .unwrap_err();
+/// assert_eq!(conflict.conflicts().interval_iter().collect::<Vec<_>>(), [
+///     Interval::right_open(1100, 1130),
+/// ]);
+///
+/// rooms.try_reserve("office", Interval::right_open(1100, 1200))?;
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(bound="for<'a> R: Ord + Serialize + \
+    Deserialize<'a> + 'a, for<'a> T: Ord + Serialize + Deserialize<'a> + \
+    Clone + 'a"))]
+pub struct Bookings<R, T> where R: Ord {
+    /// The busy time of each resource with reservations.
+    busy: BTreeMap<R, Selection<T>>,
+}
+
+impl<R, T> Bookings<R, T> where R: Ord {
+    /// Constructs a new table with no reservations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { busy: BTreeMap::new() }
+    }
+
+    /// Returns `true` if no resource has any reservations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.busy.is_empty()
+    }
+
+    /// Returns the `Selection` of points at which the given resource is busy,
+    /// or `None` if it has no reservations.
+    #[must_use]
+    pub fn busy(&self, resource: &R) -> Option<&Selection<T>> {
+        self.busy.get(resource)
+    }
+
+    /// Returns an iterator over the resources with reservations and their
+    /// busy time, ordered by resource.
+    pub fn iter(&self) -> impl Iterator<Item=(&R, &Selection<T>)> + '_ {
+        self.busy.iter()
+    }
+
+    /// Removes all reservations of the given resource, returning its busy
+    /// time.
+    pub fn remove(&mut self, resource: &R) -> Option<Selection<T>> {
+        self.busy.remove(resource)
+    }
+}
+
+impl<R, T> Bookings<R, T>
+    where
+        R: Ord,
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Returns `true` if the given resource is free for the whole `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::booking::Bookings;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut cars: Bookings<u8, i32> = Bookings::new();
+    /// cars.try_reserve(1, Interval::closed(3, 5))?;
+    ///
+    /// assert!(cars.is_free(&1, &Interval::closed(6, 9)));
+    /// assert!(!cars.is_free(&1, &Interval::closed(5, 9)));
+    /// assert!(cars.is_free(&2, &Interval::closed(5, 9)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_free(&self, resource: &R, interval: &Interval<T>) -> bool {
+        self.busy(resource)
+            .is_none_or(|busy| overlap(busy, interval).is_empty())
+    }
+
+    /// Returns an iterator over the resources with reservations which are
+    /// free for the whole `Interval`, ordered by resource. Resources with no
+    /// reservations are not known to the table and are not included.
+    pub fn free_for<'b>(&'b self, interval: &'b Interval<T>)
+        -> impl Iterator<Item=&'b R> + 'b
+    {
+        self.busy
+            .iter()
+            .filter(move |(_, busy)| overlap(busy, interval).is_empty())
+            .map(|(resource, _)| resource)
+    }
+
+    /// Reserves the given resource for the `Interval`.
+    ///
+    /// Reserving an empty `Interval` always succeeds and has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BookingConflict`] holding the points of the `Interval` at
+    /// which the resource is already busy, in which case the table is not
+    /// modified.
+    ///
+    /// [`BookingConflict`]: struct.BookingConflict.html
+    pub fn try_reserve(&mut self, resource: R, interval: Interval<T>)
+        -> Result<(), BookingConflict<T>>
+    {
+        if interval.is_empty() { return Ok(()); }
+
+        let busy = self.busy.entry(resource).or_default();
+        let conflicts = overlap(busy, &interval);
+        if conflicts.is_empty() {
+            busy.union_in_place(interval);
+            Ok(())
+        } else {
+            Err(BookingConflict(conflicts))
+        }
+    }
+
+    /// Releases the given resource for the `Interval`, returning `true` if it
+    /// was busy at any point of the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::booking::Bookings;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut cars: Bookings<u8, i32> = Bookings::new();
+    /// cars.try_reserve(1, Interval::closed(3, 5))?;
+    ///
+    /// assert!(cars.release(&1, Interval::closed(0, 9)));
+    /// assert!(!cars.release(&1, Interval::closed(0, 9)));
+    /// assert!(cars.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn release(&mut self, resource: &R, interval: Interval<T>) -> bool {
+        let Some(busy) = self.busy.get_mut(resource) else { return false };
+        if overlap(busy, &interval).is_empty() { return false; }
+
+        busy.minus_in_place(interval);
+        if busy.is_empty() {
+            let _ = self.busy.remove(resource);
+        }
+        true
+    }
+}
+
+impl<R, T> Default for Bookings<R, T> where R: Ord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, T> PartialEq for Bookings<R, T>
+    where
+        R: Ord,
+        Selection<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.busy == other.busy
+    }
+}
+
+impl<R, T> Eq for Bookings<R, T>
+    where
+        R: Ord,
+        Selection<T>: Eq,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the points of the `Selection` within the `Interval`.
+fn overlap<T>(busy: &Selection<T>, interval: &Interval<T>) -> Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    busy.interval_iter()
+        .map(|i| i.intersect(interval))
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BookingConflict
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when a reservation overlaps an existing one.
+#[derive(Debug, Clone)]
+pub struct BookingConflict<T>(Selection<T>);
+
+impl<T> BookingConflict<T> {
+    /// Returns the points at which the resource is already busy.
+    #[must_use]
+    pub const fn conflicts(&self) -> &Selection<T> {
+        &self.0
+    }
+
+    /// Returns the points at which the resource is already busy.
+    #[must_use]
+    pub fn into_conflicts(self) -> Selection<T> {
+        self.0
+    }
+}
+
+impl<T> PartialEq for BookingConflict<T> where Selection<T>: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for BookingConflict<T> where Selection<T>: Eq {}
+
+impl<T> std::fmt::Display for BookingConflict<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "reservation conflicts with an existing reservation")
+    }
+}
+
+impl<T> std::error::Error for BookingConflict<T> where T: std::fmt::Debug {}
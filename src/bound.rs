@@ -14,9 +14,14 @@
 // External library imports.
 #[cfg(feature="serde")] use serde::Deserialize;
 #[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="schemars")] use schemars::JsonSchema;
+
+// Internal library imports.
+use crate::normalize::Finite;
 
 // Standard library imports.
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::default::Default;
 
 // Local enum shortcut.
@@ -31,6 +36,7 @@ use self::Bound::*;
 /// [`Interval`]: struct.Interval.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="schemars", derive(JsonSchema))]
 pub enum Bound<T> {
     /// The bound includes the point.
     Include(T),
@@ -180,6 +186,31 @@ impl<T> Bound<T> {
         }
     }
 
+    /// Converts from `&Bound<T>` to `Bound<&T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x: Bound<String> = Bound::Include("a".to_string());
+    ///
+    /// assert_eq!(x.as_bound_ref().map(String::len), Bound::Include(1));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn as_bound_ref(&self) -> Bound<&T> {
+        match self {
+            Include(bound) => Include(bound),
+            Exclude(bound) => Exclude(bound),
+            Infinite       => Infinite,
+        }
+    }
+
     // Getting to contained values
     ////////////////////////////////////////////////////////////////////////////
 
@@ -517,6 +548,127 @@ impl<T> Bound<T> where T: PartialOrd + Clone {
     }
 }
 
+impl<T> Bound<T> where T: Ord {
+    // Comparison helpers
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Compares the `Bound`s as lower bounds, so that a bound is less than
+    /// another if it includes more points below them.
+    pub(in crate) fn cmp_lower(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Infinite,   Infinite)   => Ordering::Equal,
+            (Infinite,   _)          => Ordering::Less,
+            (_,          Infinite)   => Ordering::Greater,
+            (Include(p), Exclude(o)) => p.cmp(o).then(Ordering::Less),
+            (Exclude(p), Include(o)) => p.cmp(o).then(Ordering::Greater),
+            (Include(p), Include(o)) |
+            (Exclude(p), Exclude(o)) => p.cmp(o),
+        }
+    }
+
+    /// Compares the `Bound`s as upper bounds, so that a bound is greater than
+    /// another if it includes more points above them.
+    pub(in crate) fn cmp_upper(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Infinite,   Infinite)   => Ordering::Equal,
+            (Infinite,   _)          => Ordering::Greater,
+            (_,          Infinite)   => Ordering::Less,
+            (Include(p), Exclude(o)) => p.cmp(o).then(Ordering::Greater),
+            (Exclude(p), Include(o)) => p.cmp(o).then(Ordering::Less),
+            (Include(p), Include(o)) |
+            (Exclude(p), Exclude(o)) => p.cmp(o),
+        }
+    }
+
+    /// Returns `true` if every point below the `Bound`, used as an upper
+    /// bound, is less than every point above the given lower bound.
+    pub(in crate) fn precedes_lower(&self, lower: &Self) -> bool {
+        match (self, lower) {
+            (Infinite,   _)          |
+            (_,          Infinite)   => false,
+            (Include(p), Include(o)) => p < o,
+            (Include(p) | Exclude(p), Include(o) | Exclude(o)) => p <= o,
+        }
+    }
+}
+
+impl<T> Bound<T> where T: Finite {
+    // Normalization helpers
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the least point contained by the bound when it is used as a
+    /// lower bound, or `None` if no such point exists.
+    ///
+    /// [`Exclude`] bounds are advanced to the successor of their point, and
+    /// [`Infinite`] bounds resolve to the [`Finite`] minimum. This is the same
+    /// conversion used to normalize the lower bound of an [`Interval`].
+    ///
+    /// [`Exclude`]: #variant.Exclude
+    /// [`Infinite`]: #variant.Infinite
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`Interval`]: ../interval/struct.Interval.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Bound::Include(15u8).next_up(), Some(15));
+    /// assert_eq!(Bound::Exclude(15u8).next_up(), Some(16));
+    /// assert_eq!(Bound::Exclude(u8::MAX).next_up(), None);
+    /// assert_eq!(Bound::<u8>::Infinite.next_up(), Some(u8::MIN));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn next_up(self) -> Option<T> {
+        match self {
+            Include(x) => Some(x),
+            Exclude(x) => x.succ(),
+            Infinite   => Some(T::MINIMUM),
+        }
+    }
+
+    /// Returns the greatest point contained by the bound when it is used as an
+    /// upper bound, or `None` if no such point exists.
+    ///
+    /// [`Exclude`] bounds are retreated to the predecessor of their point, and
+    /// [`Infinite`] bounds resolve to the [`Finite`] maximum. This is the same
+    /// conversion used to normalize the upper bound of an [`Interval`].
+    ///
+    /// [`Exclude`]: #variant.Exclude
+    /// [`Infinite`]: #variant.Infinite
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`Interval`]: ../interval/struct.Interval.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Bound::Include(15u8).next_down(), Some(15));
+    /// assert_eq!(Bound::Exclude(15u8).next_down(), Some(14));
+    /// assert_eq!(Bound::Exclude(u8::MIN).next_down(), None);
+    /// assert_eq!(Bound::<u8>::Infinite.next_down(), Some(u8::MAX));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn next_down(self) -> Option<T> {
+        match self {
+            Include(x) => Some(x),
+            Exclude(x) => x.pred(),
+            Infinite   => Some(T::MAXIMUM),
+        }
+    }
+}
+
 // Default `Bound` is closed.
 impl<T> Default for Bound<T> where T: Default {
     #[inline]
@@ -532,3 +684,147 @@ impl<T> From<T> for Bound<T> {
         Include(t)
     }
 }
+
+// Conversions to and from the standard library `Bound`.
+impl<T> From<Bound<T>> for std::ops::Bound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        match bound {
+            Include(t) => Self::Included(t),
+            Exclude(t) => Self::Excluded(t),
+            Infinite   => Self::Unbounded,
+        }
+    }
+}
+
+impl<T> From<std::ops::Bound<T>> for Bound<T> {
+    #[inline]
+    fn from(bound: std::ops::Bound<T>) -> Self {
+        match bound {
+            std::ops::Bound::Included(t) => Include(t),
+            std::ops::Bound::Excluded(t) => Exclude(t),
+            std::ops::Bound::Unbounded   => Infinite,
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LowerBound
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Bound`] ordered as the lower bound of an `Interval`, so that a bound
+/// is less than another if it includes more points below them.
+///
+/// `Bound` itself is not ordered, since an [`Infinite`] bound is the least
+/// lower bound but the greatest upper bound. At the same point, an
+/// [`Include`] lower bound is less than an [`Exclude`] lower bound.
+///
+/// [`Bound`]: enum.Bound.html
+/// [`Infinite`]: enum.Bound.html#variant.Infinite
+/// [`Include`]: enum.Bound.html#variant.Include
+/// [`Exclude`]: enum.Bound.html#variant.Exclude
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::bound::LowerBound;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut bounds = vec![
+///     LowerBound(Bound::Exclude(3)),
+///     LowerBound(Bound::Include(3)),
+///     LowerBound(Bound::Infinite),
+/// ];
+/// bounds.sort();
+///
+/// assert_eq!(bounds, [
+///     LowerBound(Bound::Infinite),
+///     LowerBound(Bound::Include(3)),
+///     LowerBound(Bound::Exclude(3)),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(transparent))]
+pub struct LowerBound<T>(pub Bound<T>);
+
+impl<T> PartialOrd for LowerBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for LowerBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_lower(&other.0)
+    }
+}
+
+impl<T> From<Bound<T>> for LowerBound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        Self(bound)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// UpperBound
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Bound`] ordered as the upper bound of an `Interval`, so that a bound
+/// is greater than another if it includes more points above them.
+///
+/// An [`Infinite`] upper bound is the greatest, and at the same point, an
+/// [`Include`] upper bound is greater than an [`Exclude`] upper bound.
+///
+/// [`Bound`]: enum.Bound.html
+/// [`Infinite`]: enum.Bound.html#variant.Infinite
+/// [`Include`]: enum.Bound.html#variant.Include
+/// [`Exclude`]: enum.Bound.html#variant.Exclude
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::bound::UpperBound;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let widest = vec![Bound::Include(7), Bound::Exclude(9), Bound::Exclude(7)]
+///     .into_iter()
+///     .map(UpperBound)
+///     .max();
+///
+/// assert_eq!(widest, Some(UpperBound(Bound::Exclude(9))));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(transparent))]
+pub struct UpperBound<T>(pub Bound<T>);
+
+impl<T> PartialOrd for UpperBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for UpperBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_upper(&other.0)
+    }
+}
+
+impl<T> From<Bound<T>> for UpperBound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        Self(bound)
+    }
+}
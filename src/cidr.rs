@@ -0,0 +1,148 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a CIDR block type for converting between `Selection`s of IPv4
+//! addresses and network prefixes.
+//!
+//! A `Selection<Ipv4Addr>` built through union, intersection, and difference
+//! of allowed and denied networks can be converted back into the fewest CIDR
+//! blocks covering it with [`Selection::to_cidrs`], as is needed to generate
+//! firewall rules.
+//!
+//! [`Selection::to_cidrs`]: ../selection/struct.Selection.html#method.to_cidrs
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+
+// External library imports.
+#[cfg(feature="serde")] use serde::Deserialize;
+#[cfg(feature="serde")] use serde::Serialize;
+
+// Standard library imports.
+#[cfg(feature="serde")] use std::convert::TryFrom;
+use std::fmt;
+use std::net::Ipv4Addr;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Ipv4Cidr
+////////////////////////////////////////////////////////////////////////////////
+/// An IPv4 CIDR block, containing every address which shares the first
+/// `prefix_len` bits of its network address.
+///
+/// Deserialized blocks are constructed with [`Ipv4Cidr::new`], so their host
+/// bits are cleared, and prefix lengths greater than 32 are rejected.
+///
+/// [`Ipv4Cidr::new`]: #method.new
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::cidr::Ipv4Cidr;
+/// # use std::net::Ipv4Addr;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let block = Ipv4Cidr::new(Ipv4Addr::new(10, 1, 2, 3), 16).unwrap();
+///
+/// assert_eq!(block.to_string(), "10.1.0.0/16");
+/// assert_eq!(block.interval(), Interval::closed(
+///     Ipv4Addr::new(10, 1, 0, 0),
+///     Ipv4Addr::new(10, 1, 255, 255)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="serde", serde(try_from="RawIpv4Cidr"))]
+pub struct Ipv4Cidr {
+    /// The network address, with all host bits cleared.
+    address: Ipv4Addr,
+    /// The number of leading bits shared by the addresses of the block.
+    prefix_len: u8,
+}
+
+impl Ipv4Cidr {
+    /// Constructs a new `Ipv4Cidr` from any address within the block and the
+    /// prefix length. The host bits of the address are cleared. Returns `None`
+    /// if the prefix length is greater than 32.
+    #[must_use]
+    pub fn new(address: Ipv4Addr, prefix_len: u8) -> Option<Self> {
+        if prefix_len > 32 { return None; }
+        let mask = u32::MAX.checked_shl(32 - u32::from(prefix_len))
+            .unwrap_or(0);
+        Some(Self {
+            address: Ipv4Addr::from(u32::from(address) & mask),
+            prefix_len,
+        })
+    }
+
+    /// Returns the network address of the block.
+    #[must_use]
+    pub const fn address(&self) -> Ipv4Addr {
+        self.address
+    }
+
+    /// Returns the prefix length of the block.
+    #[must_use]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the `Interval` of addresses in the block.
+    #[must_use]
+    pub fn interval(&self) -> Interval<Ipv4Addr> {
+        let hosts = u32::MAX.checked_shr(u32::from(self.prefix_len))
+            .unwrap_or(0);
+        let last = Ipv4Addr::from(u32::from(self.address) | hosts);
+        Interval::closed(self.address, last)
+    }
+}
+
+impl From<Ipv4Addr> for Ipv4Cidr {
+    fn from(address: Ipv4Addr) -> Self {
+        Self { address, prefix_len: 32 }
+    }
+}
+
+impl From<Ipv4Cidr> for Interval<Ipv4Addr> {
+    fn from(cidr: Ipv4Cidr) -> Self {
+        cidr.interval()
+    }
+}
+
+/// The unvalidated fields of a serialized `Ipv4Cidr`.
+#[cfg(feature="serde")]
+#[derive(Deserialize)]
+struct RawIpv4Cidr {
+    /// The address of the block, possibly with host bits set.
+    address: Ipv4Addr,
+    /// The number of leading bits shared by the addresses of the block.
+    prefix_len: u8,
+}
+
+#[cfg(feature="serde")]
+impl TryFrom<RawIpv4Cidr> for Ipv4Cidr {
+    type Error = &'static str;
+
+    fn try_from(raw: RawIpv4Cidr) -> Result<Self, Self::Error> {
+        Self::new(raw.address, raw.prefix_len)
+            .ok_or("CIDR prefix length greater than 32")
+    }
+}
+
+impl fmt::Display for Ipv4Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides conversions between `Selection<u32>`s of CPU numbers and the
+//! Linux CPU mask and CPU list formats.
+//!
+//! A CPU mask is a hexadecimal bitmap, written as comma-separated 32-bit
+//! words with the most significant word first, such as `ff,0000000f`. A CPU
+//! list is a comma-separated list of CPUs and CPU ranges, such as `0-3,8`,
+//! where ranges may have a stride, as in `0-15:2/4`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::num::ParseIntError;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of CPUs in each word of a CPU mask.
+const WORD_BITS: u32 = 32;
+
+/// The number of CPUs which can be written to a CPU mask. This is the largest
+/// number of CPUs supported by Linux.
+pub const MAX_CPUS: u32 = 8192;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CPU masks
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a `Selection` of CPUs from a CPU mask. Each comma-separated word
+/// holds the next 32 CPUs, starting from the last word. An optional `0x`
+/// prefix and surrounding whitespace are ignored.
+///
+/// # Errors
+///
+/// Returns a [`CpuSetParseError::InvalidMask`] if a word is empty or is not
+/// a hexadecimal number of at most 8 digits, or if the mask holds CPUs
+/// greater than `u32::MAX`.
+///
+/// [`CpuSetParseError::InvalidMask`]: enum.CpuSetParseError.html#variant.InvalidMask
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::cpu_set::parse_cpu_mask;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let cpus = parse_cpu_mask("f0,0000000f")?;
+///
+/// assert_eq!(cpus.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(0, 3),
+///     Interval::closed(36, 39),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn parse_cpu_mask(s: &str) -> Result<Selection<u32>, CpuSetParseError> {
+    let s = s.trim();
+    let s = s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+
+    let mut cpus = Selection::new();
+    let mut run_start = None;
+    let mut last_cpu = None;
+    for (idx, word) in s.rsplit(',').enumerate() {
+        let word = word.trim();
+        if word.is_empty() || word.len() > 8 {
+            return Err(CpuSetParseError::InvalidMask);
+        }
+        let bits = u32::from_str_radix(word, 16)
+            .ok()
+            .ok_or(CpuSetParseError::InvalidMask)?;
+        let base = u32::try_from(idx)
+            .ok()
+            .and_then(|idx| idx.checked_mul(WORD_BITS));
+
+        // Record each run of set bits as an interval.
+        for bit in 0..WORD_BITS {
+            let cpu = base.and_then(|base| base.checked_add(bit));
+            let selected = bits & (1 << bit) != 0;
+            if selected && cpu.is_none() {
+                return Err(CpuSetParseError::InvalidMask);
+            }
+            match (selected, run_start, cpu) {
+                (true, None, _) => run_start = cpu,
+                (false, Some(start), Some(cpu)) => {
+                    cpus.union_in_place(Interval::right_open(start, cpu));
+                    run_start = None;
+                },
+                (false, Some(start), None) => {
+                    cpus.union_in_place(Interval::unbounded_from(start));
+                    run_start = None;
+                },
+                _ => (),
+            }
+            last_cpu = cpu;
+        }
+    }
+    if let (Some(start), Some(end)) = (run_start, last_cpu) {
+        cpus.union_in_place(Interval::closed(start, end));
+    }
+    Ok(cpus)
+}
+
+/// Returns the CPU mask of the given `Selection` of CPUs. The most
+/// significant word is written without leading zeros, and an empty
+/// `Selection` is written as `0`.
+///
+/// The length of the mask is proportional to the greatest selected CPU, so
+/// `None` is returned if any selected CPU is not less than [`MAX_CPUS`].
+///
+/// [`MAX_CPUS`]: constant.MAX_CPUS.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::cpu_set::cpu_mask;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut cpus = Selection::from(Interval::closed(0, 3));
+/// cpus.union_in_place(Interval::closed(36, 39));
+///
+/// assert_eq!(cpu_mask(&cpus).as_deref(), Some("f0,0000000f"));
+/// assert_eq!(cpu_mask(&Selection::from(Interval::unbounded_from(0))), None);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn cpu_mask(cpus: &Selection<u32>) -> Option<String> {
+    let last = cpus.interval_iter().next_back();
+    let Some(&max) = last.as_ref().and_then(|i| i.0.upper_point()) else {
+        return Some("0".to_owned());
+    };
+    if max >= MAX_CPUS { return None; }
+
+    let mut words = vec![0u32; (max / WORD_BITS) as usize + 1];
+    for interval in cpus.interval_iter() {
+        let (Some(&start), Some(&end)) = (
+            interval.0.lower_point(),
+            interval.0.upper_point()) else { continue };
+
+        for idx in start / WORD_BITS..=end / WORD_BITS {
+            let word_start = idx * WORD_BITS;
+            let low = start.saturating_sub(word_start);
+            let high = (end - word_start).min(WORD_BITS - 1);
+            let bits = u32::MAX >> (WORD_BITS - 1 - (high - low));
+            words[idx as usize] |= bits << low;
+        }
+    }
+
+    let mut mask = String::new();
+    let mut words = words.into_iter().rev();
+    if let Some(word) = words.next() {
+        let _ = write!(mask, "{word:x}");
+    }
+    for word in words {
+        let _ = write!(mask, ",{word:08x}");
+    }
+    Some(mask)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CPU lists
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a `Selection` of CPUs from a CPU list.
+///
+/// Each comma-separated item is a CPU (`8`), a range of CPUs (`0-3`), or a
+/// strided range of CPUs (`0-15:2/4`), which selects the first 2 of every 4
+/// CPUs in the range. Whitespace around items is ignored, and an empty list
+/// parses as an empty `Selection`.
+///
+/// # Errors
+///
+/// Returns a [`CpuSetParseError::InvalidValue`] if a CPU number could not be
+/// parsed, or a [`CpuSetParseError::InvalidItem`] if an item is empty, a range
+/// ends before it starts, or a stride selects more CPUs than its group size.
+///
+/// [`CpuSetParseError::InvalidValue`]: enum.CpuSetParseError.html#variant.InvalidValue
+/// [`CpuSetParseError::InvalidItem`]: enum.CpuSetParseError.html#variant.InvalidItem
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::cpu_set::parse_cpu_list;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let cpus = parse_cpu_list("0-7:2/4,12")?;
+///
+/// assert_eq!(cpus.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(0, 1),
+///     Interval::closed(4, 5),
+///     Interval::point(12),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn parse_cpu_list(s: &str) -> Result<Selection<u32>, CpuSetParseError> {
+    /// Parses a single CPU number.
+    fn parse_cpu(cpu: &str) -> Result<u32, CpuSetParseError> {
+        cpu.trim().parse().map_err(CpuSetParseError::InvalidValue)
+    }
+
+    let mut cpus = Selection::new();
+    if s.trim().is_empty() { return Ok(cpus); }
+
+    for item in s.split(',') {
+        let item = item.trim();
+        if item.is_empty() { return Err(CpuSetParseError::InvalidItem); }
+
+        let (range, stride) = match item.split_once(':') {
+            Some((range, stride)) => (range, Some(stride)),
+            None                  => (item, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (parse_cpu(start)?, parse_cpu(end)?),
+            None               => { let cpu = parse_cpu(range)?; (cpu, cpu) },
+        };
+        if start > end { return Err(CpuSetParseError::InvalidItem); }
+
+        let Some(stride) = stride else {
+            cpus.union_in_place(Interval::closed(start, end));
+            continue;
+        };
+        let (used, group) = stride
+            .split_once('/')
+            .ok_or(CpuSetParseError::InvalidItem)?;
+        let (used, group) = (parse_cpu(used)?, parse_cpu(group)?);
+        if used == 0 || used > group {
+            return Err(CpuSetParseError::InvalidItem);
+        }
+
+        let mut group_start = Some(start);
+        while let Some(first) = group_start.filter(|first| *first <= end) {
+            let last = first.saturating_add(used - 1).min(end);
+            cpus.union_in_place(Interval::closed(first, last));
+            group_start = first.checked_add(group);
+        }
+    }
+    Ok(cpus)
+}
+
+/// Returns the CPU list of the given `Selection` of CPUs, written as CPUs and
+/// ranges of CPUs without strides.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::cpu_set::cpu_list;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut cpus = Selection::from(Interval::closed(0, 3));
+/// cpus.union_in_place(Interval::point(8));
+///
+/// assert_eq!(cpu_list(&cpus), "0-3,8");
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn cpu_list(cpus: &Selection<u32>) -> String {
+    let mut list = String::new();
+    for interval in cpus.interval_iter() {
+        let (Some(start), Some(end)) = (
+            interval.0.lower_point(),
+            interval.0.upper_point()) else { continue };
+
+        if !list.is_empty() { list.push(','); }
+        if start == end {
+            let _ = write!(list, "{start}");
+        } else {
+            let _ = write!(list, "{start}-{end}");
+        }
+    }
+    list
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CpuSetParseError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned by failure to parse a CPU mask or CPU list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CpuSetParseError {
+    /// The CPU mask is not valid.
+    InvalidMask,
+    /// An item of the CPU list is not valid.
+    InvalidItem,
+    /// A CPU number of the CPU list could not be parsed.
+    InvalidValue(ParseIntError),
+}
+
+impl std::fmt::Display for CpuSetParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMask     => write!(f, "invalid cpu mask"),
+            Self::InvalidItem     => write!(f, "invalid cpu list item"),
+            Self::InvalidValue(e) => write!(f, "invalid cpu number: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CpuSetParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidValue(e) => Some(e),
+            _                     => None,
+        }
+    }
+}
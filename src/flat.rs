@@ -0,0 +1,294 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a flat binary encoding of `Selection`s, and a read-only view which
+//! answers queries directly from the encoded bytes.
+//!
+//! A `Selection` of [`Finite`] points is encoded by
+//! [`Selection::serialize_flat`] as the lower and upper points of each of its
+//! `Interval`s in ascending order, each written in little-endian byte order
+//! using [`FlatPoint::WIDTH`] bytes. The encoding is the same on every
+//! platform, so it can be written once and memory-mapped by later processes,
+//! and a [`SelectionView`] over it answers queries by binary search without
+//! allocating.
+//!
+//! [`Finite`]: ../normalize/trait.Finite.html
+//! [`Selection::serialize_flat`]: ../selection/struct.Selection.html#method.serialize_flat
+//! [`FlatPoint::WIDTH`]: trait.FlatPoint.html#associatedconstant.WIDTH
+//! [`SelectionView`]: struct.SelectionView.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryInto;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FlatPoint
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a fixed-width, platform-independent binary encoding of points.
+///
+/// Implementations must ensure that `read_flat` inverts `write_flat`.
+pub trait FlatPoint: Sized {
+    /// The number of bytes in the encoding of a point.
+    const WIDTH: usize;
+
+    /// Appends the encoding of the point to the given bytes.
+    fn write_flat(&self, bytes: &mut Vec<u8>);
+
+    /// Decodes a point from exactly `WIDTH` bytes.
+    fn read_flat(bytes: &[u8]) -> Self;
+}
+
+/// Implements `FlatPoint` for builtin integer types.
+macro_rules! std_integer_flat_point_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl FlatPoint for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn write_flat(&self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_flat(bytes: &[u8]) -> Self {
+                Self::from_le_bytes(bytes
+                    .try_into()
+                    .expect("flat point has the encoded width"))
+            }
+        })*
+    };
+}
+
+// Provide implementations of FlatPoint for builtin integer types. The widths
+// of `usize` and `isize` vary by platform, so they are not included.
+std_integer_flat_point_impl![
+    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, i128
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionView
+////////////////////////////////////////////////////////////////////////////////
+/// A read-only view of a `Selection` encoded by
+/// [`Selection::serialize_flat`], which answers queries directly from the
+/// encoded bytes.
+///
+/// [`Selection::serialize_flat`]: ../selection/struct.Selection.html#method.serialize_flat
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::flat::SelectionView;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<u32> = Selection::from(Interval::closed(10, 20));
+/// sel.union_in_place(Interval::closed(40, 50));
+/// let bytes = sel.serialize_flat();
+///
+/// let view: SelectionView<'_, u32> = SelectionView::new(&bytes)?;
+/// assert!(view.contains(&45));
+/// assert!(!view.contains(&30));
+/// assert_eq!(view.interval_containing(&12), Some(Interval::closed(10, 20)));
+/// assert_eq!(view.to_selection(), sel);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SelectionView<'a, T> {
+    /// The encoded `Interval` bounds.
+    bytes: &'a [u8],
+    /// The point type of the encoded `Interval`s.
+    marker: PhantomData<T>,
+}
+
+// The view only borrows bytes, so it is `Copy` for any point type.
+impl<T> Clone for SelectionView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SelectionView<'_, T> {}
+
+impl<'a, T> SelectionView<'a, T> where T: FlatPoint + Finite + Ord + Clone {
+    /// Constructs a new `SelectionView` over the given bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FlatSelectionError::InvalidLength`] if the bytes do not
+    /// encode a whole number of `Interval`s, or a
+    /// [`FlatSelectionError::Unordered`] if the encoded `Interval`s are
+    /// empty, overlapping, or out of order.
+    ///
+    /// [`FlatSelectionError::InvalidLength`]: enum.FlatSelectionError.html#variant.InvalidLength
+    /// [`FlatSelectionError::Unordered`]: enum.FlatSelectionError.html#variant.Unordered
+    pub fn new(bytes: &'a [u8]) -> Result<Self, FlatSelectionError> {
+        if !bytes.len().is_multiple_of(2 * T::WIDTH) {
+            return Err(FlatSelectionError::InvalidLength);
+        }
+        let view = Self { bytes, marker: PhantomData };
+
+        // Each point must be at most the next, and each upper point must be
+        // less than the next lower point.
+        for idx in 1..(2 * view.len()) {
+            let (prev, next) = (view.point(idx - 1), view.point(idx));
+            if prev > next || (idx % 2 == 0 && prev == next) {
+                return Err(FlatSelectionError::Unordered);
+            }
+        }
+        Ok(view)
+    }
+
+    /// Returns the encoded bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the number of encoded `Interval`s.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / (2 * T::WIDTH)
+    }
+
+    /// Returns `true` if no `Interval`s are encoded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns `true` if the given point is in the encoded `Selection`.
+    #[must_use]
+    pub fn contains(&self, point: &T) -> bool {
+        self.interval_containing(point).is_some()
+    }
+
+    /// Returns the encoded `Interval` containing the given point, or `None`
+    /// if it is not in the encoded `Selection`.
+    #[must_use]
+    pub fn interval_containing(&self, point: &T) -> Option<Interval<T>> {
+        // Find the number of `Interval`s beginning at or before the point.
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.point(2 * mid) <= *point {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let idx = low.checked_sub(1)?;
+        let upper = self.point(2 * idx + 1);
+        (upper >= *point).then(|| Interval::closed(self.point(2 * idx), upper))
+    }
+
+    /// Returns an iterator over the encoded `Interval`s in order.
+    #[must_use]
+    pub fn interval_iter(&self) -> IntervalIter<'a, T> {
+        IntervalIter { view: *self, indices: 0..self.len() }
+    }
+
+    /// Returns the encoded `Selection`.
+    #[must_use]
+    pub fn to_selection(&self) -> Selection<T> {
+        self.interval_iter().collect()
+    }
+
+    /// Decodes the point at the given index.
+    fn point(&self, idx: usize) -> T {
+        T::read_flat(&self.bytes[idx * T::WIDTH..(idx + 1) * T::WIDTH])
+    }
+
+    /// Decodes the `Interval` at the given index.
+    fn interval(&self, idx: usize) -> Interval<T> {
+        Interval::closed(self.point(2 * idx), self.point(2 * idx + 1))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the encoded `Interval`s of a `SelectionView` in order.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'a, T> {
+    /// The view being iterated over.
+    view: SelectionView<'a, T>,
+    /// The indices of the `Interval`s not yet yielded.
+    indices: Range<usize>,
+}
+
+impl<T> Iterator for IntervalIter<'_, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|idx| self.view.interval(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntervalIter<'_, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|idx| self.view.interval(idx))
+    }
+}
+
+impl<T> ExactSizeIterator for IntervalIter<'_, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{}
+
+impl<T> FusedIterator for IntervalIter<'_, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FlatSelectionError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned by failure to read an encoded `Selection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatSelectionError {
+    /// The bytes do not encode a whole number of `Interval`s.
+    InvalidLength,
+    /// The encoded `Interval`s are empty, overlapping, or out of order.
+    Unordered,
+}
+
+impl fmt::Display for FlatSelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "invalid encoded length"),
+            Self::Unordered     => write!(f, "unordered encoded intervals"),
+        }
+    }
+}
+
+impl std::error::Error for FlatSelectionError {}
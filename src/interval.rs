@@ -11,25 +11,45 @@
 //!
 ////////////////////////////////////////////////////////////////////////////////
 
+// Public modules.
+pub mod algorithms;
+pub mod corpus;
+pub mod low_level;
+#[cfg(feature="serde")]
+pub mod serde_notation;
+#[cfg(feature="serde")]
+pub mod serde_range;
+
 // Internal library imports.
 use crate::bound::Bound;
+use crate::measure::Measurable;
+use crate::normalize::Align;
 use crate::normalize::Finite;
 use crate::normalize::Normalize;
+use crate::normalize::Reflect;
 use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
 
 // External library imports.
 #[cfg(feature="serde")] use serde::Deserialize;
 #[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="schemars")] use schemars::JsonSchema;
+#[cfg(feature="rand")] use rand::Rng;
+#[cfg(feature="rand")] use rand::distributions::uniform::SampleUniform;
 
 // Standard library imports.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::iter::FusedIterator;
+use std::iter::Rev;
+use std::num::TryFromIntError;
+use std::ops::Neg;
 use std::ops::Range;
 use std::ops::RangeFrom;
 use std::ops::RangeFull;
-// use std::ops::RangeInclusive; // TODO: Add when RangeInclusive accessors stabilize.
+use std::ops::RangeInclusive;
 use std::ops::RangeTo;
 use std::ops::RangeToInclusive;
-use std::ops::Sub;
 
 
 
@@ -47,6 +67,8 @@ use std::ops::Sub;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature="serde", serde(transparent))]
+#[cfg_attr(feature="schemars", derive(JsonSchema))]
+#[cfg_attr(feature="schemars", schemars(transparent))]
 pub struct Interval<T>(pub (crate) RawInterval<T>);
 
 impl<T> Default for Interval<T>
@@ -69,6 +91,27 @@ impl<T> Interval<T>
     ////////////////////////////////////////////////////////////////////////////
     // Constructors
     ////////////////////////////////////////////////////////////////////////////
+
+    /// The empty `Interval`. Equal to [`Interval::empty`], but usable in
+    /// constant expressions and patterns.
+    ///
+    /// [`Interval::empty`]: #method.empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(7, 3);
+    ///
+    /// assert!(matches!(interval, Interval::EMPTY));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub const EMPTY: Self = Self(RawInterval::Empty);
     
     /// Constructs a new `Interval` from the given [`Bound`]s.
     /// 
@@ -126,7 +169,60 @@ impl<T> Interval<T>
     pub fn new(left: Bound<T>, right: Bound<T>) -> Self {
         Self(RawInterval::new(left, right).normalized())
     }
-    
+
+    /// Constructs a new `Interval` from the given [`Bound`]s, resolving bounds
+    /// at the same point with conflicting openness by the given
+    /// [`BoundConflict`] policy. Other bounds are handled as by [`new`], which
+    /// gives the point for half-open bounds such as `[3,3)`, but an empty
+    /// `Interval` for open bounds such as `(3,3)`.
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    /// [`BoundConflict`]: enum.BoundConflict.html
+    /// [`new`]: #method.new
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval::BoundConflict;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let point: Interval<i32> = Interval::from_bounds_lenient(
+    ///     Include(3),
+    ///     Exclude(3),
+    ///     BoundConflict::PreferPoint);
+    /// let empty: Interval<i32> = Interval::from_bounds_lenient(
+    ///     Include(3),
+    ///     Exclude(3),
+    ///     BoundConflict::PreferEmpty);
+    ///
+    /// assert_eq!(point, Interval::point(3));
+    /// assert_eq!(empty, Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_bounds_lenient(
+        lower: Bound<T>,
+        upper: Bound<T>,
+        policy: BoundConflict)
+        -> Self
+    {
+        use Bound::*;
+        let conflict = lower.as_ref().is_some()
+            && lower.as_ref() == upper.as_ref()
+            && (lower.is_exclusive() || upper.is_exclusive());
+        match (policy, lower) {
+            (BoundConflict::PreferPoint, Include(p) | Exclude(p))
+                if conflict => Self::point(p),
+            (BoundConflict::PreferEmpty, _) if conflict => Self::empty(),
+            (_, lower) => Self::new(lower, upper),
+        }
+    }
+
     
     /// Constructs an empty `Interval`.
     ///
@@ -145,8 +241,7 @@ impl<T> Interval<T>
     #[inline]
     #[must_use]
     pub fn empty() -> Self {
-        // Normalization not needed for empty intervals.
-        Self(RawInterval::Empty)
+        Self::EMPTY
     }
     
     /// Constructs a new degenerate `Interval` containing the given point.
@@ -716,6 +811,90 @@ impl<T> Interval<T>
         Self(RawInterval::UpTo(point).normalized())
     }
 
+    /// Constructs a new `Interval` containing all points strictly greater than
+    /// the given point. Equivalent to [`unbounded_up_from`].
+    ///
+    /// [`unbounded_up_from`]: #method.unbounded_up_from
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u8> = Interval::above(7);
+    ///
+    /// assert_eq!(interval, Interval::closed(8, u8::MAX));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// There are no [`Finite`] points above the maximum:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u8> = Interval::above(u8::MAX);
+    ///
+    /// assert_eq!(interval, Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn above(point: T) -> Self {
+        Self::unbounded_up_from(point)
+    }
+
+    /// Constructs a new `Interval` containing all points strictly less than
+    /// the given point. Equivalent to [`unbounded_up_to`].
+    ///
+    /// [`unbounded_up_to`]: #method.unbounded_up_to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u8> = Interval::below(7);
+    ///
+    /// assert_eq!(interval, Interval::closed(0, 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// There are no [`Finite`] points below the minimum:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u8> = Interval::below(u8::MIN);
+    ///
+    /// assert_eq!(interval, Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn below(point: T) -> Self {
+        Self::unbounded_up_to(point)
+    }
+
     /// Constructs a new unbounded `Interval` containing all points.
     ///
     /// # Examples
@@ -787,6 +966,72 @@ impl<T> Interval<T>
         }
     }
 
+    /// Returns the bounds of the `Interval` as a pair of standard library
+    /// [`Bound`]s, or `None` if the `Interval` is empty. The pair implements
+    /// [`RangeBounds`], so it can be passed to range queries such as
+    /// [`BTreeMap::range`].
+    ///
+    /// Empty `Interval`s have no such bounds, and a range query with equal
+    /// excluded bounds would panic.
+    ///
+    /// [`Bound`]: https://doc.rust-lang.org/std/ops/enum.Bound.html
+    /// [`RangeBounds`]: https://doc.rust-lang.org/std/ops/trait.RangeBounds.html
+    /// [`BTreeMap::range`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::collections::BTreeMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let map: BTreeMap<i32, char> = (0..10).zip('a'..).collect();
+    ///
+    /// let interval: Interval<i32> = Interval::open(2, 6);
+    /// let bounds = interval.try_into_range_bounds().unwrap();
+    /// assert_eq!(map.range(bounds).map(|(_, c)| *c).collect::<String>(),
+    ///     "def");
+    ///
+    /// assert_eq!(Interval::<i32>::empty().try_into_range_bounds(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn try_into_range_bounds(&self)
+        -> Option<(std::ops::Bound<T>, std::ops::Bound<T>)>
+    {
+        let lower = self.lower_bound()?;
+        let upper = self.upper_bound()?;
+        Some((lower.into(), upper.into()))
+    }
+
+    /// Returns the `Selection` containing exactly the points of the
+    /// `Interval`. This is the concretization of a Galois connection whose
+    /// abstraction is [`Selection::abstracted`].
+    ///
+    /// [`Selection::abstracted`]: ../selection/struct.Selection.html#method.abstracted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 4);
+    ///
+    /// assert_eq!(interval.concretized().abstracted(), interval);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn concretized(&self) -> Selection<T> {
+        Selection::from(self.clone())
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
@@ -960,7 +1205,9 @@ impl<T> Interval<T>
     }
 
     /// Returns the size of the `Interval`, or `None` if it is either infinite
-    /// or empty.
+    /// or empty. The size is the [`Measurable`] span between the bounds.
+    ///
+    /// [`Measurable`]: ../measure/trait.Measurable.html
     ///
     /// # Example
     ///
@@ -975,13 +1222,135 @@ impl<T> Interval<T>
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn size(&self) -> Option<T> where T: Sub<Output=T> {
-        match (self.infimum(), self.supremum()) {
-            (Some(l), Some(u)) => Some(u - l),
+    pub fn size(&self) -> Option<T::Length> where T: Measurable {
+        match (self.0.lower_point(), self.0.upper_point()) {
+            (Some(l), Some(u)) => Some(T::span(l, u)),
             _                  => None,
         }
     }
 
+    /// Returns the size of the intersection of the `Interval`s, or `None` if
+    /// it is either infinite or empty. This is equivalent to
+    /// `self.intersect(other).size()`, without constructing the intersection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    ///
+    /// assert_eq!(a.overlap(&Interval::closed(5, 20)), Some(2));
+    /// assert_eq!(a.overlap(&Interval::closed(8, 20)), None);
+    /// assert_eq!(a.overlap(&Interval::unbounded_from(2)), Some(5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn overlap(&self, other: &Self) -> Option<T::Length>
+        where T: Measurable
+    {
+        if self.is_empty() || other.is_empty() { return None; }
+
+        // Missing points are infinite bounds, which never limit the overlap.
+        let lower = match (self.0.lower_point(), other.0.lower_point()) {
+            (Some(a), Some(b)) => a.max(b),
+            (a, b)             => a.or(b)?,
+        };
+        let upper = match (self.0.upper_point(), other.0.upper_point()) {
+            (Some(a), Some(b)) => a.min(b),
+            (a, b)             => a.or(b)?,
+        };
+
+        match lower.cmp(upper) {
+            Ordering::Less    => Some(T::span(lower, upper)),
+            // Intervals meeting at a point overlap only if both contain it.
+            Ordering::Equal if self.contains(lower) && other.contains(lower)
+                              => Some(T::span(lower, upper)),
+            _                 => None,
+        }
+    }
+
+    /// Returns `true` if the [`Measurable`] span between the bounds of the
+    /// `Interval` is at least the given width. An unbounded `Interval` is
+    /// wider than any width, and an empty `Interval` has no width.
+    ///
+    /// [`Measurable`]: ../measure/trait.Measurable.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 7);
+    /// assert_eq!(interval.width_at_least(&10), true);
+    /// assert_eq!(interval.width_at_least(&11), false);
+    ///
+    /// let interval: Interval<i8> = Interval::closed(-100, 100);
+    /// assert_eq!(interval.width_at_least(&200), true);
+    /// assert_eq!(interval.width_at_least(&201), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn width_at_least(&self, width: &T::Length) -> bool
+        where
+            T: Measurable,
+            T::Length: PartialOrd,
+    {
+        match (self.0.lower_point(), self.0.upper_point()) {
+            (Some(l), Some(u)) => T::span(l, u) >= *width,
+            _                  => !self.is_empty(),
+        }
+    }
+
+    /// Returns `true` if the `Interval` contains at least the given number of
+    /// points. Only defined for [`Finite`] `Interval`s.
+    ///
+    /// The number of points is compared using the [`Measurable`] span of the
+    /// `Interval`, so it is never computed. Numbers of points beyond the
+    /// range of the span are checked by iterating over the points.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`Measurable`]: ../measure/trait.Measurable.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<u64> = Interval::full();
+    /// assert_eq!(interval.has_at_least(usize::MAX), true);
+    ///
+    /// let interval: Interval<i8> = Interval::closed(-100, 100);
+    /// assert_eq!(interval.has_at_least(201), true);
+    /// assert_eq!(interval.has_at_least(202), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn has_at_least(&self, n: usize) -> bool
+        where
+            T: Finite + Measurable,
+            T::Length: PartialOrd + TryFrom<usize>,
+    {
+        let Some(n) = n.checked_sub(1) else { return true };
+        // Spans saturate at the maximum width, which is at least any width
+        // converted from `n`, so the comparison is exact.
+        T::Length::try_from(n).map_or_else(
+            |_| self.iter().nth(n).is_some(),
+            |width| self.width_at_least(&width))
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
     ////////////////////////////////////////////////////////////////////////////
@@ -1009,6 +1378,38 @@ impl<T> Interval<T>
         matches!(self.0, RawInterval::Empty)
     }
 
+    /// Returns `true` if the interval contains every point. For [`Finite`]
+    /// types, this is the interval closed at the minimum and maximum points.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 5);
+    /// assert_eq!(interval.is_full(), false);
+    ///
+    /// let interval: Interval<i32> = Interval::closed(i32::MIN, i32::MAX);
+    /// assert_eq!(interval.is_full(), true);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        match self.0 {
+            RawInterval::Full         => true,
+            // `Finite` types normalize the full interval to closed bounds.
+            RawInterval::Closed(_, _) => *self == Self::full(),
+            _                         => false,
+        }
+    }
+
     /// Returns `true` if the interval contains a single point.
     ///
     /// # Example
@@ -1489,66 +1890,1141 @@ impl<T> Interval<T>
         self.0.contains(point)
     }
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Set comparisons
-    ////////////////////////////////////////////////////////////////////////////
-    
-    /// Returns `true` if the `Interval` overlaps the given `Interval`.
+    /// Returns `true` if the given `Bound` lies within the `Interval`, so that
+    /// it may bound a sub-interval of it. An [`Include`] bound must have its
+    /// point in the `Interval`, while an [`Exclude`] bound may also have its
+    /// point at an excluded end of the `Interval`. An [`Infinite`] bound lies
+    /// within the `Interval` only if the `Interval` is unbounded.
+    ///
+    /// [`Include`]: ../bound/enum.Bound.html#variant.Include
+    /// [`Exclude`]: ../bound/enum.Bound.html#variant.Exclude
+    /// [`Infinite`]: ../bound/enum.Bound.html#variant.Infinite
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(4, 15);
-    /// assert_eq!(a.intersects(&b), true);
+    /// let interval: Interval<i32> = Interval::closed(0, 20);
+    /// assert!(interval.contains_bound(&Bound::Include(20)));
+    /// assert!(interval.contains_bound(&Bound::Exclude(20)));
     ///
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(8, 12);
-    /// assert_eq!(a.intersects(&b), false);
+    /// assert!(!interval.contains_bound(&Bound::Exclude(21)));
+    /// assert!(!interval.contains_bound(&Bound::Infinite));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersects(&self, other: &Self) -> bool {
-        self.0.intersects(&other.0)
+    pub fn contains_bound(&self, bound: &Bound<T>) -> bool {
+        match bound {
+            Bound::Include(p) => self.contains(p),
+            Bound::Exclude(p) => !self.is_empty()
+                && self.infimum().is_none_or(|lower| lower <= *p)
+                && self.supremum().is_none_or(|upper| *p <= upper),
+            Bound::Infinite   => !self.is_bounded(),
+        }
     }
 
-    /// Returns `true` if the `Interval` shares a bound with the given 
-    /// `Interval`.
+    /// Returns the given `Bound` if it lies within the `Interval`, or else
+    /// the nearest `Bound` which does. A `Bound` below the `Interval` is
+    /// clamped to its lower bound, and one above it to its upper bound.
+    ///
+    /// An [`Infinite`] bound may denote either end of the `Interval`, so it
+    /// is returned unchanged, as is any `Bound` if the `Interval` is empty.
+    ///
+    /// [`Infinite`]: ../bound/enum.Bound.html#variant.Infinite
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(5, 15);
-    /// assert_eq!(a .is_adjacent_to(&b), true);
+    /// let parent: Interval<i32> = Interval::closed(0, 20);
+    /// let (lower, upper) = (Bound::Exclude(-5), Bound::Include(12));
     ///
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(8, 12);
-    /// assert_eq!(a .is_adjacent_to(&b), false);
+    /// let child = Interval::new(parent.clamp_bound(lower),
+    ///     parent.clamp_bound(upper));
+    /// assert_eq!(child, Interval::closed(0, 12));
+    /// assert_eq!(parent.clamp_bound(Bound::Exclude(30)), Bound::Include(20));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn is_adjacent_to(&self, other: &Self) -> bool {
-        // TODO: Consider normalization steps adjacent.
-        self.0 .is_adjacent_to(&other.0)
+    #[must_use]
+    pub fn clamp_bound(&self, bound: Bound<T>) -> Bound<T> {
+        if self.contains_bound(&bound) { return bound; }
+        let (Some(lower), Some(upper)) = (
+            self.lower_bound(),
+            self.upper_bound()) else { return bound };
+
+        match bound {
+            Bound::Include(p) | Bound::Exclude(p) => {
+                if self.infimum().is_some_and(|inf| p < inf) {
+                    lower
+                } else if self.supremum().is_some_and(|sup| p > sup) {
+                    upper
+                } else {
+                    // An included point at an excluded end of the `Interval`.
+                    Bound::Exclude(p)
+                }
+            },
+            Bound::Infinite => Bound::Infinite,
+        }
     }
 
     ////////////////////////////////////////////////////////////////////////////
-    // Set operations
+    // Set comparisons
     ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns `Interval`s containing all points not contained in the 
-    /// [`Interval`].
+    
+    /// Returns `true` if the `Interval` overlaps the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(4, 15);
+    /// assert_eq!(a.intersects(&b), true);
+    ///
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a.intersects(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.0.intersects(&other.0)
+    }
+
+    /// Returns `true` if the `Interval` shares a bound with the given 
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(5, 15);
+    /// assert_eq!(a .is_adjacent_to(&b), true);
+    ///
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(8, 12);
+    /// assert_eq!(a .is_adjacent_to(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_adjacent_to(&self, other: &Self) -> bool {
+        // TODO: Consider normalization steps adjacent.
+        self.0 .is_adjacent_to(&other.0)
+    }
+
+    /// Returns `true` if every point of the `Interval` is in the given
+    /// `Interval`. An empty `Interval` is within every `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::open(0, 10);
+    /// let b: Interval<i32> = Interval::closed(1, 9);
+    /// assert_eq!(a.within(&b), true);
+    ///
+    /// let a: Interval<i32> = Interval::closed(0, 10);
+    /// let b: Interval<i32> = Interval::closed(1, 9);
+    /// assert_eq!(a.within(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn within(&self, other: &Self) -> bool {
+        self.intersect(other) == *self
+    }
+
+    /// Returns `true` if every point of the given `Interval` is in the
+    /// `Interval`, and the `Interval`s are not equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 10);
+    /// let b: Interval<i32> = Interval::closed(0, 9);
+    /// assert_eq!(a.properly_contains(&b), true);
+    ///
+    /// let b: Interval<i32> = Interval::left_open(-1, 10);
+    /// assert_eq!(a.properly_contains(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn properly_contains(&self, other: &Self) -> bool {
+        other.within(self) && self != other
+    }
+
+    /// Returns `true` if every point of the `Interval` is less than every
+    /// point of the given `Interval`. This is vacuously `true` if either
+    /// `Interval` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 4);
+    /// assert_eq!(a.entirely_before(&Interval::closed(5, 9)), true);
+    /// assert_eq!(a.entirely_before(&Interval::closed(4, 9)), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn entirely_before(&self, other: &Self) -> bool {
+        match (self.0.upper_bound(), other.0.lower_bound()) {
+            (Some(upper), Some(lower)) => upper.precedes_lower(&lower),
+            _                          => true,
+        }
+    }
+
+    /// Returns `true` if every point of the `Interval` is greater than every
+    /// point of the given `Interval`. This is vacuously `true` if either
+    /// `Interval` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(5, 9);
+    /// assert_eq!(a.entirely_after(&Interval::closed(0, 4)), true);
+    /// assert_eq!(a.entirely_after(&Interval::unbounded_to(7)), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn entirely_after(&self, other: &Self) -> bool {
+        other.entirely_before(self)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `Interval`s containing all points not contained in the 
+    /// [`Interval`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(-3, 5);
+    /// 
+    /// assert_eq!(interval.complement().collect::<Vec<_>>(), 
+    ///     [Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> impl Iterator<Item=Self> {
+        self.0
+            .complement()
+            .map(Normalize::normalized)
+            .map(Interval)
+    }
+    
+    /// Returns the largest `Interval` whose points are all contained entirely
+    /// within the `Interval` and the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.intersect(&b), Interval::closed(4, 7));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.0.intersect(&other.0).normalized().into()
+    }
+
+    /// Returns the `Interval`s containing all points in the `Interval` and the
+    /// given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.union(&b).collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> impl Iterator<Item=Self> {
+        // TODO: Fix intervals that are adjacent after normalization.
+        self.0
+            .union(&other.0)
+            .map(Normalize::normalized)
+            .map(Interval)
+    }
+    
+    /// Returns the `Interval`s containing all points in the `Interval` which
+    /// are not in the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 7);
+    /// let b: Interval<i32> = Interval::closed(4, 13);
+    /// assert_eq!(a.minus(&b).collect::<Vec<_>>(),
+    ///     [Interval::right_open(-3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
+        // TODO: Fix intervals that are adjacent after normalization.
+        self.0
+            .minus(&other.0)
+            .map(Normalize::normalized)
+            .map(Interval)
+    }
+
+    /// Partitions the `Interval` by the given `Interval`, returning the parts
+    /// of the `Interval` which are strictly below, intersecting, and strictly
+    /// above the given `Interval`, in that order. If the given `Interval` is
+    /// empty, the entire `Interval` is returned as the part below it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 13);
+    /// let b: Interval<i32> = Interval::closed(4, 7);
+    ///
+    /// assert_eq!(a.split_by(&b), (
+    ///     Interval::closed(-3, 3),
+    ///     Interval::closed(4, 7),
+    ///     Interval::closed(8, 13)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn split_by(&self, other: &Self) -> (Self, Self, Self) {
+        use Bound::*;
+        let (Some(lower), Some(upper)) = (
+                other.lower_bound(),
+                other.upper_bound())
+            else { return (self.clone(), Self::empty(), Self::empty()) };
+        let below = match lower {
+            Include(p) => RawInterval::UpTo(p),
+            Exclude(p) => RawInterval::To(p),
+            Infinite   => RawInterval::Empty,
+        };
+        let above = match upper {
+            Include(p) => RawInterval::UpFrom(p),
+            Exclude(p) => RawInterval::From(p),
+            Infinite   => RawInterval::Empty,
+        };
+        (
+            Self(self.0.intersect(&below).normalized()),
+            self.intersect(other),
+            Self(self.0.intersect(&above).normalized()),
+        )
+    }
+
+    /// Returns the smallest `Interval` that contains all of the points
+    /// contained within the `Interval` and the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(-3, 5);
+    /// let b: Interval<i32> = Interval::closed(9, 13);
+    /// assert_eq!(a.enclose(&b), Interval::closed(-3, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn enclose(&self, other: &Self) -> Self {
+        self.0.enclose(&other.0).normalized().into()
+    }
+
+    /// Returns the smallest closed `Interval` containing all of the given
+    /// points, or an empty `Interval` if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let samples = [12, -4, 7, 30, 0];
+    /// assert_eq!(Interval::enclose_points(samples.iter().copied()),
+    ///     Interval::closed(-4, 30));
+    ///
+    /// assert_eq!(Interval::<i32>::enclose_points(None), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose_points<I>(points: I) -> Self
+        where I: IntoIterator<Item=T>
+    {
+        let mut points = points.into_iter();
+        let Some(first) = points.next() else { return Self::empty() };
+        let (lower, upper) = points.fold((first.clone(), first),
+            |(lower, upper), p| {
+                if p < lower {
+                    (p, upper)
+                } else if p > upper {
+                    (lower, p)
+                } else {
+                    (lower, upper)
+                }
+            });
+        Self::closed(lower, upper)
+    }
+
+    /// Returns the smallest `Interval` containing all of the points in the
+    /// given `Interval`s, or an empty `Interval` if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let intervals = [
+    ///     Interval::closed(3, 5),
+    ///     Interval::empty(),
+    ///     Interval::open(-2, 1),
+    /// ];
+    /// assert_eq!(Interval::enclose_intervals(intervals.iter().copied()),
+    ///     Interval::closed(-1, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose_intervals<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=Self>
+    {
+        RawInterval::enclose_all(intervals.into_iter().map(|i| i.0))
+            .normalized()
+            .into()
+    }
+
+    /// Returns the smallest closed `Interval` containing all of the points in 
+    /// this `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(-3, 7);
+    /// assert_eq!(interval.closure(), Interval::closed(-2, 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn closure(&self) -> Self {
+        self.0.closure().normalized().into()
+    }
+
+    /// Returns the largest open `Interval` containing only points in this
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 7);
+    /// assert_eq!(interval.interior(), Interval::closed(-2, 6));
+    ///
+    /// let interval: Interval<i32> = Interval::point(4);
+    /// assert_eq!(interval.interior(), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn interior(&self) -> Self {
+        self.0.interior().normalized().into()
+    }
+
+    /// Returns an iterator over the finite bound points of the `Interval`,
+    /// least first. An empty `Interval` has no boundary points, a point
+    /// `Interval` has one, and an `Interval` which is unbounded on a side has
+    /// no boundary point on that side.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(-3, 7);
+    /// assert_eq!(interval.boundary_points().collect::<Vec<_>>(), [-2, 6]);
+    ///
+    /// let interval: Interval<i32> = Interval::point(4);
+    /// assert_eq!(interval.boundary_points().collect::<Vec<_>>(), [4]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn boundary_points(&self) -> impl Iterator<Item=T> {
+        let lower = self.infimum();
+        let upper = self.supremum().filter(|u| lower.as_ref() != Some(u));
+        lower.into_iter().chain(upper)
+    }
+
+    // Widening and narrowing
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the widening of the `Interval` by another, as used in the
+    /// interval domain of abstract interpretation.
+    ///
+    /// Each bound of the `Interval` which the other `Interval` extends beyond
+    /// is dropped, leaving the result unbounded on that side. Repeated
+    /// widening therefore stabilizes after at most two steps, ensuring that
+    /// fixpoint iterations terminate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 10);
+    ///
+    /// assert_eq!(a.widen(&Interval::closed(0, 11)),
+    ///     Interval::unbounded_from(0));
+    /// assert_eq!(a.widen(&Interval::closed(-1, 5)),
+    ///     Interval::unbounded_to(10));
+    /// assert_eq!(a.widen(&Interval::closed(2, 8)), a);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn widen(&self, other: &Self) -> Self {
+        let (Some(lower), Some(upper)) = (
+            self.lower_bound(),
+            self.upper_bound()) else { return other.clone() };
+        let (Some(other_lower), Some(other_upper)) = (
+            other.lower_bound(),
+            other.upper_bound()) else { return self.clone() };
+
+        let lower = if lower.least_union(&other_lower) == lower {
+            lower
+        } else {
+            Bound::Infinite
+        };
+        let upper = if upper.greatest_union(&other_upper) == upper {
+            upper
+        } else {
+            Bound::Infinite
+        };
+        Self::new(lower, upper)
+    }
+
+    /// Returns the narrowing of the `Interval` by another, as used in the
+    /// interval domain of abstract interpretation.
+    ///
+    /// Each unbounded side of the `Interval` is replaced by the corresponding
+    /// bound of the other `Interval`, recovering precision lost by
+    /// [`widen`]. Bounded sides are kept.
+    ///
+    /// [`widen`]: #method.widen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::unbounded_from(0);
+    ///
+    /// assert_eq!(a.narrow(&Interval::closed(-5, 100)),
+    ///     Interval::closed(0, 100));
+    /// assert_eq!(a.narrow(&Interval::empty()), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn narrow(&self, other: &Self) -> Self {
+        let (Some(lower), Some(upper)) = (
+            self.lower_bound(),
+            self.upper_bound()) else { return Self::empty() };
+        let (Some(other_lower), Some(other_upper)) = (
+            other.lower_bound(),
+            other.upper_bound()) else { return Self::empty() };
+
+        let full = Self::full();
+        let lower = if full.lower_bound().as_ref() == Some(&lower) {
+            other_lower
+        } else {
+            lower
+        };
+        let upper = if full.upper_bound().as_ref() == Some(&upper) {
+            other_upper
+        } else {
+            upper
+        };
+        Self::new(lower, upper)
+    }
+
+    // Function application
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the image of the `Interval` under a monotone function, which is
+    /// the smallest `Interval` containing the result of applying the function
+    /// to each point of the `Interval`.
+    ///
+    /// The bounds of the `Interval` are mapped through the function, and are
+    /// swapped if the function is decreasing. The open bounds of strictly
+    /// monotone functions remain open, while those of other monotone functions
+    /// are closed, as their bound value may be reached from inside the
+    /// `Interval`. The result is unspecified if the function does not have the
+    /// given [`Monotonicity`].
+    ///
+    /// [`Monotonicity`]: enum.Monotonicity.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval::Monotonicity;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 7);
+    ///
+    /// assert_eq!(interval.image(|x| x * 2, Monotonicity::Increasing),
+    ///     Interval::closed(-6, 14));
+    /// assert_eq!(interval.image(|x| 10 - x, Monotonicity::Decreasing),
+    ///     Interval::closed(3, 13));
+    /// assert_eq!(interval.image(|x| x / 4, Monotonicity::NonDecreasing),
+    ///     Interval::closed(0, 1));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn image<U, F>(&self, f: F, monotonicity: Monotonicity) -> Interval<U>
+        where
+            U: Ord + Clone,
+            RawInterval<U>: Normalize,
+            F: Fn(&T) -> U,
+    {
+        use Monotonicity::*;
+        let (Some(lower), Some(upper)) = (
+            self.lower_bound(),
+            self.upper_bound()) else { return Interval::empty() };
+
+        let map = |bound: Bound<T>| {
+            let strict = matches!(monotonicity, Increasing | Decreasing);
+            match bound {
+                Bound::Exclude(p) if strict => Bound::Exclude(f(&p)),
+                Bound::Include(p) |
+                Bound::Exclude(p)           => Bound::Include(f(&p)),
+                Bound::Infinite             => Bound::Infinite,
+            }
+        };
+        match monotonicity {
+            Increasing | NonDecreasing => Interval::new(map(lower), map(upper)),
+            Decreasing | NonIncreasing => Interval::new(map(upper), map(lower)),
+        }
+    }
+
+    /// Applies a function to each [`Bound`] of the `Interval`, returning the
+    /// normalized `Interval` between the results. The function should
+    /// preserve the order of the bound points, which is checked in debug
+    /// builds. Returns an empty `Interval` if the `Interval` is empty.
+    ///
+    /// The bounds are those of the normalized `Interval`, so the unbounded
+    /// ends of [`Finite`] types are given as their extreme points.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the function reverses the order of the bound
+    /// points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let seconds: Interval<u64> = Interval::right_open(2, 5);
+    /// let millis = seconds.map_bounds(|b| b.map(|s| s * 1000));
+    ///
+    /// assert_eq!(millis, Interval::closed(2000, 4000));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map_bounds<U, F>(&self, f: F) -> Interval<U>
+        where
+            U: Ord + Clone,
+            RawInterval<U>: Normalize,
+            F: Fn(Bound<T>) -> Bound<U>,
+    {
+        let (Some(lower), Some(upper)) = (
+            self.lower_bound(),
+            self.upper_bound()) else { return Interval::empty() };
+
+        let (lower, upper) = (f(lower), f(upper));
+        if let (Some(l), Some(u)) = (lower.as_ref(), upper.as_ref()) {
+            debug_assert!(l <= u, "map_bounds reversed the bound order");
+        }
+        Interval::new(lower, upper)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<RawInterval<T>> for Interval<T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(raw_interval: RawInterval<T>) -> Self {
+        Self(raw_interval.normalized())
+    }
+}
+
+// NOTE: Conflicts with From<RangeFull> convertion.
+impl<T> From<T> for Interval<T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(point: T) -> Self {
+        Self(RawInterval::Point(point).normalized())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<Range<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: Range<T>) -> Self {
+        Self(RawInterval::right_open(r.start, r.end).normalized())
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeInclusive<T>) -> Self {
+        if r.is_empty() {
+            return Self::empty();
+        }
+        let (start, end) = r.into_inner();
+        Self(RawInterval::closed(start, end).normalized())
+    }
+}
+
+impl<T> From<RangeFrom<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeFrom<T>) -> Self {
+        Self(RawInterval::From(r.start).normalized())
+    }
+}
+
+impl<T> From<RangeTo<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeTo<T>) -> Self {
+        Self(RawInterval::UpTo(r.end).normalized())
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeToInclusive<T>) -> Self {
+        Self(RawInterval::To(r.end).normalized())
+    }
+}
+
+impl<T> From<RangeFull> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(_r: RangeFull) -> Self {
+        Self(RawInterval::Full.normalized())
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer conversions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements lossless conversions from `Interval`s of builtin integer types
+/// to `Interval`s of wider integer types.
+macro_rules! std_integer_widening_impl {
+    // For each given type and the types it widens to...
+    ($($s:ident => $($t:ident),*;)*) => {
+        $($(impl From<Interval<$s>> for Interval<$t> {
+            fn from(interval: Interval<$s>) -> Self {
+                match (interval.0.lower_point(), interval.0.upper_point()) {
+                    (Some(&l), Some(&u)) =>
+                        Self::closed($t::from(l), $t::from(u)),
+                    _ => Self::empty(),
+                }
+            }
+        })*)*
+    };
+}
+
+/// Implements checked conversions from `Interval`s of builtin integer types
+/// to `Interval`s of integer types which may not hold all of their points.
+macro_rules! std_integer_narrowing_impl {
+    // For each given type and the types it narrows to...
+    ($($s:ident => $($t:ident),*;)*) => {
+        $($(impl TryFrom<Interval<$s>> for Interval<$t> {
+            type Error = TryFromIntError;
+
+            fn try_from(interval: Interval<$s>) -> Result<Self, Self::Error> {
+                match (interval.0.lower_point(), interval.0.upper_point()) {
+                    (Some(&l), Some(&u)) =>
+                        Ok(Self::closed($t::try_from(l)?, $t::try_from(u)?)),
+                    _ => Ok(Self::empty()),
+                }
+            }
+        })*)*
+    };
+}
+
+// Provide conversions mirroring those of the builtin integer types.
+std_integer_widening_impl![
+    u8    => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize;
+    u16   => u32, u64, u128, usize, i32, i64, i128;
+    u32   => u64, u128, i64, i128;
+    u64   => u128, i128;
+    i8    => i16, i32, i64, i128, isize;
+    i16   => i32, i64, i128, isize;
+    i32   => i64, i128;
+    i64   => i128;
+];
+
+std_integer_narrowing_impl![
+    u8    => i8;
+    u16   => u8, i8, i16, isize;
+    u32   => u8, u16, usize, i8, i16, i32, isize;
+    u64   => u8, u16, u32, usize, i8, i16, i32, i64, isize;
+    u128  => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize;
+    usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize;
+    i8    => u8, u16, u32, u64, u128, usize;
+    i16   => u8, u16, u32, u64, u128, usize, i8;
+    i32   => u8, u16, u32, u64, u128, usize, i8, i16, isize;
+    i64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize;
+    i128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize;
+    isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128;
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer sign operations
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements negation of `Interval`s of builtin signed integer types, as the
+/// `Interval` of the negated points. The negation of the minimum point is not
+/// representable, so it is dropped.
+macro_rules! std_integer_neg_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Neg for Interval<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.reflected(&0)
+            }
+        })*
+    };
+}
+
+/// Implements the absolute value of `Interval`s of builtin signed integer
+/// types.
+macro_rules! std_integer_abs_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Interval<$t> {
+            /// Returns the `Interval` of the absolute values of the points in
+            /// the `Interval`. The absolute value of the minimum point is not
+            /// representable, so it is dropped.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use std::error::Error;
+            /// # use normalize_interval::Interval;
+            /// # fn main() -> Result<(), Box<dyn Error>> {
+            /// # //-------------------------------------------------------------
+            /// let interval: Interval<i32> = Interval::closed(-7, 3);
+            /// assert_eq!(interval.abs(), Interval::closed(0, 7));
+            ///
+            /// let interval: Interval<i32> = Interval::right_open(-7, -3);
+            /// assert_eq!(interval.abs(), Interval::closed(4, 7));
+            /// # //-------------------------------------------------------------
+            /// #     Ok(())
+            /// # }
+            /// ```
+            #[must_use]
+            pub fn abs(&self) -> Self {
+                // The negative and nonnegative points are mapped separately,
+                // and their images overlap at zero if both are nonempty.
+                let negative = self.intersect(&Self::unbounded_to(0));
+                let nonnegative = self.intersect(&Self::unbounded_from(0));
+                (-negative).enclose(&nonnegative)
+            }
+        })*
+    };
+}
+
+// Provide negation mirroring that of the builtin signed integer types.
+std_integer_neg_impl![i8, i16, i32, i64, i128, isize];
+std_integer_abs_impl![i8, i16, i32, i64, i128, isize];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer modular reduction
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements the Euclidean remainders of `Interval`s of builtin integer
+/// types.
+macro_rules! std_integer_rem_euclid_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Interval<$t> {
+            /// Returns the `Selection` of the Euclidean remainders of the
+            /// points in the `Interval` modulo the given modulus. This is
+            /// every remainder if the `Interval` has at least `modulus`
+            /// points, and otherwise one `Interval`, or two if the remainders
+            /// wrap around past zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is not positive.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use std::error::Error;
+            /// # use normalize_interval::Interval;
+            /// # use normalize_interval::Selection;
+            /// # fn main() -> Result<(), Box<dyn Error>> {
+            /// # //-------------------------------------------------------------
+            /// let slots: Interval<i32> = Interval::closed(6, 9);
+            /// assert_eq!(slots.rem_euclid(8), Selection::from([
+            ///     Interval::closed(0, 1),
+            ///     Interval::closed(6, 7),
+            /// ]));
+            ///
+            /// assert_eq!(Interval::<i32>::closed(-2, 1).rem_euclid(8),
+            ///     Selection::from([
+            ///         Interval::closed(0, 1),
+            ///         Interval::closed(6, 7),
+            ///     ]));
+            /// assert_eq!(Interval::<i32>::closed(3, 12).rem_euclid(8),
+            ///     Selection::from(Interval::closed(0, 7)));
+            /// # //-------------------------------------------------------------
+            /// #     Ok(())
+            /// # }
+            /// ```
+            #[must_use]
+            pub fn rem_euclid(&self, modulus: $t) -> Selection<$t> {
+                assert!(modulus > 0, "modulus must be positive");
+                let (Some(&l), Some(&u)) = (
+                    self.0.lower_point(),
+                    self.0.upper_point()) else { return Selection::new() };
+
+                // An overflowing width is larger than any modulus.
+                if u.checked_sub(l).is_none_or(|width| width >= modulus - 1) {
+                    return Selection::from(Self::closed(0, modulus - 1));
+                }
+                let (l, u) = (l.rem_euclid(modulus), u.rem_euclid(modulus));
+                if l <= u {
+                    Selection::from(Self::closed(l, u))
+                } else {
+                    Selection::from([
+                        Self::closed(0, u),
+                        Self::closed(l, modulus - 1),
+                    ])
+                }
+            }
+        })*
+    };
+}
+
+// Provide remainders mirroring those of the builtin integer types.
+std_integer_rem_euclid_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Finite iteration support
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Interval<T> where T: Ord + Clone + Finite {
+    /// The `Interval` of all points of a [`Finite`] type, with both bounds
+    /// closed. Equal to [`Interval::full`], but usable in constant
+    /// expressions and patterns.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`Interval::full`]: #method.full
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// const PORTS: Interval<u16> = Interval::FULL_CLOSED;
+    ///
+    /// assert_eq!(PORTS, Interval::full());
+    /// assert_eq!(PORTS, Interval::closed(0, u16::MAX));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub const FULL_CLOSED: Self = Self(
+        RawInterval::Closed(T::MINIMUM, T::MAXIMUM));
+
+    /// Returns an `Iterator` over the points in the `Interval`. Only defined
+    /// for `Finite` `Interval`s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.iter().collect::<Vec<_>>(), [4, 5, 6]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The `Interval` can be iterated in both directions.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.iter().rev().collect::<Vec<_>>(), [6, 5, 4]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            inner: self.clone(),
+        }
+    }
+
+    /// Returns an `Iterator` over the points in the `Interval` which are
+    /// greater than or equal to the given point, starting from the least.
+    ///
+    /// The first point is found directly from the bounds of the `Interval`,
+    /// so resuming an iteration is as cheap as starting one.
     ///
     /// # Example
     ///
@@ -1557,23 +3033,26 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(-3, 5);
-    /// 
-    /// assert_eq!(interval.complement().collect::<Vec<_>>(), 
-    ///     [Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
+    /// let ids: Interval<u64> = Interval::closed(1, u64::MAX);
+    /// let checkpoint = 1 << 40;
+    /// assert_eq!(ids.iter_from(checkpoint).take(3).collect::<Vec<_>>(),
+    ///     [checkpoint, checkpoint + 1, checkpoint + 2]);
+    ///
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.iter_from(0).collect::<Vec<_>>(), [4, 5, 6]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn complement(&self) -> impl Iterator<Item=Self> {
-        self.0
-            .complement()
-            .map(Normalize::normalized)
-            .map(Interval)
+    pub fn iter_from(&self, point: T) -> Iter<T> {
+        self.intersect(&Self::unbounded_from(point)).iter()
     }
-    
-    /// Returns the largest `Interval` whose points are all contained entirely
-    /// within the `Interval` and the given `Interval`.
+
+    /// Returns an `Iterator` over the points in the `Interval` which are less
+    /// than or equal to the given point, starting from the greatest.
+    ///
+    /// The first point is found directly from the bounds of the `Interval`,
+    /// so resuming an iteration is as cheap as starting one.
     ///
     /// # Example
     ///
@@ -1582,20 +3061,31 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.intersect(&b), Interval::closed(4, 7));
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.iter_rev_from(5).collect::<Vec<_>>(), [5, 4]);
+    /// assert_eq!(interval.iter_rev_from(3).count(), 0);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    #[must_use]
-    pub fn intersect(&self, other: &Self) -> Self {
-        self.0.intersect(&other.0).normalized().into()
+    pub fn iter_rev_from(&self, point: T) -> Rev<Iter<T>> {
+        self.intersect(&Self::unbounded_to(point)).iter().rev()
     }
 
-    /// Returns the `Interval`s containing all points in the `Interval` and the
-    /// given `Interval`.
+    /// Returns an `Iterator` over the points in the `Interval`, or an error if
+    /// the `Interval` is not in its normalized form.
+    ///
+    /// [`iter`] will recover from an unnormalized `Interval` by normalizing it
+    /// during iteration. This method instead checks the normalization
+    /// invariant before any iteration occurs.
+    ///
+    /// [`iter`]: #method.iter
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NormalizationError`] if the `Interval` is not normalized.
+    ///
+    /// [`NormalizationError`]: struct.NormalizationError.html
     ///
     /// # Example
     ///
@@ -1604,24 +3094,29 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.union(&b).collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 13)]);
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// let points = interval.points().expect("normalized interval");
+    /// assert_eq!(points.collect::<Vec<_>>(), [4, 5, 6]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union(&self, other: &Self) -> impl Iterator<Item=Self> {
-        // TODO: Fix intervals that are adjacent after normalization.
-        self.0
-            .union(&other.0)
-            .map(Normalize::normalized)
-            .map(Interval)
+    pub fn points(&self) -> Result<Iter<T>, NormalizationError> {
+        if self.0 == self.0.clone().normalized() {
+            Ok(self.iter())
+        } else {
+            Err(NormalizationError)
+        }
     }
-    
-    /// Returns the `Interval`s containing all points in the `Interval` which
-    /// are not in the given `Interval`.
+
+    /// Returns the smallest `Interval` covering this one whose bounds are
+    /// aligned to whole blocks of the given quantum. Blocks start at multiples
+    /// of the quantum, and blocks extending past the range of the type are
+    /// truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
     ///
     /// # Example
     ///
@@ -1630,24 +3125,31 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 7);
-    /// let b: Interval<i32> = Interval::closed(4, 13);
-    /// assert_eq!(a.minus(&b).collect::<Vec<_>>(),
-    ///     [Interval::right_open(-3, 4)]);
+    /// let interval: Interval<u32> = Interval::closed(100, 5000);
+    ///
+    /// assert_eq!(interval.aligned_outward(&4096), Interval::closed(0, 8191));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
-        // TODO: Fix intervals that are adjacent after normalization.
-        self.0
-            .minus(&other.0)
-            .map(Normalize::normalized)
-            .map(Interval)
+    #[must_use]
+    pub fn aligned_outward(&self, quantum: &T) -> Self where T: Align {
+        match (self.0.lower_point(), self.0.upper_point()) {
+            (Some(l), Some(u)) => Self::closed(
+                l.block_start(quantum).unwrap_or(T::MINIMUM),
+                u.block_end(quantum).unwrap_or(T::MAXIMUM)),
+            _ => Self::empty(),
+        }
     }
 
-    /// Returns the smallest `Interval` that contains all of the points
-    /// contained within the `Interval` and the given `Interval`.
+    /// Returns the largest `Interval` covered by this one whose bounds are
+    /// aligned to whole blocks of the given quantum. Blocks start at multiples
+    /// of the quantum, and blocks extending past the range of the type are
+    /// excluded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
     ///
     /// # Example
     ///
@@ -1656,20 +3158,42 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Interval<i32> = Interval::closed(-3, 5);
-    /// let b: Interval<i32> = Interval::closed(9, 13);
-    /// assert_eq!(a.enclose(&b), Interval::closed(-3, 13));
+    /// let interval: Interval<u32> = Interval::closed(100, 9000);
+    ///
+    /// assert_eq!(interval.aligned_inward(&4096),
+    ///     Interval::closed(4096, 8191));
+    ///
+    /// let interval: Interval<u32> = Interval::closed(100, 5000);
+    ///
+    /// assert_eq!(interval.aligned_inward(&4096), Interval::empty());
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn enclose(&self, other: &Self) -> Self {
-        self.0.enclose(&other.0).normalized().into()
+    pub fn aligned_inward(&self, quantum: &T) -> Self where T: Align {
+        let (Some(l), Some(u)) = (self.0.lower_point(), self.0.upper_point())
+            else { return Self::empty() };
+        let lower = if l.block_start(quantum).as_ref() == Some(l) {
+            Some(l.clone())
+        } else {
+            l.block_end(quantum).and_then(|end| end.succ())
+        };
+        let upper = if u.block_end(quantum).as_ref() == Some(u) {
+            Some(u.clone())
+        } else {
+            u.block_start(quantum).and_then(|start| start.pred())
+        };
+        match (lower, upper) {
+            (Some(l), Some(u)) => Self::closed(l, u),
+            _                  => Self::empty(),
+        }
     }
 
-    /// Returns the smallest closed `Interval` containing all of the points in 
-    /// this `Interval`.
+    /// Returns the mirror image of the `Interval` around the given center
+    /// point, so that each point moves to the other side of the center at
+    /// the same distance. The points whose mirror images are not
+    /// representable are dropped.
     ///
     /// # Example
     ///
@@ -1678,155 +3202,245 @@ impl<T> Interval<T>
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(-3, 7);
-    /// assert_eq!(interval.closure(), Interval::closed(-2, 6));
+    /// let interval: Interval<i32> = Interval::right_open(2, 6);
+    /// assert_eq!(interval.reflected(&0), Interval::left_open(-6, -2));
+    ///
+    /// let interval: Interval<u8> = Interval::closed(100, 200);
+    /// assert_eq!(interval.reflected(&50), Interval::closed(0, 0));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn closure(&self) -> Self {
-        self.0.closure().normalized().into()
+    pub fn reflected(&self, center: &T) -> Self where T: Reflect {
+        let (Some(l), Some(u)) = (self.0.lower_point(), self.0.upper_point())
+            else { return Self::empty() };
+        // An unrepresentable mirror image lies beyond the minimum point if
+        // the point is above the center, and beyond the maximum otherwise.
+        let lower = match u.reflect(center) {
+            Some(p)            => Bound::Include(p),
+            None if u > center => Bound::Infinite,
+            None               => return Self::empty(),
+        };
+        let upper = match l.reflect(center) {
+            Some(p)            => Bound::Include(p),
+            None if l < center => Bound::Infinite,
+            None               => return Self::empty(),
+        };
+        Self::new(lower, upper)
     }
 }
 
 
 ////////////////////////////////////////////////////////////////////////////////
-// Conversion traits
+// Start and length conversions
 ////////////////////////////////////////////////////////////////////////////////
+impl Interval<usize> {
+    /// Constructs an `Interval` from a start index and a length, as used by
+    /// diff hunks and `splice`-style arguments. The `Interval` contains the
+    /// `len` indices beginning at `start`.
+    ///
+    /// A zero-length pair produces an empty `Interval`, regardless of its
+    /// start. A pair extending past `usize::MAX` is clamped to `usize::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::from_start_len(3, 4), Interval::closed(3, 6));
+    /// assert_eq!(Interval::from_start_len(3, 0), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn from_start_len(start: usize, len: usize) -> Self {
+        match (len, start.checked_add(len)) {
+            (0, _)         => Self::empty(),
+            (_, Some(end)) => Self::right_open(start, end),
+            (_, None)      => Self::unbounded_from(start),
+        }
+    }
 
-impl<T> From<RawInterval<T>> for Interval<T> 
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(raw_interval: RawInterval<T>) -> Self {
-        Self(raw_interval.normalized())
+    /// Returns the start index and length of the `Interval`, or `None` if the
+    /// `Interval` is empty.
+    ///
+    /// An empty `Interval` has no start, so a zero-length pair will not
+    /// survive a round trip through an `Interval`. The length of the `Interval`
+    /// containing every `usize` is saturated to `usize::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::closed(3, 6).to_start_len(), Some((3, 4)));
+    /// assert_eq!(Interval::<usize>::empty().to_start_len(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_start_len(&self) -> Option<(usize, usize)> {
+        let start = *self.0.lower_point()?;
+        let end = *self.0.upper_point()?;
+        Some((start, (end - start).saturating_add(1)))
     }
 }
 
-// NOTE: Conflicts with From<RangeFull> convertion.
-impl<T> From<T> for Interval<T> 
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(point: T) -> Self {
-        Self(RawInterval::Point(point).normalized())
+
+////////////////////////////////////////////////////////////////////////////////
+// Random generation
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature="rand")]
+impl<T> Interval<T> where T: Ord + Clone + Finite + SampleUniform {
+    /// Returns a random non-empty sub-interval of the `Interval`, or an empty
+    /// `Interval` if the `Interval` is empty. Requires the `rand` feature.
+    ///
+    /// The bounds of the sub-interval are drawn uniformly from the points of
+    /// the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 99);
+    /// let sub = interval.random_subinterval(&mut rand::thread_rng());
+    ///
+    /// assert!(!sub.is_empty());
+    /// assert_eq!(interval.intersect(&sub), sub);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn random_subinterval<R>(&self, rng: &mut R) -> Self
+        where R: Rng + ?Sized
+    {
+        let (Some(lower), Some(upper)) = (self.infimum(), self.supremum())
+            else { return Self::empty() };
+
+        let a = rng.gen_range(lower.clone()..=upper.clone());
+        let b = rng.gen_range(lower..=upper);
+        Self::closed(a.clone().min(b.clone()), a.max(b))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
-// Conversion traits
+// Monotonicity
 ////////////////////////////////////////////////////////////////////////////////
-
-impl<T> From<Range<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: Range<T>) -> Self {
-        Self(RawInterval::right_open(r.start, r.end).normalized())
-    }
+/// The direction in which a function preserves order. Used to compute the
+/// [`Interval::image`] of an `Interval` under a function.
+///
+/// [`Interval::image`]: struct.Interval.html#method.image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Monotonicity {
+    /// The function is strictly increasing, so `a < b` implies `f(a) < f(b)`.
+    Increasing,
+    /// The function is strictly decreasing, so `a < b` implies `f(a) > f(b)`.
+    Decreasing,
+    /// The function is increasing, so `a < b` implies `f(a) <= f(b)`.
+    NonDecreasing,
+    /// The function is decreasing, so `a < b` implies `f(a) >= f(b)`.
+    NonIncreasing,
 }
 
-// // TODO: Fix RangeInclusive once the accessors become available.
-// impl<T> From<RangeInclusive<T>> for Interval<T>
-//     where
-//         T: Ord + Clone,
-//         RawInterval<T>: Normalize,
-// {
-//     fn from(r: RangeInclusive<T>) -> Self {
-//         Self(RawInterval::closed(
-//             r.next().unwrap(),
-//             r.next_back().unwrap()).normalized())
-//     }
-// }
 
-impl<T> From<RangeFrom<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: RangeFrom<T>) -> Self {
-        Self(RawInterval::From(r.start).normalized())
-    }
+////////////////////////////////////////////////////////////////////////////////
+// BoundConflict
+////////////////////////////////////////////////////////////////////////////////
+/// The resolution of lower and upper bounds at the same point with
+/// conflicting openness, such as `[3,3)`. Used by
+/// [`Interval::from_bounds_lenient`].
+///
+/// Bounds which both include the point always give the point, and bounds
+/// which are out of order always give an empty `Interval`.
+///
+/// [`Interval::from_bounds_lenient`]: struct.Interval.html#method.from_bounds_lenient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundConflict {
+    /// Include the point if either bound excludes it.
+    PreferPoint,
+    /// Exclude the point if either bound excludes it.
+    PreferEmpty,
 }
 
-impl<T> From<RangeTo<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: RangeTo<T>) -> Self {
-        Self(RawInterval::UpTo(r.end).normalized())
-    }
-}
 
-impl<T> From<RangeToInclusive<T>> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(r: RangeToInclusive<T>) -> Self {
-        Self(RawInterval::To(r.end).normalized())
-    }
-}
+////////////////////////////////////////////////////////////////////////////////
+// NormalizationError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when an `Interval` is not in its normalized form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NormalizationError;
 
-impl<T> From<RangeFull> for Interval<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn from(_r: RangeFull) -> Self {
-        Self(RawInterval::Full.normalized())
+impl std::fmt::Display for NormalizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "interval is not normalized")
     }
 }
 
+impl std::error::Error for NormalizationError {}
+
+
 
 ////////////////////////////////////////////////////////////////////////////////
-// Finite iteration support
+// Stepped iteration support
 ////////////////////////////////////////////////////////////////////////////////
-impl<T> Interval<T> where T: Ord + Clone + Finite {
-    /// Returns an `Iterator` over the points in the `Interval`. Only defined
-    /// for `Finite` `Interval`s.
+impl<T> Interval<T> where T: Ord + Clone {
+    /// Returns an `Iterator` over the points in the `Interval` produced by
+    /// repeatedly applying the given successor function, starting from the
+    /// least point of the `Interval`. Unlike [`iter`], this does not require
+    /// the points to be [`Finite`].
+    ///
+    /// Iteration stops when the successor function returns `None`, when it
+    /// returns a point outside of the `Interval`, or when it returns a point
+    /// that is not greater than the previous one. If the `Interval` has an
+    /// infinite lower bound, there is no starting point and nothing is
+    /// iterated.
+    ///
+    /// [`iter`]: #method.iter
+    /// [`Finite`]: ../normalize/trait.Finite.html
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use normalize_interval::Interval;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(3, 7);
-    /// assert_eq!(interval.iter().collect::<Vec<_>>(), [4, 5, 6]);
-    /// # //-------------------------------------------------------------------
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// The `Interval` can be iterated in both directions.
+    /// let interval: Interval<i32> = Interval::closed(0, 10);
+    /// let evens = interval.iter_with(|p| p.checked_add(2));
     ///
-    /// ```rust
-    /// # use std::error::Error;
-    /// # use normalize_interval::Interval;
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// # //-------------------------------------------------------------------
-    /// let interval: Interval<i32> = Interval::open(3, 7);
-    /// assert_eq!(interval.iter().rev().collect::<Vec<_>>(), [6, 5, 4]);
+    /// assert_eq!(evens.collect::<Vec<_>>(), [0, 2, 4, 6, 8, 10]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            inner: self.clone(),
+    pub fn iter_with<F>(&self, mut step: F) -> IterWith<T, F>
+        where F: FnMut(&T) -> Option<T>
+    {
+        let next = match self.0.lower_bound() {
+            Some(Bound::Include(l)) => Some(l),
+            Some(Bound::Exclude(l)) => step(&l),
+            _                       => None,
+        };
+        IterWith {
+            inner: self.0.clone(),
+            next,
+            step,
         }
     }
 }
 
 
-
 impl<T> IntoIterator for Interval<T>
     where T: Ord + Clone + Finite,
 {
@@ -1839,7 +3453,7 @@ impl<T> IntoIterator for Interval<T>
 }
 
 
-impl<'a, T> IntoIterator for &'a Interval<T>
+impl<T> IntoIterator for &Interval<T>
     where T: Ord + Clone + Finite,
 {
     type Item = T;
@@ -1875,7 +3489,17 @@ impl<T> Iterator for Iter<T>
                 Some(lb)
             },
             None => None,
-            _ => unreachable!("iter for Finite interval with open lower bound"),
+            _ => {
+                // The `Interval` is not normalized. Normalize it and try again,
+                // rather than aborting the iteration.
+                self.inner.0.normalize();
+                if matches!(self.inner.lower_bound(), Some(Bound::Include(_))) {
+                    self.next()
+                } else {
+                    self.inner = Interval::empty();
+                    None
+                }
+            },
         }
     }
 }
@@ -1893,7 +3517,17 @@ impl<T> DoubleEndedIterator for Iter<T>
                 Some(ub)
             },
             None => None,
-            _ => unreachable!("iter for Finite interval with open upper bound"),
+            _ => {
+                // The `Interval` is not normalized. Normalize it and try again,
+                // rather than aborting the iteration.
+                self.inner.0.normalize();
+                if matches!(self.inner.upper_bound(), Some(Bound::Include(_))) {
+                    self.next_back()
+                } else {
+                    self.inner = Interval::empty();
+                    None
+                }
+            },
         }
     }
 }
@@ -1902,3 +3536,50 @@ impl<T> FusedIterator for Iter<T>
     where
         T: Ord + Clone + Finite
 {}
+
+////////////////////////////////////////////////////////////////////////////////
+// IterWith
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points in an `Interval` produced by a successor
+/// function.
+pub struct IterWith<T, F> {
+    /// The `Interval` being iterated over.
+    inner: RawInterval<T>,
+    /// The next point to yield.
+    next: Option<T>,
+    /// The successor function.
+    step: F,
+}
+
+impl<T, F> std::fmt::Debug for IterWith<T, F> where T: std::fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IterWith")
+            .field("inner", &self.inner)
+            .field("next", &self.next)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> Iterator for IterWith<T, F>
+    where
+        T: Ord + Clone,
+        F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        if !self.inner.contains(&current) {
+            return None;
+        }
+        // Only continue if the successor function makes progress.
+        self.next = (self.step)(&current).filter(|next| next > &current);
+        Some(current)
+    }
+}
+
+impl<T, F> FusedIterator for IterWith<T, F>
+    where
+        T: Ord + Clone,
+        F: FnMut(&T) -> Option<T>,
+{}
@@ -0,0 +1,659 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides streaming algorithms over sorted sequences of intervals, and
+//! covering and scheduling algorithms over collections of intervals. The
+//! latter require the `algorithms` feature.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::iter::FusedIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Origin
+////////////////////////////////////////////////////////////////////////////////
+/// Identifies which inputs of [`pairwise_intersections`] an `Interval` was
+/// taken from.
+///
+/// [`pairwise_intersections`]: fn.pairwise_intersections.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The points are only in the first input.
+    A,
+    /// The points are only in the second input.
+    B,
+    /// The points are in both inputs.
+    Both,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// pairwise_intersections
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator over the pieces of two sorted sequences of disjoint
+/// `Interval`s, tagged with the [`Origin`] of their points.
+///
+/// The pieces are yielded in ascending order and together cover every point
+/// of both inputs. Each input must be sorted and contain no overlapping
+/// `Interval`s, such as the output of [`Selection::interval_iter`]. Empty
+/// `Interval`s in the inputs are ignored.
+///
+/// [`Origin`]: enum.Origin.html
+/// [`Selection::interval_iter`]: ../../selection/struct.Selection.html#method.interval_iter
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::algorithms::pairwise_intersections;
+/// # use normalize_interval::interval::algorithms::Origin;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 10), Interval::closed(20, 25)];
+/// let b = vec![Interval::closed(5, 22)];
+///
+/// assert_eq!(pairwise_intersections(a, b).collect::<Vec<_>>(), [
+///     (Interval::closed(0, 4),   Origin::A),
+///     (Interval::closed(5, 10),  Origin::Both),
+///     (Interval::closed(11, 19), Origin::B),
+///     (Interval::closed(20, 22), Origin::Both),
+///     (Interval::closed(23, 25), Origin::A),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn pairwise_intersections<T, A, B>(a: A, b: B)
+    -> PairwiseIntersections<T, A::IntoIter, B::IntoIter>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        A: IntoIterator<Item=Interval<T>>,
+        B: IntoIterator<Item=Interval<T>>,
+{
+    PairwiseIntersections {
+        a: a.into_iter(),
+        b: b.into_iter(),
+        current_a: None,
+        current_b: None,
+        pending: None,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PairwiseIntersections
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the tagged pieces of two sorted sequences of
+/// `Interval`s. Returned by [`pairwise_intersections`].
+///
+/// [`pairwise_intersections`]: fn.pairwise_intersections.html
+#[derive(Debug, Clone)]
+pub struct PairwiseIntersections<T, A, B> {
+    /// The first input sequence.
+    a: A,
+    /// The second input sequence.
+    b: B,
+    /// The unprocessed portion of the current `Interval` of the first input.
+    current_a: Option<Interval<T>>,
+    /// The unprocessed portion of the current `Interval` of the second input.
+    current_b: Option<Interval<T>>,
+    /// A piece to be yielded before any further processing.
+    pending: Option<(Interval<T>, Origin)>,
+}
+
+/// Returns the next nonempty `Interval` from the given iterator.
+fn next_nonempty<T, I>(iter: &mut I) -> Option<Interval<T>>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    iter.find(|interval| !interval.is_empty())
+}
+
+impl<T, A, B> Iterator for PairwiseIntersections<T, A, B>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        A: Iterator<Item=Interval<T>>,
+        B: Iterator<Item=Interval<T>>,
+{
+    type Item = (Interval<T>, Origin);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(piece) = self.pending.take() {
+            return Some(piece);
+        }
+
+        if self.current_a.is_none() {
+            self.current_a = next_nonempty(&mut self.a);
+        }
+        if self.current_b.is_none() {
+            self.current_b = next_nonempty(&mut self.b);
+        }
+
+        let (a, b) = match (self.current_a.take(), self.current_b.take()) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None)    => return Some((a, Origin::A)),
+            (None, Some(b))    => return Some((b, Origin::B)),
+            (None, None)       => return None,
+        };
+
+        // At most one input can have points below the other, and at most one
+        // can have points above it; the remainder above is kept for the next
+        // step.
+        let (a_below, both, a_above) = a.split_by(&b);
+        let (b_below, _, b_above) = b.split_by(&a);
+        if !a_above.is_empty() { self.current_a = Some(a_above); }
+        if !b_above.is_empty() { self.current_b = Some(b_above); }
+
+        let below = if a_below.is_empty() {
+            (!b_below.is_empty()).then_some((b_below, Origin::B))
+        } else {
+            Some((a_below, Origin::A))
+        };
+        let both = (!both.is_empty()).then_some((both, Origin::Both));
+
+        match (below, both) {
+            (Some(below), both) => {
+                self.pending = both;
+                Some(below)
+            },
+            (None, Some(both)) => Some(both),
+            (None, None)       => self.next(),
+        }
+    }
+}
+
+impl<T, A, B> FusedIterator for PairwiseIntersections<T, A, B>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        A: FusedIterator<Item=Interval<T>>,
+        B: FusedIterator<Item=Interval<T>>,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// union_sorted_streams
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator over the union of any number of sorted sequences of
+/// disjoint `Interval`s.
+///
+/// The union is computed lazily using a heap holding one `Interval` from each
+/// input, so memory use is proportional to the number of inputs rather than
+/// their lengths. The resulting `Interval`s are sorted, disjoint, and
+/// nonadjacent. Each input must be sorted and contain no overlapping
+/// `Interval`s, such as the output of [`Selection::interval_iter`]. Empty
+/// `Interval`s in the inputs are ignored.
+///
+/// [`Selection::interval_iter`]: ../../selection/struct.Selection.html#method.interval_iter
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::algorithms::union_sorted_streams;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let streams = vec![
+///     vec![Interval::closed(0, 3), Interval::closed(20, 25)],
+///     vec![Interval::closed(2, 6)],
+///     vec![Interval::closed(7, 9), Interval::closed(30, 31)],
+/// ];
+///
+/// assert_eq!(union_sorted_streams(streams).collect::<Vec<_>>(), [
+///     Interval::closed(0, 9),
+///     Interval::closed(20, 25),
+///     Interval::closed(30, 31),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn union_sorted_streams<T, S>(streams: S)
+    -> UnionSortedStreams<T, <S::Item as IntoIterator>::IntoIter>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        S: IntoIterator,
+        S::Item: IntoIterator<Item=Interval<T>>,
+{
+    let mut streams: Vec<_> = streams
+        .into_iter()
+        .map(IntoIterator::into_iter)
+        .collect();
+    let mut heap = BinaryHeap::with_capacity(streams.len());
+    for (index, stream) in streams.iter_mut().enumerate() {
+        if let Some(entry) = next_nonempty(stream)
+            .and_then(|interval| HeapEntry::new(interval, index))
+        {
+            heap.push(Reverse(entry));
+        }
+    }
+    UnionSortedStreams { streams, heap }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// UnionSortedStreams
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the union of sorted sequences of `Interval`s. Returned
+/// by [`union_sorted_streams`].
+///
+/// [`union_sorted_streams`]: fn.union_sorted_streams.html
+#[derive(Debug, Clone)]
+pub struct UnionSortedStreams<T, I> {
+    /// The input sequences.
+    streams: Vec<I>,
+    /// The next `Interval` of each nonempty input, least lower bound first.
+    heap: BinaryHeap<Reverse<HeapEntry<T>>>,
+}
+
+impl<T, I> UnionSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    /// Removes the `Interval` with the least lower bound from the heap,
+    /// replacing it with the next `Interval` from the same input.
+    fn pop(&mut self) -> Option<Interval<T>> {
+        let Reverse(HeapEntry { interval, index, .. }) = self.heap.pop()?;
+        if let Some(entry) = next_nonempty(&mut self.streams[index])
+            .and_then(|next| HeapEntry::new(next, index))
+        {
+            self.heap.push(Reverse(entry));
+        }
+        Some(interval)
+    }
+}
+
+impl<T, I> Iterator for UnionSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = self.pop()?;
+        while let Some(Reverse(entry)) = self.heap.peek() {
+            match merge(&current, &entry.interval) {
+                Some(merged) => {
+                    current = merged;
+                    let _ = self.pop();
+                },
+                None => break,
+            }
+        }
+        Some(current)
+    }
+}
+
+impl<T, I> FusedIterator for UnionSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// intersect_sorted_streams
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator over the intersection of any number of sorted
+/// sequences of disjoint `Interval`s.
+///
+/// The intersection is computed lazily holding one `Interval` from each
+/// input, so memory use is proportional to the number of inputs rather than
+/// their lengths. The resulting `Interval`s are sorted and disjoint. Each
+/// input must be sorted and contain no overlapping `Interval`s, such as the
+/// output of [`Selection::interval_iter`]. Empty `Interval`s in the inputs
+/// are ignored. If there are no inputs, nothing is yielded.
+///
+/// [`Selection::interval_iter`]: ../../selection/struct.Selection.html#method.interval_iter
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::algorithms::intersect_sorted_streams;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let streams = vec![
+///     vec![Interval::closed(0, 10), Interval::closed(20, 25)],
+///     vec![Interval::closed(2, 22)],
+///     vec![Interval::closed(4, 6), Interval::closed(8, 30)],
+/// ];
+///
+/// assert_eq!(intersect_sorted_streams(streams).collect::<Vec<_>>(), [
+///     Interval::closed(4, 6),
+///     Interval::closed(8, 10),
+///     Interval::closed(20, 22),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn intersect_sorted_streams<T, S>(streams: S)
+    -> IntersectSortedStreams<T, <S::Item as IntoIterator>::IntoIter>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        S: IntoIterator,
+        S::Item: IntoIterator<Item=Interval<T>>,
+{
+    let mut streams: Vec<_> = streams
+        .into_iter()
+        .map(IntoIterator::into_iter)
+        .collect();
+    let heads = streams
+        .iter_mut()
+        .map(next_nonempty)
+        .collect::<Option<Vec<_>>>()
+        .filter(|heads| !heads.is_empty())
+        .unwrap_or_default();
+    IntersectSortedStreams { streams, heads }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntersectSortedStreams
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the intersection of sorted sequences of `Interval`s.
+/// Returned by [`intersect_sorted_streams`].
+///
+/// [`intersect_sorted_streams`]: fn.intersect_sorted_streams.html
+#[derive(Debug, Clone)]
+pub struct IntersectSortedStreams<T, I> {
+    /// The input sequences.
+    streams: Vec<I>,
+    /// The current `Interval` of each input, or nothing if any input is
+    /// exhausted.
+    heads: Vec<Interval<T>>,
+}
+
+impl<T, I> Iterator for IntersectSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.heads.is_empty() {
+            let intersection = self.heads
+                .iter()
+                .fold(Interval::full(), |acc, head| acc.intersect(head));
+
+            // Advance the input whose current `Interval` ends first; it
+            // cannot intersect any later `Interval`s of the other inputs.
+            let index = (1..self.heads.len())
+                .fold(0, |least, i| {
+                    if ends_before(&self.heads[i], &self.heads[least]) {
+                        i
+                    } else {
+                        least
+                    }
+                });
+            match next_nonempty(&mut self.streams[index]) {
+                Some(next) => self.heads[index] = next,
+                None       => self.heads.clear(),
+            }
+
+            if !intersection.is_empty() {
+                return Some(intersection);
+            }
+        }
+        None
+    }
+}
+
+impl<T, I> FusedIterator for IntersectSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// minimum_cover
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the indices of the fewest candidate `Interval`s whose union
+/// contains the target `Interval`, in order of the points they cover, or
+/// `None` if the candidates do not cover the target.
+///
+/// The cover is found by a greedy sweep over the candidates in order of their
+/// lower bounds, taking the candidate which covers furthest past each
+/// uncovered point in turn.
+///
+/// Requires the `algorithms` feature. This function is unstable, and may
+/// change in minor releases.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::algorithms::minimum_cover;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let candidates = [
+///     Interval::closed(0, 4),
+///     Interval::closed(3, 6),
+///     Interval::closed(2, 9),
+///     Interval::closed(8, 12),
+/// ];
+///
+/// assert_eq!(minimum_cover(&Interval::closed(1, 10), &candidates),
+///     Some(vec![0, 2, 3]));
+/// assert_eq!(minimum_cover(&Interval::closed(1, 15), &candidates), None);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature="algorithms")]
+#[must_use]
+pub fn minimum_cover<T>(target: &Interval<T>, candidates: &[Interval<T>])
+    -> Option<Vec<usize>>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Candidates in order of their lower bounds, ignoring empty ones.
+    let mut order: Vec<(Bound<T>, usize)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, c)| c.lower_bound().map(|lower| (lower, idx)))
+        .collect();
+    order.sort_by(|(a, _), (b, _)| a.cmp_lower(b));
+    let mut order = order.into_iter().peekable();
+
+    let mut cover = Vec::new();
+    let mut remaining = target.clone();
+    while let Some(start) = remaining.lower_bound() {
+        // Of the candidates beginning at or before the first uncovered point,
+        // take the one covering the most points after it. The others end
+        // within it, so they are not needed for any later points.
+        let mut best: Option<(Bound<T>, Interval<T>, usize)> = None;
+        while let Some((_, idx)) = order
+            .next_if(|(lower, _)| lower.cmp_lower(&start).is_le())
+        {
+            let covered = remaining.intersect(&candidates[idx]);
+            let Some(upper) = covered.upper_bound() else { continue };
+            if best.as_ref().is_none_or(|(most, _, _)|
+                upper.cmp_upper(most).is_gt())
+            {
+                best = Some((upper, covered, idx));
+            }
+        }
+
+        let (_, covered, idx) = best?;
+        cover.push(idx);
+        remaining = remaining
+            .minus(&covered)
+            .next()
+            .unwrap_or_else(Interval::empty);
+    }
+    Some(cover)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// max_disjoint
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the indices of a largest set of mutually disjoint `Interval`s from
+/// the given `Interval`s, in order of the points they contain. Empty
+/// `Interval`s are never selected.
+///
+/// The set is found by a greedy sweep over the `Interval`s in order of their
+/// upper bounds, taking each `Interval` which is disjoint from the last one
+/// taken.
+///
+/// Requires the `algorithms` feature. This function is unstable, and may
+/// change in minor releases.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval::algorithms::max_disjoint;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let intervals = [
+///     Interval::closed(0, 10),
+///     Interval::closed(1, 3),
+///     Interval::closed(3, 5),
+///     Interval::closed(4, 7),
+///     Interval::closed(8, 9),
+/// ];
+///
+/// assert_eq!(max_disjoint(intervals.iter().copied()), [1, 3, 4]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature="algorithms")]
+#[must_use]
+pub fn max_disjoint<I, T>(intervals: I) -> Vec<usize>
+    where
+        I: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Intervals in order of their upper bounds, ignoring empty ones.
+    let mut order: Vec<(Bound<T>, Interval<T>, usize)> = intervals
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, i)| i.upper_bound().map(|upper| (upper, i, idx)))
+        .collect();
+    order.sort_by(|(a, _, _), (b, _, _)| a.cmp_upper(b));
+
+    let mut selected = Vec::new();
+    let mut last: Option<Interval<T>> = None;
+    for (_, interval, idx) in order {
+        if last.as_ref().is_none_or(|last| !last.intersects(&interval)) {
+            selected.push(idx);
+            last = Some(interval);
+        }
+    }
+    selected
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Stream helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// An `Interval` in a heap, ordered by its lower bound.
+#[derive(Debug, Clone)]
+struct HeapEntry<T> {
+    /// The lower bound of the `Interval`.
+    lower: Bound<T>,
+    /// The `Interval`.
+    interval: Interval<T>,
+    /// The index of the input the `Interval` was taken from.
+    index: usize,
+}
+
+impl<T> PartialEq for HeapEntry<T> where T: Ord + Clone {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for HeapEntry<T> where T: Ord + Clone {}
+
+impl<T> PartialOrd for HeapEntry<T> where T: Ord + Clone {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> HeapEntry<T> where T: Ord + Clone {
+    /// Constructs a new `HeapEntry`, or returns `None` if the `Interval` is
+    /// empty.
+    fn new(interval: Interval<T>, index: usize) -> Option<Self> {
+        let lower = interval.0.lower_bound()?;
+        Some(Self { lower, interval, index })
+    }
+}
+
+impl<T> Ord for HeapEntry<T> where T: Ord + Clone {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.lower.cmp_lower(&other.lower)
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+/// Returns `true` if the first `Interval`'s upper bound is less than the
+/// second's.
+fn ends_before<T>(a: &Interval<T>, b: &Interval<T>) -> bool
+    where T: Ord + Clone
+{
+    match (a.0.upper_bound(), b.0.upper_bound()) {
+        (Some(a), Some(b)) => a.least_intersect(&b) == a && a != b,
+        _                  => false,
+    }
+}
+
+/// Merges the given `Interval`s if they overlap or are adjacent after
+/// normalization.
+fn merge<T>(a: &Interval<T>, b: &Interval<T>) -> Option<Interval<T>>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    let a = a.0.clone().denormalized();
+    let b = b.0.clone().denormalized();
+    (a.intersects(&b) || a.is_adjacent_to(&b))
+        .then(|| Interval(a.enclose(&b).normalized()))
+}
@@ -0,0 +1,160 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a corpus of `Interval` operation results for validating
+//! implementations of [`Finite`].
+//!
+//! The [`CORPUS`] lists the result of each `Interval` operation for every
+//! pair of intervals bounded at a few small points, as computed for `i32`.
+//! The crate's tests regenerate it and check that it is unchanged. A type
+//! with a consistent `Finite` implementation produces the same results, which
+//! [`check`] verifies.
+//!
+//! Each line of the corpus is a case, giving the operation, its operands, and
+//! its results in interval notation, separated by whitespace:
+//!
+//! ```text
+//! complement (1,3) = (-∞,1] [3,∞)
+//! intersect [1,3] (1,3) = 2
+//! intersects (3,∞) 3 = false
+//! ```
+//!
+//! Lines which are empty or start with `#` are comments. The results are
+//! normalized, with unbounded ends written as infinite bounds rather than as
+//! the type's extreme points.
+//!
+//! [`Finite`]: ../../normalize/trait.Finite.html
+//! [`CORPUS`]: constant.CORPUS.html
+//! [`check`]: fn.check.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::fmt;
+use std::iter;
+use std::ops::RangeInclusive;
+
+
+/// The corpus of `Interval` operation results.
+pub const CORPUS: &str = include_str!("corpus.txt");
+
+/// The points written in the [`CORPUS`].
+///
+/// [`CORPUS`]: constant.CORPUS.html
+pub const POINTS: RangeInclusive<u8> = 0..=4;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// check
+////////////////////////////////////////////////////////////////////////////////
+/// Checks that the `Interval` operations on `T` produce the results in the
+/// [`CORPUS`], using the given function to convert each point of the corpus.
+///
+/// The conversion must map consecutive [`POINTS`] to consecutive values of
+/// `T`, and every point must lie strictly between `T::MINIMUM` and
+/// `T::MAXIMUM`, so that the unbounded intervals contain points beyond them.
+///
+/// # Errors
+///
+/// Returns a [`CorpusMismatch`] for the first case whose results differ.
+///
+/// # Panics
+///
+/// Panics if the corpus is malformed, which the crate's tests rule out.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::interval::corpus;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// corpus::check(|p| u16::from(p) + 100)?;
+///
+/// // Points which are not consecutive give different results.
+/// assert!(corpus::check(|p| u16::from(p) * 2 + 100).is_err());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`CORPUS`]: constant.CORPUS.html
+/// [`POINTS`]: constant.POINTS.html
+/// [`CorpusMismatch`]: struct.CorpusMismatch.html
+pub fn check<T, F>(point: F) -> Result<(), CorpusMismatch>
+    where
+        T: Finite + Ord + Clone,
+        RawInterval<T>: Normalize,
+        F: Fn(u8) -> T,
+{
+    let parse = |text: &str| -> Interval<T> {
+        RawInterval::parse_with(text, |p| p.parse::<u8>().map(&point))
+            .expect("parse corpus interval")
+            .into()
+    };
+
+    for (index, case) in CORPUS.lines().enumerate() {
+        if case.is_empty() || case.starts_with('#') { continue; }
+
+        let (operands, results) = case
+            .split_once('=')
+            .expect("corpus case results");
+        let mut operands = operands.split_whitespace();
+        let op = operands.next().expect("corpus case operation");
+        let a = parse(operands.next().expect("corpus case operand"));
+        let b = operands.next().map(parse);
+        let expected = results.split_whitespace().map(parse);
+        let flag = results.trim().parse::<bool>().ok();
+
+        let matches = match (op, b) {
+            ("complement", None) => a.complement().eq(expected),
+            ("intersect", Some(b)) => iter::once(a.intersect(&b))
+                .eq(expected),
+            ("union", Some(b)) => a.union(&b).eq(expected),
+            ("minus", Some(b)) => a.minus(&b).eq(expected),
+            ("enclose", Some(b)) => iter::once(a.enclose(&b)).eq(expected),
+            ("intersects", Some(b)) => flag == Some(a.intersects(&b)),
+            ("is_adjacent_to", Some(b)) => flag == Some(a.is_adjacent_to(&b)),
+            _ => panic!("unrecognized corpus case {:?}", case),
+        };
+        if !matches {
+            return Err(CorpusMismatch { line: index + 1, case });
+        }
+    }
+    Ok(())
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CorpusMismatch
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when the results of a case in the [`CORPUS`] differ.
+///
+/// [`CORPUS`]: constant.CORPUS.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CorpusMismatch {
+    /// The line number of the case in the corpus, starting from 1.
+    pub line: usize,
+    /// The text of the case.
+    pub case: &'static str,
+}
+
+impl fmt::Display for CorpusMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "corpus case on line {} differs: {}", self.line, self.case)
+    }
+}
+
+impl std::error::Error for CorpusMismatch {}
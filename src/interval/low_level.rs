@@ -0,0 +1,58 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the low-level interval storage used to implement `Selection`, for
+//! building custom containers such as interval maps or coverage counters.
+//!
+//! A [`TineTree`] stores a possibly noncontiguous union of [`RawInterval`]s
+//! and supports construction from `RawInterval`s, in-place set operations,
+//! ordered interval iteration, and splitting at a point. The items exported
+//! here follow the same versioning guarantees as the rest of the crate.
+//!
+//! Unlike `Selection`, a `TineTree` does not normalize its intervals. For
+//! [`Finite`] types, intervals must be [`denormalized`] before they are
+//! inserted so that adjacent intervals are merged, and [`normalized`] after
+//! they are read.
+//!
+//! [`TineTree`]: struct.TineTree.html
+//! [`RawInterval`]: enum.RawInterval.html
+//! [`Finite`]: ../../normalize/trait.Finite.html
+//! [`denormalized`]: ../../normalize/trait.Normalize.html#method.denormalized
+//! [`normalized`]: ../../normalize/trait.Normalize.html#method.normalized
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use normalize_interval::interval::low_level::RawInterval;
+//! # use normalize_interval::interval::low_level::TineTree;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! use RawInterval::*;
+//!
+//! let mut tree = TineTree::from_raw_interval(Closed(0, 10));
+//! tree.union_in_place(&Closed(20, 30));
+//! tree.minus_in_place(&Open(4, 6));
+//!
+//! let above = tree.split_off(&25);
+//! assert_eq!(tree.interval_iter().collect::<Vec<_>>(),
+//!     [Closed(0, 4), Closed(6, 10), RightOpen(20, 25)]);
+//! assert_eq!(above.interval_iter().collect::<Vec<_>>(), [Closed(25, 30)]);
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Exports.
+pub use crate::raw_interval::RawInterval;
+pub use crate::tine_tree::IntoIter;
+pub use crate::tine_tree::Iter;
+pub use crate::tine_tree::TineTree;
@@ -0,0 +1,234 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides serde adapters for fields of type `Interval<T>`, serialized as
+//! interval notation strings such as `"[3,7)"`. Requires the `serde` feature.
+//!
+//! Points are written with their `Display` implementation and read with their
+//! `FromStr` implementation. Any `\`, `,`, `(`, `)`, `[`, `]`, `Ø`, or `∞`
+//! within a point, and any whitespace at either end of it, is escaped with a
+//! `\`, so that every `Interval` is read back unchanged. Unescaped whitespace
+//! around points is ignored.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use normalize_interval::Interval;
+//! # use normalize_interval::normalize::Dense;
+//! # use serde::Deserialize;
+//! # use serde::Serialize;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with="normalize_interval::interval::serde_notation")]
+//!     names: Interval<Dense<String>>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{ "names": "[Adams\\, J, Baker)" }"#)?;
+//! assert!(config.names.contains(&Dense("Adams, J".to_string())));
+//! assert_eq!(serde_json::to_string(&config)?,
+//!     r#"{"names":"[Adams\\, J,Baker)"}"#);
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serializer;
+use serde::de::Error as _;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::str::FromStr;
+
+
+/// Characters with a meaning in interval notation, which are escaped within
+/// points.
+const RESERVED: [char; 8] = ['\\', ',', '(', ')', '[', ']', 'Ø', '∞'];
+
+/// Serializes an `Interval` as an interval notation string.
+///
+/// # Errors
+///
+/// Returns an error if the string could not be serialized.
+pub fn serialize<T, S>(interval: &Interval<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Display,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    serializer.serialize_str(&to_notation(interval))
+}
+
+/// Deserializes an `Interval` from an interval notation string.
+///
+/// # Errors
+///
+/// Returns an error if the string is not in interval notation, or if one of
+/// its points could not be parsed.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Interval<T>, D::Error>
+    where
+        T: Ord + Clone + FromStr,
+        T::Err: Display,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    from_notation(&s).map_err(|e| e.map_or_else(
+        || D::Error::custom(format!("invalid interval {:?}", s)),
+        D::Error::custom))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Notation conversions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the interval notation string for the given `Interval`.
+fn to_notation<T>(interval: &Interval<T>) -> String
+    where
+        T: Ord + Clone + Display,
+        RawInterval<T>: Normalize,
+{
+    use Bound::*;
+    let mut s = String::new();
+    match (interval.lower_bound(), interval.upper_bound()) {
+        (Some(Include(l)), Some(Include(u))) if l == u => {
+            escape_into(&mut s, &l);
+        },
+        (Some(l), Some(u)) => {
+            match l {
+                Include(p) => { s.push('['); escape_into(&mut s, &p); },
+                Exclude(p) => { s.push('('); escape_into(&mut s, &p); },
+                Infinite   => s.push_str("(-∞"),
+            }
+            s.push(',');
+            match u {
+                Include(p) => { escape_into(&mut s, &p); s.push(']'); },
+                Exclude(p) => { escape_into(&mut s, &p); s.push(')'); },
+                Infinite   => s.push_str("∞)"),
+            }
+        },
+        _ => s.push('Ø'),
+    }
+    s
+}
+
+/// Parses an `Interval` from an interval notation string. Returns `Err(None)`
+/// if the string is not in interval notation, or an error from `T::from_str`
+/// if a point could not be parsed.
+fn from_notation<T>(s: &str) -> Result<Interval<T>, Option<T::Err>>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    // Escaped whitespace must be kept, so points are trimmed by `unescape`.
+    if s.trim() == "Ø" { return Ok(Interval::empty()); }
+
+    let parse = |p: &str| unescape(p)
+        .ok_or(None)
+        .and_then(|p| T::from_str(&p).map_err(Some));
+
+    let Some((x, y)) = split_unescaped(s) else {
+        return parse(s).map(Interval::point);
+    };
+
+    let x = x.trim_start();
+    let lower = if x.trim_end() == "(-∞" {
+        Bound::Infinite
+    } else if let Some(p) = x.strip_prefix('(') {
+        Bound::Exclude(parse(p)?)
+    } else if let Some(p) = x.strip_prefix('[') {
+        Bound::Include(parse(p)?)
+    } else {
+        return Err(None);
+    };
+
+    // An escaped closing bracket leaves a trailing `\` for `unescape` to
+    // reject.
+    let y = y.trim_end();
+    let upper = if y.trim_start() == "∞)" {
+        Bound::Infinite
+    } else if let Some(p) = y.strip_suffix(')') {
+        Bound::Exclude(parse(p)?)
+    } else if let Some(p) = y.strip_suffix(']') {
+        Bound::Include(parse(p)?)
+    } else {
+        return Err(None);
+    };
+
+    Ok(Interval::new(lower, upper))
+}
+
+/// Writes the given point into the string, escaping reserved characters and
+/// whitespace at either end.
+fn escape_into<T>(s: &mut String, point: &T) where T: Display {
+    let text = point.to_string();
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    for (i, c) in text.char_indices() {
+        if RESERVED.contains(&c) || i < start || i >= end {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+}
+
+/// Returns the given point text with its escapes removed and its unescaped
+/// whitespace trimmed, or `None` if it contains an unescaped reserved
+/// character.
+fn unescape(text: &str) -> Option<String> {
+    let mut point = String::with_capacity(text.len());
+    // The length of `point` without its trailing unescaped whitespace.
+    let mut kept = 0;
+    let mut chars = text.trim_start().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                point.push(chars.next()?);
+                kept = point.len();
+            },
+            c if RESERVED.contains(&c) => return None,
+            c => {
+                point.push(c);
+                if !c.is_whitespace() { kept = point.len(); }
+            },
+        }
+    }
+    point.truncate(kept);
+    Some(point)
+}
+
+/// Splits the given text at its first unescaped `,`.
+fn split_unescaped(text: &str) -> Option<(&str, &str)> {
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\'         => escaped = true,
+            ','          => return Some((&text[..i], &text[i + 1..])),
+            _            => (),
+        }
+    }
+    None
+}
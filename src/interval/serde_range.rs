@@ -0,0 +1,100 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides serde adapters for fields of type `Range<T>`, serialized as
+//! interval notation strings. Requires the `serde` feature.
+//!
+//! A `Range` is written as the `Interval` of points it contains, in the same
+//! form as [`serde_notation`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use std::ops::Range;
+//! # use serde::Deserialize;
+//! # use serde::Serialize;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with="normalize_interval::interval::serde_range")]
+//!     ports: Range<u16>,
+//! }
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! A human-readable format, such as a configuration file, may then give the
+//! field as `"[8000, 8080)"` or `"[8000,8079]"`.
+//!
+//! [`serde_notation`]: ../serde_notation/index.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::interval::serde_notation;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use serde::Deserializer;
+use serde::Serializer;
+use serde::de::Error as _;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+
+/// Serializes a `Range` as the interval notation string of the `Interval` of
+/// points it contains.
+///
+/// # Errors
+///
+/// Returns an error if the `Interval` could not be serialized.
+pub fn serialize<T, S>(range: &Range<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Display,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    serde_notation::serialize(&Interval::from(range.clone()), serializer)
+}
+
+/// Deserializes a `Range` from the interval notation string of an
+/// `Interval`. An empty `Interval` is deserialized as an empty `Range`
+/// starting at the minimum point.
+///
+/// # Errors
+///
+/// Returns an error if the `Interval` could not be deserialized, or if it
+/// contains the maximum point, which no `Range` can contain.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Range<T>, D::Error>
+    where
+        T: Finite + Ord + Clone + FromStr,
+        T::Err: Display,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    let interval: Interval<T> = serde_notation::deserialize(deserializer)?;
+    match (interval.infimum(), interval.supremum()) {
+        (Some(start), Some(last)) => last
+            .succ()
+            .map(|end| start..end)
+            .ok_or_else(|| D::Error::custom(
+                "interval containing the maximum point is not a range")),
+        _ => Ok(T::MINIMUM..T::MINIMUM),
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the [`IntervalSet`] trait for code which is generic over the
+//! containers of disjoint `Interval`s provided by this crate.
+//!
+//! `IntervalSet` is implemented by [`Selection`], the inline
+//! [`ArraySelection`], and the encoded [`SelectionView`]. Since some of these
+//! cannot be modified, or can only be modified fallibly, set operations
+//! return a new `Selection`.
+//!
+//! The set operations have the same names as the inherent methods of
+//! `Selection`, which take precedence in method call syntax. For a
+//! `Selection` receiver, call them as `IntervalSet::union(&sel, &other)`.
+//!
+//! [`IntervalSet`]: trait.IntervalSet.html
+//! [`Selection`]: ../selection/struct.Selection.html
+//! [`ArraySelection`]: ../array_selection/struct.ArraySelection.html
+//! [`SelectionView`]: ../flat/struct.SelectionView.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::array_selection;
+use crate::array_selection::ArraySelection;
+use crate::flat;
+use crate::flat::FlatPoint;
+use crate::flat::SelectionView;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection;
+use crate::selection::Selection;
+use crate::sorted_iter::SortedDisjointIter;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalSet
+////////////////////////////////////////////////////////////////////////////////
+/// A set of points stored as disjoint `Interval`s.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::array_selection::ArraySelection;
+/// # use normalize_interval::interval_set::IntervalSet;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// /// Returns the number of the given points in the set.
+/// fn count_hits<S>(set: &S, points: &[u32]) -> usize
+///     where S: IntervalSet<u32>
+/// {
+///     points.iter().filter(|p| set.contains(p)).count()
+/// }
+///
+/// let sel: Selection<u32> = Selection::from(Interval::closed(10, 20));
+/// let mut array: ArraySelection<u32, 4> = ArraySelection::new();
+/// array.try_union_in_place(Interval::closed(15, 30))?;
+///
+/// assert_eq!(count_hits(&sel, &[5, 10, 25]), 1);
+/// assert_eq!(count_hits(&array, &[5, 10, 25]), 1);
+/// assert_eq!(IntervalSet::union(&sel, &array),
+///     Selection::from(Interval::closed(10, 30)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait IntervalSet<T> {
+    /// The `Iterator` over the `Interval`s of the set, which are disjoint and
+    /// in ascending order.
+    type IntervalIter<'s>: SortedDisjointIter<T> where Self: 's;
+
+    /// Returns an iterator over the `Interval`s of the set in ascending
+    /// order.
+    fn interval_iter(&self) -> Self::IntervalIter<'_>;
+
+    /// Returns `true` if the given point is in the set.
+    fn contains(&self, point: &T) -> bool;
+
+    /// Returns `true` if the set contains no points.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the `Selection` of the points in the set.
+    fn to_selection(&self) -> Selection<T>
+        where
+            T: Ord + Clone,
+            RawInterval<T>: Normalize,
+    {
+        Selection::from_sorted_disjoint(self.interval_iter())
+    }
+
+    /// Returns the `Selection` of the points in either set.
+    fn union<S>(&self, other: &S) -> Selection<T>
+        where
+            S: IntervalSet<T> + ?Sized,
+            T: Ord + Clone,
+            RawInterval<T>: Normalize,
+    {
+        let mut selection = self.to_selection();
+        for interval in other.interval_iter() {
+            selection.union_in_place(interval);
+        }
+        selection
+    }
+
+    /// Returns the `Selection` of the points in both sets.
+    fn intersect<S>(&self, other: &S) -> Selection<T>
+        where
+            S: IntervalSet<T> + ?Sized,
+            T: Ord + Clone,
+            RawInterval<T>: Normalize,
+    {
+        self.to_selection().intersect(&other.to_selection())
+    }
+
+    /// Returns the `Selection` of the points in the set which are not in the
+    /// other set.
+    fn minus<S>(&self, other: &S) -> Selection<T>
+        where
+            S: IntervalSet<T> + ?Sized,
+            T: Ord + Clone,
+            RawInterval<T>: Normalize,
+    {
+        let mut selection = self.to_selection();
+        for interval in other.interval_iter() {
+            selection.minus_in_place(interval);
+        }
+        selection
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalSet implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> IntervalSet<T> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type IntervalIter<'s> = selection::IntervalIter<'s, T> where T: 's;
+
+    fn interval_iter(&self) -> Self::IntervalIter<'_> {
+        Self::interval_iter(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        Self::contains(self, point)
+    }
+
+    fn is_empty(&self) -> bool {
+        Self::is_empty(self)
+    }
+
+    fn to_selection(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T, const N: usize> IntervalSet<T> for ArraySelection<T, N>
+    where T: Finite + Ord + Clone
+{
+    type IntervalIter<'s> = array_selection::IntervalIter<'s, T> where T: 's;
+
+    fn interval_iter(&self) -> Self::IntervalIter<'_> {
+        Self::interval_iter(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        Self::contains(self, point)
+    }
+
+    fn is_empty(&self) -> bool {
+        Self::is_empty(self)
+    }
+}
+
+impl<'a, T> IntervalSet<T> for SelectionView<'a, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{
+    type IntervalIter<'s> = flat::IntervalIter<'a, T> where Self: 's;
+
+    fn interval_iter(&self) -> Self::IntervalIter<'_> {
+        Self::interval_iter(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        Self::contains(self, point)
+    }
+
+    fn is_empty(&self) -> bool {
+        Self::is_empty(self)
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides traits describing the lattice structure of `Interval`s and
+//! `Selection`s, for writing generic fixpoint computations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Lattice
+////////////////////////////////////////////////////////////////////////////////
+/// A lattice, in which every pair of elements has a least upper bound and a
+/// greatest lower bound.
+///
+/// Implementations must ensure that `join` and `meet` are commutative,
+/// associative, and idempotent, and that `a.join(&a.meet(&b)) == a` and
+/// `a.meet(&a.join(&b)) == a`.
+pub trait Lattice {
+    /// Returns the least upper bound of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::lattice::Lattice;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 3);
+    /// let b: Interval<i32> = Interval::closed(7, 9);
+    ///
+    /// assert_eq!(a.join(&b), Interval::closed(0, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn join(&self, other: &Self) -> Self;
+
+    /// Returns the greatest lower bound of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::lattice::Lattice;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Interval<i32> = Interval::closed(0, 5);
+    /// let b: Interval<i32> = Interval::closed(3, 9);
+    ///
+    /// assert_eq!(a.meet(&b), Interval::closed(3, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn meet(&self, other: &Self) -> Self;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BoundedLattice
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Lattice`] with a least and a greatest element.
+///
+/// Implementations must ensure that `bottom` is the identity of `join` and
+/// that `top` is the identity of `meet`.
+///
+/// [`Lattice`]: trait.Lattice.html
+pub trait BoundedLattice: Lattice {
+    /// Returns the least element of the lattice.
+    #[must_use]
+    fn bottom() -> Self;
+
+    /// Returns the greatest element of the lattice.
+    #[must_use]
+    fn top() -> Self;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Intervals are ordered by inclusion, so their join is the smallest enclosing
+// interval rather than their union.
+impl<T> Lattice for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn join(&self, other: &Self) -> Self {
+        self.enclose(other)
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersect(other)
+    }
+}
+
+impl<T> BoundedLattice for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn bottom() -> Self {
+        Self::empty()
+    }
+
+    fn top() -> Self {
+        Self::full()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Selections are ordered by inclusion, and can represent any union exactly.
+impl<T> Lattice for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn join(&self, other: &Self) -> Self {
+        self.union(other)
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersect(other)
+    }
+}
+
+impl<T> BoundedLattice for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn bottom() -> Self {
+        Self::empty()
+    }
+
+    fn top() -> Self {
+        Self::full()
+    }
+}
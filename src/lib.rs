@@ -102,6 +102,7 @@
 #![allow(clippy::shadow_unrelated)] // Does not work correctly.
 
 // // Internal modules.
+mod macros;
 pub mod raw_interval;
 pub mod tine;
 pub mod tine_tree;
@@ -110,10 +111,35 @@ pub mod tine_tree;
 mod test;
 
 // Public modules.
+#[cfg(feature="arrow")]
+pub mod arrow;
+pub mod array_selection;
+#[cfg(feature="num")]
+pub mod bignum;
+pub mod booking;
 pub mod bound;
+pub mod cidr;
+pub mod cpu_set;
+pub mod flat;
 pub mod interval;
+pub mod interval_set;
+pub mod lattice;
+pub mod measure;
 pub mod normalize;
+pub mod ops;
+pub mod partial_interval;
+pub mod predicate;
+pub mod prefix;
+#[cfg(feature="uom")]
+pub mod quantity;
+pub mod range_list;
 pub mod selection;
+pub mod sorted_iter;
+pub mod step_fn;
+pub mod text;
+pub mod units;
+#[cfg(feature="semver")]
+pub mod version;
 
 // Exports.
 pub use crate::bound::Bound;
@@ -0,0 +1,116 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides macros for constructing `Interval`s and `Selection`s.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+////////////////////////////////////////////////////////////////////////////////
+// interval!
+////////////////////////////////////////////////////////////////////////////////
+/// Constructs an [`Interval`] from range syntax.
+///
+/// | Syntax             | Interval                         |
+/// |--------------------|----------------------------------|
+/// | `interval!()`      | [`Interval::empty()`]            |
+/// | `interval!(=p)`    | [`Interval::point(p)`]           |
+/// | `interval!(p)`     | [`Interval::point(p)`]           |
+/// | `interval!(a..b)`  | [`Interval::right_open(a, b)`]   |
+/// | `interval!(a..=b)` | [`Interval::closed(a, b)`]       |
+/// | `interval!(a..)`   | [`Interval::unbounded_from(a)`]  |
+/// | `interval!(..b)`   | [`Interval::unbounded_up_to(b)`] |
+/// | `interval!(..=b)`  | [`Interval::unbounded_to(b)`]    |
+/// | `interval!(..)`    | [`Interval::full()`]             |
+///
+/// Any other expression is converted using `Interval::from`.
+///
+/// [`Interval`]: interval/struct.Interval.html
+/// [`Interval::empty()`]: interval/struct.Interval.html#method.empty
+/// [`Interval::point(p)`]: interval/struct.Interval.html#method.point
+/// [`Interval::right_open(a, b)`]: interval/struct.Interval.html#method.right_open
+/// [`Interval::closed(a, b)`]: interval/struct.Interval.html#method.closed
+/// [`Interval::unbounded_from(a)`]: interval/struct.Interval.html#method.unbounded_from
+/// [`Interval::unbounded_up_to(b)`]: interval/struct.Interval.html#method.unbounded_up_to
+/// [`Interval::unbounded_to(b)`]: interval/struct.Interval.html#method.unbounded_to
+/// [`Interval::full()`]: interval/struct.Interval.html#method.full
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a: Interval<i32> = interval!(3..7);
+/// assert_eq!(a, Interval::right_open(3, 7));
+///
+/// let b: Interval<i32> = interval!(=5);
+/// assert_eq!(b, Interval::point(5));
+///
+/// let c: Interval<i32> = interval!(..=9);
+/// assert_eq!(c, Interval::unbounded_to(9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! interval {
+    () => {
+        $crate::Interval::empty()
+    };
+    (= $point:expr) => {
+        $crate::Interval::point($point)
+    };
+    ($range:expr) => {
+        $crate::Interval::from($range)
+    };
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// selection!
+////////////////////////////////////////////////////////////////////////////////
+/// Constructs a [`Selection`] from a list of points and ranges, each of which
+/// is converted into an [`Interval`] as by the [`interval!`] macro.
+///
+/// [`Selection`]: selection/struct.Selection.html
+/// [`Interval`]: interval/struct.Interval.html
+/// [`interval!`]: macro.interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<i32> = selection![0..5, 8, 10..];
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(0, 4),
+///     Interval::point(8),
+///     Interval::unbounded_from(10),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! selection {
+    ($($range:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut selection = $crate::Selection::new();
+        $(selection.union_in_place($crate::Interval::from($range));)*
+        selection
+    }};
+}
@@ -0,0 +1,273 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides traits for measuring the distance between interval bounds and
+//! comparing the resulting lengths.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Standard library imports.
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measurable
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the distance between two points of a type. Used to compute the
+/// [`size`] of an [`Interval`] and the [`measure`] of a [`Selection`].
+///
+/// The `Length` of a type need not be the type itself. For example, the
+/// distance between two [`Instant`]s is a [`Duration`], and the distance
+/// between two `i8`s is a `u8`.
+///
+/// [`size`]: ../interval/struct.Interval.html#method.size
+/// [`measure`]: ../selection/struct.Selection.html#method.measure
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+pub trait Measurable {
+    /// The type of the distance between two points.
+    type Length;
+
+    /// Returns the distance from the `lower` point to the `upper` point.
+    /// Implementations may assume that `lower <= upper`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::measure::Measurable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(i32::span(&-3, &7), 10);
+    /// assert_eq!(i8::span(&i8::MIN, &i8::MAX), 255);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn span(lower: &Self, upper: &Self) -> Self::Length;
+
+    /// Returns the extent of the closed interval from the `lower` point to the
+    /// `upper` point, or `None` if it is not representable. For [`Finite`]
+    /// types, this counts the points of the interval, so that a single point
+    /// has an extent of one. Otherwise, it is the [`span`] between the points.
+    /// Implementations may assume that `lower <= upper`.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`span`]: #tymethod.span
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::measure::Measurable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(i32::extent(&-3, &7), Some(11));
+    /// assert_eq!(i32::extent(&5, &5), Some(1));
+    /// assert_eq!(i8::extent(&i8::MIN, &i8::MAX), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn extent(lower: &Self, upper: &Self) -> Option<Self::Length> {
+        Some(Self::span(lower, upper))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard Measurable implementations
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements `Measurable` for a builtin integer type by its absolute
+/// difference, counting its points inclusively.
+macro_rules! std_integer_measurable_impl {
+    // For each given type and its length type...
+    ($($t:ident => $l:ident),*) => {
+        $(impl Measurable for $t {
+            type Length = $l;
+
+            fn span(lower: &Self, upper: &Self) -> Self::Length {
+                upper.abs_diff(*lower)
+            }
+
+            fn extent(lower: &Self, upper: &Self) -> Option<Self::Length> {
+                Self::span(lower, upper).checked_add(1)
+            }
+        })*
+    };
+}
+
+// Provide implementations of Measurable for builtin integer types. Spans are
+// measured in the unsigned counterpart of the type, which holds the span
+// between any two points exactly.
+std_integer_measurable_impl![
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128,
+    usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128,
+    isize => usize
+];
+
+// Durations are `Finite`, so their points are counted in nanoseconds.
+impl Measurable for Duration {
+    type Length = Self;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper.saturating_sub(*lower)
+    }
+
+    fn extent(lower: &Self, upper: &Self) -> Option<Self::Length> {
+        Self::span(lower, upper).checked_add(Self::from_nanos(1))
+    }
+}
+
+impl Measurable for Instant {
+    type Length = Duration;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper.saturating_duration_since(*lower)
+    }
+}
+
+impl Measurable for SystemTime {
+    type Length = Duration;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper.duration_since(*lower).unwrap_or_default()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Ratio
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the ratio between two lengths. Used to compute similarity metrics
+/// such as [`Selection::jaccard`] from [`Measurable`] lengths.
+///
+/// [`Selection::jaccard`]: ../selection/struct.Selection.html#method.jaccard
+/// [`Measurable`]: trait.Measurable.html
+pub trait Ratio {
+    /// Returns the ratio of the length to the `whole` length. Implementations
+    /// may assume that `whole` is nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::measure::Ratio;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(3u32.ratio(&4), 0.75);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn ratio(&self, whole: &Self) -> f64;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard Ratio implementations
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements `Ratio` for a builtin numeric type by floating point division.
+macro_rules! std_numeric_ratio_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Ratio for $t {
+            #[allow(clippy::cast_lossless)]
+            fn ratio(&self, whole: &Self) -> f64 {
+                *self as f64 / *whole as f64
+            }
+        })*
+    };
+}
+
+// Provide implementations of Ratio for builtin numeric types. Lengths which
+// exceed the precision of `f64` are rounded.
+std_numeric_ratio_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32
+];
+
+impl Ratio for f64 {
+    fn ratio(&self, whole: &Self) -> f64 {
+        self / whole
+    }
+}
+
+impl Ratio for Duration {
+    fn ratio(&self, whole: &Self) -> f64 {
+        self.as_secs_f64() / whole.as_secs_f64()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CheckedAdd
+////////////////////////////////////////////////////////////////////////////////
+/// Provides checked addition of lengths. Used to total the [`measure`] of a
+/// [`Selection`] from [`Measurable`] lengths.
+///
+/// [`measure`]: ../selection/struct.Selection.html#method.measure
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Measurable`]: trait.Measurable.html
+pub trait CheckedAdd: Sized {
+    /// Returns the sum of the lengths, or `None` if it is not representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::measure::CheckedAdd;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(CheckedAdd::checked_add(&200u8, &55), Some(255));
+    /// assert_eq!(CheckedAdd::checked_add(&200u8, &56), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard CheckedAdd implementations
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements `CheckedAdd` for a builtin integer type by its inherent checked
+/// addition.
+macro_rules! std_integer_checked_add_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl CheckedAdd for $t {
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                $t::checked_add(*self, *other)
+            }
+        })*
+    };
+}
+
+// Provide implementations of CheckedAdd for builtin integer types.
+std_integer_checked_add_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+impl CheckedAdd for Duration {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::checked_add(*self, *other)
+    }
+}
@@ -12,8 +12,17 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Internal library imports.
+use crate::bound::Bound;
 use crate::raw_interval::RawInterval;
 
+// Standard library imports.
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
 
 ////////////////////////////////////////////////////////////////////////////////
 // Finite
@@ -21,8 +30,14 @@ use crate::raw_interval::RawInterval;
 /// Provides the methods needed to iterate over an type's points. Used
 /// to [`Normalize`] finite types used in [`Interval`] bounds.
 ///
+/// Implementations must ensure that `pred` and `succ` return `None` exactly
+/// when called on [`MINIMUM`] and [`MAXIMUM`] respectively, and that they are
+/// inverses of each other otherwise.
+///
 /// [`Normalize`]: trait.Normalize.html
 /// [`Interval`]: ../interval/struct.Interval.html
+/// [`MINIMUM`]: #associatedconstant.MINIMUM
+/// [`MAXIMUM`]: #associatedconstant.MAXIMUM
 pub trait Finite: Sized {
     /// The minimum value of the type.
     const MINIMUM: Self;
@@ -30,11 +45,91 @@ pub trait Finite: Sized {
     /// The maximum value of the type.
     const MAXIMUM: Self;
 
-    /// Returns the previous element before the given one.
+    /// Returns the previous element before the given one, or `None` if the
+    /// given element is the [`MINIMUM`].
+    ///
+    /// [`MINIMUM`]: #associatedconstant.MINIMUM
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Finite;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(5i32.pred(), Some(4));
+    /// assert_eq!(u8::MIN.pred(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn pred(&self) -> Option<Self>;
 
-    /// Returns the next element after the given one.
+    /// Returns the next element after the given one, or `None` if the given
+    /// element is the [`MAXIMUM`].
+    ///
+    /// [`MAXIMUM`]: #associatedconstant.MAXIMUM
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Finite;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(5i32.succ(), Some(6));
+    /// assert_eq!(u8::MAX.succ(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn succ(&self) -> Option<Self>;
+
+    /// Returns the previous element before the given one, or the
+    /// [`MINIMUM`] if the given element is the [`MINIMUM`].
+    ///
+    /// [`MINIMUM`]: #associatedconstant.MINIMUM
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Finite;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(5i32.saturating_pred(), 4);
+    /// assert_eq!(u8::MIN.saturating_pred(), u8::MIN);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn saturating_pred(&self) -> Self {
+        self.pred().unwrap_or(Self::MINIMUM)
+    }
+
+    /// Returns the next element after the given one, or the [`MAXIMUM`] if
+    /// the given element is the [`MAXIMUM`].
+    ///
+    /// [`MAXIMUM`]: #associatedconstant.MAXIMUM
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Finite;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(5i32.saturating_succ(), 6);
+    /// assert_eq!(u8::MAX.saturating_succ(), u8::MAX);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn saturating_succ(&self) -> Self {
+        self.succ().unwrap_or(Self::MAXIMUM)
+    }
 }
 
 
@@ -77,25 +172,31 @@ pub trait Normalize {
 // }
 
 /// Specialization for [`Finite`] intervals.
-impl<T> Normalize for RawInterval<T> where T: Finite {
+impl<T> Normalize for RawInterval<T> where T: Finite + Ord {
     fn normalize(&mut self) {
         use RawInterval::*;
-        *self = match std::mem::replace(self, Empty) {
-            Empty           => Empty,
-            Point(p)        => Point(p),
-            Open(l, r)      => match (l.succ(), r.pred()) {
-                (Some(l), Some(r)) => Closed(l, r),
-                _                  => Empty,
-            },
-            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| Closed(l, r)),
-            RightOpen(l, r) => r.pred().map_or(Empty, |r| Closed(l, r)),
-            Closed(l, r)    => Closed(l, r),
-            UpTo(r)         => r.pred().map_or(Empty, |r| Closed(T::MINIMUM, r)),
-            UpFrom(l)       => l.succ().map_or(Empty, |l| Closed(l, T::MAXIMUM)),
-            To(p)           => Closed(T::MINIMUM, p),
-            From(p)         => Closed(p, T::MAXIMUM),
-            Full            => Closed(T::MINIMUM, T::MAXIMUM),
-        }
+        use Bound::*;
+        // Bounds are resolved to their contained points using the same
+        // `Bound::next_up` and `Bound::next_down` helpers exposed publicly,
+        // and closed with `RawInterval::closed` so that bounds which cross or
+        // meet after being closed produce `Empty` or `Point` intervals.
+        let (lower, upper) = match std::mem::replace(self, Empty) {
+            Empty           => return,
+            Point(p)        => { *self = Point(p); return; },
+            Open(l, r)      => (Exclude(l), Exclude(r)),
+            LeftOpen(l, r)  => (Exclude(l), Include(r)),
+            RightOpen(l, r) => (Include(l), Exclude(r)),
+            Closed(l, r)    => (Include(l), Include(r)),
+            UpTo(r)         => (Infinite,   Exclude(r)),
+            UpFrom(l)       => (Exclude(l), Infinite),
+            To(p)           => (Infinite,   Include(p)),
+            From(p)         => (Include(p), Infinite),
+            Full            => (Infinite,   Infinite),
+        };
+        *self = match (lower.next_up(), upper.next_down()) {
+            (Some(l), Some(r)) => Self::closed(l, r),
+            _                  => Empty,
+        };
     }
 
     fn denormalize(&mut self) {
@@ -133,11 +234,92 @@ impl<T> Normalize for RawInterval<T> where T: Finite {
 }
 
 
+////////////////////////////////////////////////////////////////////////////////
+// Dense
+////////////////////////////////////////////////////////////////////////////////
+/// Wraps a point to be treated as part of a dense order, so that the
+/// [`Interval`]s bounded by it are not normalized.
+///
+/// An `Interval` of `Dense` integers preserves its open bounds, as an
+/// `Interval` of real numbers would. Set operations remain correct for the
+/// dense order, in which points are assumed to lie between any two points,
+/// so `(3,4)` is not empty. Intervals of `Dense` points cannot be iterated
+/// over, since they are not [`Finite`].
+///
+/// Unbounded ends are given by [`Bound::Infinite`], and the extreme values of
+/// the wrapped type are ordinary points, so `(-∞,i32::MIN)` is not empty.
+/// Prefer `Dense` to wrapping points in an `Option` to keep their open
+/// bounds: `Option` orders `None` below every `Some` point, so `None` is a
+/// lowest point rather than an unbounded end.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Finite`]: trait.Finite.html
+/// [`Bound::Infinite`]: ../bound/enum.Bound.html#variant.Infinite
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound::*;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::normalize::Dense;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let interval: Interval<Dense<i32>> = Interval::open(Dense(3), Dense(7));
+///
+/// assert_eq!(interval.lower_bound(), Some(Exclude(Dense(3))));
+/// assert!(!interval.contains(&Dense(3)));
+/// assert!(!Interval::open(Dense(3), Dense(4)).is_empty());
+///
+/// let mut sel = Selection::from(interval);
+/// sel.minus_in_place(Interval::point(Dense(5)));
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), vec![
+///     Interval::open(Dense(3), Dense(5)),
+///     Interval::open(Dense(5), Dense(7)),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dense<T>(pub T);
+
+impl<T> From<T> for Dense<T> {
+    fn from(point: T) -> Self {
+        Self(point)
+    }
+}
+
+impl<T> std::fmt::Display for Dense<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> FromStr for Dense<T> where T: FromStr {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Self)
+    }
+}
+
+/// Intervals of [`Dense`] points are already in their normal form.
+///
+/// [`Dense`]: struct.Dense.html
+impl<T> Normalize for RawInterval<Dense<T>> where T: Ord {
+    fn normalize(&mut self) {/* Do nothing. */}
+
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Standard integer Finite implementations
 ////////////////////////////////////////////////////////////////////////////////
 
-// Implements basic normalization for a single builtin integer type.
+/// Implements basic normalization for a single builtin integer type.
 macro_rules! std_integer_countable_impl {
     // For each given type...
     ($($t:ident),*) => {
@@ -163,6 +345,246 @@ std_integer_countable_impl![
 ];
 
 
+////////////////////////////////////////////////////////////////////////////////
+// Standard time Finite implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Durations are counted in whole nanoseconds.
+impl Finite for Duration {
+    const MINIMUM: Self = Self::ZERO;
+    const MAXIMUM: Self = Self::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.checked_sub(Self::from_nanos(1))
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.checked_add(Self::from_nanos(1))
+    }
+}
+
+// Instants and system times have a platform-dependent resolution, so they are
+// not `Finite`, and their intervals are already normalized.
+impl Normalize for RawInterval<Instant> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+impl Normalize for RawInterval<SystemTime> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard network Finite implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Addresses are counted by their numeric value.
+impl Finite for Ipv4Addr {
+    const MINIMUM: Self = Self::UNSPECIFIED;
+    const MAXIMUM: Self = Self::BROADCAST;
+
+    fn pred(&self) -> Option<Self> {
+        u32::from(*self).checked_sub(1).map(Self::from)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        u32::from(*self).checked_add(1).map(Self::from)
+    }
+}
+
+impl Finite for Ipv6Addr {
+    const MINIMUM: Self = Self::UNSPECIFIED;
+    const MAXIMUM: Self = Self::new(
+        0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff);
+
+    fn pred(&self) -> Option<Self> {
+        u128::from(*self).checked_sub(1).map(Self::from)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        u128::from(*self).checked_add(1).map(Self::from)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Align
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the methods needed to align [`Finite`] points to multiples of a
+/// quantum. Used to align the bounds of an [`Interval`] or [`Selection`].
+///
+/// The points of the type are partitioned into blocks of `quantum` points,
+/// each starting at a multiple of `quantum`.
+///
+/// [`Finite`]: trait.Finite.html
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+pub trait Align: Finite {
+    /// Returns the first point of the block containing the given point, or
+    /// `None` if it is not representable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Align;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(17i32.block_start(&5), Some(15));
+    /// assert_eq!((-3i32).block_start(&5), Some(-5));
+    /// assert_eq!(i8::MIN.block_start(&5), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn block_start(&self, quantum: &Self) -> Option<Self>;
+
+    /// Returns the last point of the block containing the given point, or
+    /// `None` if it is not representable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Align;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(17i32.block_end(&5), Some(19));
+    /// assert_eq!((-3i32).block_end(&5), Some(-1));
+    /// assert_eq!(u8::MAX.block_end(&100), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn block_end(&self, quantum: &Self) -> Option<Self>;
+}
+
+/// Implements alignment for a single builtin integer type.
+macro_rules! std_integer_align_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Align for $t {
+            fn block_start(&self, quantum: &Self) -> Option<Self> {
+                assert!(*quantum > 0, "alignment quantum must be positive");
+                self.checked_sub(self.rem_euclid(*quantum))
+            }
+
+            fn block_end(&self, quantum: &Self) -> Option<Self> {
+                assert!(*quantum > 0, "alignment quantum must be positive");
+                self.checked_add(*quantum - 1 - self.rem_euclid(*quantum))
+            }
+        })*
+    };
+}
+
+// Provide implementations of Align for builtin integer types.
+std_integer_align_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+
+// Durations are aligned to whole nanoseconds.
+impl Align for Duration {
+    fn block_start(&self, quantum: &Self) -> Option<Self> {
+        assert!(!quantum.is_zero(), "alignment quantum must be positive");
+        self.checked_sub(nanos(self.as_nanos() % quantum.as_nanos()))
+    }
+
+    fn block_end(&self, quantum: &Self) -> Option<Self> {
+        assert!(!quantum.is_zero(), "alignment quantum must be positive");
+        let rem = self.as_nanos() % quantum.as_nanos();
+        self.checked_add(nanos(quantum.as_nanos() - 1 - rem))
+    }
+}
+
+/// Constructs a `Duration` from a number of nanoseconds no greater than the
+/// nanoseconds of some other `Duration`, so that the whole seconds fit in a
+/// `u64`.
+#[allow(clippy::cast_possible_truncation)]
+fn nanos(nanos: u128) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    let secs = (nanos / NANOS_PER_SEC) as u64;
+    let subsec = (nanos % NANOS_PER_SEC) as u32;
+    Duration::new(secs, subsec)
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Reflect
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the mirror image of [`Finite`] points around a center point. Used
+/// to reflect an [`Interval`] or [`Selection`].
+///
+/// [`Finite`]: trait.Finite.html
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+pub trait Reflect: Finite {
+    /// Returns the point as far from `center` as the given point, on the
+    /// other side of it, or `None` if it is not representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::normalize::Reflect;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(3i32.reflect(&5), Some(7));
+    /// assert_eq!(200u8.reflect(&150), Some(100));
+    /// assert_eq!(10u8.reflect(&150), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn reflect(&self, center: &Self) -> Option<Self>;
+}
+
+/// Implements reflection for a single builtin integer type.
+macro_rules! std_integer_reflect_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Reflect for $t {
+            fn reflect(&self, center: &Self) -> Option<Self> {
+                // The offset from the center overflows only if the reflected
+                // point does.
+                if self <= center {
+                    center.checked_add(center.checked_sub(*self)?)
+                } else {
+                    center.checked_sub(self.checked_sub(*center)?)
+                }
+            }
+        })*
+    };
+}
+
+// Provide implementations of Reflect for builtin integer types.
+std_integer_reflect_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+impl Reflect for Duration {
+    fn reflect(&self, center: &Self) -> Option<Self> {
+        if self <= center {
+            center.checked_add(center.checked_sub(*self)?)
+        } else {
+            center.checked_sub(self.checked_sub(*center)?)
+        }
+    }
+}
+
 // TODO: Implement when https://github.com/rust-lang/rust/issues/91399 is
 // complete and `next_down`, `next_up` are stable.
 // macro_rules! std_float_countable_impl {
@@ -0,0 +1,62 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides an operation type for recording and replaying `Selection` edits.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+
+// External library imports.
+#[cfg(feature="serde")] use serde::Deserialize;
+#[cfg(feature="serde")] use serde::Serialize;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionOp<T>
+////////////////////////////////////////////////////////////////////////////////
+/// An edit operation on a [`Selection`].
+///
+/// Operations are applied with [`Selection::apply`] or
+/// [`Selection::apply_all`]. Applying the same sequence of operations to equal
+/// `Selection`s always produces equal `Selection`s, so a log of operations can
+/// be replicated in place of the `Selection` itself.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Selection::apply`]: ../selection/struct.Selection.html#method.apply
+/// [`Selection::apply_all`]: ../selection/struct.Selection.html#method.apply_all
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+pub enum SelectionOp<T> {
+    /// Adds all of the points in the `Interval` to the `Selection`.
+    Union(Interval<T>),
+    /// Removes all of the points not in the `Interval` from the `Selection`.
+    Intersect(Interval<T>),
+    /// Removes all of the points in the `Interval` from the `Selection`.
+    Minus(Interval<T>),
+    /// Replaces the `Selection` with its complement.
+    Complement,
+    /// Removes all points from the `Selection`.
+    Clear,
+    /// Applies each of the operations in order.
+    Batch(Vec<Self>),
+}
+
+impl<T> SelectionOp<T> {
+    /// Constructs a `SelectionOp` applying each of the given operations in
+    /// order, avoiding a `Batch` if there is only one.
+    pub(in crate) fn batch(mut ops: Vec<Self>) -> Self {
+        if ops.len() == 1 {
+            ops.pop().expect("nonempty ops")
+        } else {
+            Self::Batch(ops)
+        }
+    }
+}
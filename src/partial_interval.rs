@@ -0,0 +1,733 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides an interval type for partially ordered points.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PartialInterval<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A contiguous interval of the partially ordered type T.
+///
+/// Unlike [`Interval`], a `PartialInterval` only requires its points to be
+/// `PartialOrd`. Any operation which needs to compare two points that are
+/// incomparable will fail with an [`IncomparableError`] rather than silently
+/// producing an incorrect result. `PartialInterval`s are not normalized.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`IncomparableError`]: struct.IncomparableError.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialInterval<T>(pub (crate) RawInterval<T>);
+
+impl<T> Default for PartialInterval<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> PartialInterval<T> {
+    ////////////////////////////////////////////////////////////////////////////
+    // Infallible constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `PartialInterval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: PartialInterval<f64> = PartialInterval::empty();
+    ///
+    /// assert!(interval.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(RawInterval::Empty)
+    }
+
+    /// Constructs a new `PartialInterval` containing a single point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::point(1.5);
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn point(point: T) -> Self {
+        Self(RawInterval::Point(point))
+    }
+
+    /// Constructs a new `PartialInterval` containing all points less than or
+    /// equal to the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::unbounded_to(1.5);
+    ///
+    /// assert_eq!(interval.contains(&-8.0), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unbounded_to(point: T) -> Self {
+        Self(RawInterval::To(point))
+    }
+
+    /// Constructs a new `PartialInterval` containing all points greater than
+    /// or equal to the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::unbounded_from(1.5);
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unbounded_from(point: T) -> Self {
+        Self(RawInterval::From(point))
+    }
+
+    /// Constructs a new `PartialInterval` containing all points less than the
+    /// given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::unbounded_up_to(1.5);
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(false));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unbounded_up_to(point: T) -> Self {
+        Self(RawInterval::UpTo(point))
+    }
+
+    /// Constructs a new `PartialInterval` containing all points greater than
+    /// the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::unbounded_up_from(1.5);
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(false));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unbounded_up_from(point: T) -> Self {
+        Self(RawInterval::UpFrom(point))
+    }
+
+    /// Constructs a new `PartialInterval` containing all points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: PartialInterval<f64> = PartialInterval::full();
+    ///
+    /// assert!(interval.is_full());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn full() -> Self {
+        Self(RawInterval::Full)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `PartialInterval` contains no points.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self.0, RawInterval::Empty)
+    }
+
+    /// Returns `true` if the `PartialInterval` contains all points.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        matches!(self.0, RawInterval::Full)
+    }
+
+    /// Returns the lower [`Bound`] of the `PartialInterval`, or `None` if the
+    /// `PartialInterval` is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::open(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.lower_bound(), Some(Bound::Exclude(0.5)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn lower_bound(&self) -> Option<Bound<T>> where T: Clone {
+        self.0.lower_bound()
+    }
+
+    /// Returns the upper [`Bound`] of the `PartialInterval`, or `None` if the
+    /// `PartialInterval` is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::open(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.upper_bound(), Some(Bound::Exclude(1.5)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn upper_bound(&self) -> Option<Bound<T>> where T: Clone {
+        self.0.upper_bound()
+    }
+}
+
+impl<T> PartialInterval<T> where T: PartialOrd + Clone {
+    ////////////////////////////////////////////////////////////////////////////
+    // Fallible constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `PartialInterval` from the given [`Bound`]s. If the
+    /// upper bound point is less than the lower bound point, an empty
+    /// `PartialInterval` will be returned.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the bound points are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::new(
+    ///     Bound::Include(0.5),
+    ///     Bound::Exclude(1.5))?;
+    ///
+    /// assert_eq!(interval, PartialInterval::right_open(0.5, 1.5)?);
+    ///
+    /// assert!(PartialInterval::new(
+    ///     Bound::Include(0.5),
+    ///     Bound::Exclude(f64::NAN)).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(lower: Bound<T>, upper: Bound<T>)
+        -> Result<Self, IncomparableError>
+    {
+        use Bound::*;
+        use RawInterval::*;
+        Ok(Self(match (lower, upper) {
+            (Include(l), Include(u)) => match compare(&l, &u)? {
+                Ordering::Less    => Closed(l, u),
+                Ordering::Equal   => Point(l),
+                Ordering::Greater => Empty,
+            },
+            (Include(l), Exclude(u)) => match compare(&l, &u)? {
+                Ordering::Less    => RightOpen(l, u),
+                _                 => Empty,
+            },
+            (Exclude(l), Include(u)) => match compare(&l, &u)? {
+                Ordering::Less    => LeftOpen(l, u),
+                _                 => Empty,
+            },
+            (Exclude(l), Exclude(u)) => match compare(&l, &u)? {
+                Ordering::Less    => Open(l, u),
+                _                 => Empty,
+            },
+            (Include(l), Infinite)   => From(l),
+            (Exclude(l), Infinite)   => UpFrom(l),
+            (Infinite,   Include(u)) => To(u),
+            (Infinite,   Exclude(u)) => UpTo(u),
+            (Infinite,   Infinite)   => Full,
+        }))
+    }
+
+    /// Constructs a new open `PartialInterval` from the given points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the points are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::open(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.contains(&0.5), Ok(false));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn open(lower: T, upper: T) -> Result<Self, IncomparableError> {
+        Self::new(Bound::Exclude(lower), Bound::Exclude(upper))
+    }
+
+    /// Constructs a new left-open `PartialInterval` from the given points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the points are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::left_open(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn left_open(lower: T, upper: T) -> Result<Self, IncomparableError> {
+        Self::new(Bound::Exclude(lower), Bound::Include(upper))
+    }
+
+    /// Constructs a new right-open `PartialInterval` from the given points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the points are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::right_open(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.contains(&0.5), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn right_open(lower: T, upper: T) -> Result<Self, IncomparableError> {
+        Self::new(Bound::Include(lower), Bound::Exclude(upper))
+    }
+
+    /// Constructs a new closed `PartialInterval` from the given points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the points are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::closed(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.contains(&1.5), Ok(true));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closed(lower: T, upper: T) -> Result<Self, IncomparableError> {
+        Self::new(Bound::Include(lower), Bound::Include(upper))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Queries
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `PartialInterval` contains the given point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the point is incomparable with
+    /// either bound of the `PartialInterval`.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::closed(0.5, 1.5)?;
+    ///
+    /// assert_eq!(interval.contains(&1.0), Ok(true));
+    /// assert_eq!(interval.contains(&2.0), Ok(false));
+    /// assert!(interval.contains(&f64::NAN).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, point: &T) -> Result<bool, IncomparableError> {
+        use Bound::*;
+        let Some((lower, upper)) = self.bounds() else { return Ok(false) };
+        let above_lower = match lower {
+            Include(l) => compare(point, &l)? != Ordering::Less,
+            Exclude(l) => compare(point, &l)? == Ordering::Greater,
+            Infinite   => true,
+        };
+        let below_upper = match upper {
+            Include(u) => compare(point, &u)? != Ordering::Greater,
+            Exclude(u) => compare(point, &u)? == Ordering::Less,
+            Infinite   => true,
+        };
+        Ok(above_lower && below_upper)
+    }
+
+    /// Returns `true` if the `PartialInterval`s have any points in common.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the bounds of the
+    /// `PartialInterval`s are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = PartialInterval::closed(0.5, 1.5)?;
+    /// let b = PartialInterval::open(1.5, 2.5)?;
+    ///
+    /// assert_eq!(a.intersects(&b), Ok(false));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersects(&self, other: &Self) -> Result<bool, IncomparableError> {
+        self.intersect(other).map(|i| !i.is_empty())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the set intersection of the `PartialInterval`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the bounds of the
+    /// `PartialInterval`s are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = PartialInterval::closed(0.5, 1.5)?;
+    /// let b = PartialInterval::open(1.0, 2.5)?;
+    ///
+    /// assert_eq!(a.intersect(&b)?, PartialInterval::left_open(1.0, 1.5)?);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Result<Self, IncomparableError> {
+        match (self.bounds(), other.bounds()) {
+            (Some((sl, su)), Some((ol, ou))) => Self::new(
+                greatest_lower(sl, ol)?,
+                least_upper(su, ou)?),
+            _ => Ok(Self::empty()),
+        }
+    }
+
+    /// Returns the smallest `PartialInterval` containing all of the points in
+    /// both `PartialInterval`s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncomparableError`] if the bounds of the
+    /// `PartialInterval`s are incomparable.
+    ///
+    /// [`IncomparableError`]: struct.IncomparableError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = PartialInterval::closed(0.5, 1.5)?;
+    /// let b = PartialInterval::open(2.0, 2.5)?;
+    ///
+    /// assert_eq!(a.enclose(&b)?, PartialInterval::right_open(0.5, 2.5)?);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose(&self, other: &Self) -> Result<Self, IncomparableError> {
+        match (self.bounds(), other.bounds()) {
+            (Some((sl, su)), Some((ol, ou))) => Self::new(
+                least_lower(sl, ol)?,
+                greatest_upper(su, ou)?),
+            (Some(_), None) => Ok(self.clone()),
+            _               => Ok(other.clone()),
+        }
+    }
+
+    /// Returns the lower and upper bounds of the `PartialInterval`, or `None`
+    /// if it is empty.
+    fn bounds(&self) -> Option<(Bound<T>, Bound<T>)> {
+        self.lower_bound().zip(self.upper_bound())
+    }
+}
+
+impl<T> PartialInterval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Converts the `PartialInterval` into a normalized [`Interval`]. This
+    /// cannot fail, as all points of a totally ordered type are comparable.
+    ///
+    /// [`Interval`]: ../interval/struct.Interval.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::partial_interval::PartialInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval = PartialInterval::open(3, 7)?;
+    ///
+    /// assert_eq!(interval.into_interval(), Interval::closed(4, 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn into_interval(self) -> Interval<T> {
+        Interval::from(self.0)
+    }
+}
+
+impl<T> From<Interval<T>> for PartialInterval<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self(interval.0)
+    }
+}
+
+impl<T> std::fmt::Display for PartialInterval<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound comparison helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compares the given points, failing if they are incomparable.
+fn compare<T>(a: &T, b: &T) -> Result<Ordering, IncomparableError>
+    where T: PartialOrd
+{
+    a.partial_cmp(b).ok_or(IncomparableError)
+}
+
+/// Compares the points of two bounds, failing if they are incomparable. If
+/// either bound is infinite, `None` is returned.
+fn compare_bounds<T>(a: &Bound<T>, b: &Bound<T>)
+    -> Result<Option<Ordering>, IncomparableError>
+    where T: PartialOrd
+{
+    match (a.as_ref(), b.as_ref()) {
+        (Some(p), Some(o)) => compare(p, o).map(Some),
+        _                  => Ok(None),
+    }
+}
+
+/// Returns the greater of two lower bounds.
+fn greatest_lower<T>(a: Bound<T>, b: Bound<T>)
+    -> Result<Bound<T>, IncomparableError>
+    where T: PartialOrd
+{
+    Ok(match compare_bounds(&a, &b)? {
+        None if a.is_finite()   => a,
+        None                    => b,
+        Some(Ordering::Less)    => b,
+        Some(Ordering::Greater) => a,
+        Some(Ordering::Equal)   => if a.is_exclusive() { a } else { b },
+    })
+}
+
+/// Returns the lesser of two upper bounds.
+fn least_upper<T>(a: Bound<T>, b: Bound<T>)
+    -> Result<Bound<T>, IncomparableError>
+    where T: PartialOrd
+{
+    Ok(match compare_bounds(&a, &b)? {
+        None if a.is_finite()   => a,
+        None                    => b,
+        Some(Ordering::Less)    => a,
+        Some(Ordering::Greater) => b,
+        Some(Ordering::Equal)   => if a.is_exclusive() { a } else { b },
+    })
+}
+
+/// Returns the lesser of two lower bounds.
+fn least_lower<T>(a: Bound<T>, b: Bound<T>)
+    -> Result<Bound<T>, IncomparableError>
+    where T: PartialOrd
+{
+    Ok(match compare_bounds(&a, &b)? {
+        None                    => Bound::Infinite,
+        Some(Ordering::Less)    => a,
+        Some(Ordering::Greater) => b,
+        Some(Ordering::Equal)   => if a.is_inclusive() { a } else { b },
+    })
+}
+
+/// Returns the greater of two upper bounds.
+fn greatest_upper<T>(a: Bound<T>, b: Bound<T>)
+    -> Result<Bound<T>, IncomparableError>
+    where T: PartialOrd
+{
+    Ok(match compare_bounds(&a, &b)? {
+        None                    => Bound::Infinite,
+        Some(Ordering::Less)    => b,
+        Some(Ordering::Greater) => a,
+        Some(Ordering::Equal)   => if a.is_inclusive() { a } else { b },
+    })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IncomparableError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when a `PartialInterval` operation must compare two
+/// incomparable points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IncomparableError;
+
+impl std::fmt::Display for IncomparableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "interval points are incomparable")
+    }
+}
+
+impl std::error::Error for IncomparableError {}
@@ -0,0 +1,207 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides query predicates testing whether a column value is in a
+//! `Selection`, for pushing a `Selection` down to a query engine.
+//!
+//! A [`Predicate`] is a disjunction of comparison [`Clause`]s, one for each
+//! `Interval` of the `Selection`. Query engines slow down on very long
+//! disjunctions, so the number of clauses may be capped, in which case the
+//! predicate tests the enclosing `Interval` instead and matches a superset
+//! of the points. Such a predicate is not exact, and the engine's results
+//! must be filtered by the `Selection` afterwards.
+//!
+//! Predicates are rendered as SQL by [`Predicate::sql`]:
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use normalize_interval::Interval;
+//! # use normalize_interval::Selection;
+//! # use normalize_interval::predicate::Predicate;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! let mut sel: Selection<i32> = Selection::from(Interval::closed(1, 5));
+//! sel.union_in_place(Interval::point(8));
+//! sel.union_in_place(Interval::unbounded_from(10));
+//!
+//! let predicate = Predicate::from_selection(&sel, 16);
+//! assert!(predicate.is_exact());
+//! assert_eq!(predicate.sql("x").to_string(),
+//!     "(x BETWEEN 1 AND 5 OR x = 8 OR x >= 10)");
+//!
+//! let capped = Predicate::from_selection(&sel, 2);
+//! assert!(!capped.is_exact());
+//! assert_eq!(capped.sql("x").to_string(), "x >= 1");
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`Predicate`]: struct.Predicate.html
+//! [`Clause`]: enum.Clause.html
+//! [`Predicate::sql`]: struct.Predicate.html#method.sql
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt;
+use std::fmt::Display;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Clause
+////////////////////////////////////////////////////////////////////////////////
+/// A comparison of a column value, matching the points of an `Interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Clause<T> {
+    /// Matches every value.
+    Any,
+    /// Matches the given value.
+    Equal(T),
+    /// Matches values greater than or equal to the given value.
+    AtLeast(T),
+    /// Matches values less than or equal to the given value.
+    AtMost(T),
+    /// Matches values between the given values, inclusive.
+    Between(T, T),
+}
+
+impl<T> Clause<T> where T: Finite + Ord + Clone {
+    /// Returns the `Clause` matching the points of the given `Interval`, or
+    /// `None` if the `Interval` is empty.
+    #[must_use]
+    pub fn from_interval(interval: &Interval<T>) -> Option<Self>
+        where RawInterval<T>: Normalize
+    {
+        let lower = interval.infimum()?;
+        let upper = interval.supremum()?;
+        Some(match (lower == T::MINIMUM, upper == T::MAXIMUM) {
+            (true,  true)       => Self::Any,
+            _ if lower == upper => Self::Equal(lower),
+            (true,  false)      => Self::AtMost(upper),
+            (false, true)       => Self::AtLeast(lower),
+            (false, false)      => Self::Between(lower, upper),
+        })
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Predicate
+////////////////////////////////////////////////////////////////////////////////
+/// A disjunction of `Clause`s, matching a column value if any of its
+/// `Clause`s match. A `Predicate` with no `Clause`s matches nothing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Predicate<T> {
+    /// The `Clause`s of the disjunction, in order of the points they match.
+    clauses: Vec<Clause<T>>,
+    /// Whether the `Predicate` matches exactly the points of its `Selection`.
+    exact: bool,
+}
+
+impl<T> Predicate<T> where T: Finite + Ord + Clone {
+    /// Constructs a `Predicate` matching the points of the given `Selection`,
+    /// with one `Clause` per `Interval`. If that would need more than
+    /// `max_clauses` `Clause`s, the `Predicate` instead matches the enclosing
+    /// `Interval` of the `Selection` with a single `Clause`. A single
+    /// `Clause` is always allowed, even if `max_clauses` is zero.
+    #[must_use]
+    pub fn from_selection(selection: &Selection<T>, max_clauses: usize)
+        -> Self
+        where RawInterval<T>: Normalize
+    {
+        let clauses: Vec<_> = selection
+            .interval_iter()
+            .filter_map(|interval| Clause::from_interval(&interval))
+            .collect();
+        if clauses.len() <= max_clauses.max(1) {
+            return Self { clauses, exact: true };
+        }
+        Self {
+            clauses: Clause::from_interval(&selection.enclose())
+                .into_iter()
+                .collect(),
+            exact: false,
+        }
+    }
+
+    /// Returns the `Clause`s of the `Predicate`, in order of the points they
+    /// match.
+    #[must_use]
+    pub fn clauses(&self) -> &[Clause<T>] {
+        &self.clauses
+    }
+
+    /// Returns `true` if the `Predicate` matches exactly the points of the
+    /// `Selection` it was constructed from, or `false` if it matches the
+    /// enclosing `Interval` instead.
+    #[must_use]
+    pub const fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Returns a value which displays the `Predicate` as a SQL expression
+    /// over the given column. The column is written as given, so it must
+    /// already be quoted if necessary.
+    ///
+    /// An empty `Predicate` is written `FALSE`, and a `Clause` matching every
+    /// value as `IS NOT NULL`, so that null values are never matched. A
+    /// disjunction of several `Clause`s is parenthesized, so that it may be
+    /// combined with other conditions.
+    #[must_use]
+    pub fn sql<'p>(&'p self, column: &'p str) -> Sql<'p, T> {
+        Sql { predicate: self, column }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Sql
+////////////////////////////////////////////////////////////////////////////////
+/// Displays a `Predicate` as a SQL expression. Returned by
+/// [`Predicate::sql`].
+///
+/// [`Predicate::sql`]: struct.Predicate.html#method.sql
+#[derive(Debug, Clone, Copy)]
+pub struct Sql<'p, T> {
+    /// The `Predicate` to display.
+    predicate: &'p Predicate<T>,
+    /// The column tested by the `Predicate`.
+    column: &'p str,
+}
+
+impl<T> Display for Sql<'_, T> where T: Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = self.column;
+        let clauses = &self.predicate.clauses;
+        if clauses.is_empty() { return write!(f, "FALSE"); }
+
+        if clauses.len() > 1 { write!(f, "(")?; }
+        for (idx, clause) in clauses.iter().enumerate() {
+            if idx > 0 { write!(f, " OR ")?; }
+            match clause {
+                Clause::Any           => write!(f, "{} IS NOT NULL", column)?,
+                Clause::Equal(p)      => write!(f, "{} = {}", column, p)?,
+                Clause::AtLeast(l)    => write!(f, "{} >= {}", column, l)?,
+                Clause::AtMost(u)     => write!(f, "{} <= {}", column, u)?,
+                Clause::Between(l, u) => write!(f,
+                    "{} BETWEEN {} AND {}", column, l, u)?,
+            }
+        }
+        if clauses.len() > 1 { write!(f, ")")?; }
+        Ok(())
+    }
+}
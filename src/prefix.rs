@@ -0,0 +1,167 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides decomposition of integer intervals into digit prefix ranges.
+//!
+//! A prefix range `(prefix, len)` in a given radix contains every number whose
+//! digits begin with the digits of `prefix`, followed by any `len` digits. In
+//! radix 10, `(42, 2)` is the range `4200..=4299`. Such ranges correspond to
+//! key prefixes in prefix-sharded storage, and in radix 2 to network prefixes.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Prefix ranges
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the fewest prefix ranges in the given radix which exactly cover the
+/// `Interval`, in ascending order.
+///
+/// # Panics
+///
+/// Panics if `radix` is less than 2.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::prefix::prefix_ranges;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let ranges = prefix_ranges(&Interval::closed(1195, 1399), 10);
+///
+/// assert_eq!(ranges, [(1195, 0), (1196, 0), (1197, 0), (1198, 0),
+///     (1199, 0), (12, 2), (13, 2)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn prefix_ranges(interval: &Interval<u64>, radix: u64) -> Vec<(u64, u32)> {
+    assert!(radix >= 2, "prefix radix must be at least 2");
+
+    let (Some(&lower), Some(&upper)) = (
+        interval.0.lower_point(),
+        interval.0.upper_point()) else { return Vec::new() };
+
+    let mut ranges = Vec::new();
+    push_prefix_ranges(&mut ranges, lower, upper, radix);
+    ranges
+}
+
+/// Returns the fewest prefix ranges in the given radix which exactly cover the
+/// `Selection`, in ascending order.
+///
+/// # Panics
+///
+/// Panics if `radix` is less than 2.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::prefix::selection_prefix_ranges;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel = Selection::from(Interval::closed(0x100, 0x1ff));
+/// sel.union_in_place(Interval::closed(0x300, 0x30f));
+///
+/// assert_eq!(selection_prefix_ranges(&sel, 16), [(0x1, 2), (0x30, 1)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn selection_prefix_ranges(selection: &Selection<u64>, radix: u64)
+    -> Vec<(u64, u32)>
+{
+    assert!(radix >= 2, "prefix radix must be at least 2");
+
+    let mut ranges = Vec::new();
+    for interval in selection.interval_iter() {
+        let (Some(&lower), Some(&upper)) = (
+            interval.0.lower_point(),
+            interval.0.upper_point()) else { continue };
+        push_prefix_ranges(&mut ranges, lower, upper, radix);
+    }
+    ranges
+}
+
+/// Returns the `Interval` covered by the given prefix range, or `None` if it
+/// exceeds the range of `u64`.
+///
+/// # Panics
+///
+/// Panics if `radix` is less than 2.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::prefix::prefix_range_interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(prefix_range_interval(42, 2, 10),
+///     Some(Interval::closed(4200, 4299)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn prefix_range_interval(prefix: u64, len: u32, radix: u64)
+    -> Option<Interval<u64>>
+{
+    assert!(radix >= 2, "prefix radix must be at least 2");
+
+    // Blocks may extend one past `u64::MAX`, so they are computed in `u128`.
+    let block = u128::from(radix).checked_pow(len)?;
+    let lower = u128::from(prefix).checked_mul(block)?;
+    let upper = lower.checked_add(block - 1)?;
+    Some(Interval::closed(
+        u64::try_from(lower).ok()?,
+        u64::try_from(upper).ok()?))
+}
+
+/// Appends the fewest prefix ranges covering `lower..=upper` to `ranges`.
+fn push_prefix_ranges(ranges: &mut Vec<(u64, u32)>,
+    lower: u64,
+    upper: u64,
+    radix: u64)
+{
+    // Blocks may extend one past `u64::MAX`, so they are computed in `u128`.
+    let (upper, radix) = (u128::from(upper), u128::from(radix));
+    let mut start = u128::from(lower);
+
+    while start <= upper {
+        // Take the largest aligned block starting here which fits.
+        let mut block: u128 = 1;
+        let mut len = 0;
+        while let Some(next) = block.checked_mul(radix) {
+            if start % next != 0 || start + (next - 1) > upper { break; }
+            block = next;
+            len += 1;
+        }
+        let prefix = u64::try_from(start / block)
+            .expect("prefix within u64 range");
+        ranges.push((prefix, len));
+        start += block;
+    }
+}
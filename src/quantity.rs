@@ -0,0 +1,162 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides `Interval`s and `Selection`s of [`uom`] quantities, such as
+//! `Interval<Length>` and `Selection<Time>`. Requires the `uom` feature.
+//!
+//! A quantity is stored as a value in the base units of its dimension, so a
+//! quantity stored as an integer is [`Finite`], with successive points one
+//! base unit apart. The [`Measurable`] span between two quantities is a
+//! quantity of the same dimension, so the width of an `Interval<Length>` is a
+//! `Length`. Spans which exceed the range of the storage type saturate at its
+//! maximum.
+//!
+//! Quantities stored as floating point numbers are not totally ordered, and
+//! so cannot bound an `Interval`. Use an integer storage type such as those
+//! in `uom::si::i64`.
+//!
+//! [`uom`]: https://docs.rs/uom/0.38/uom/
+//! [`Finite`]: ../normalize/trait.Finite.html
+//! [`Measurable`]: ../measure/trait.Measurable.html
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::error::Error;
+//! # use normalize_interval::Interval;
+//! # use normalize_interval::Selection;
+//! # use uom::si::i64::Length;
+//! # use uom::si::i64::Time;
+//! # use uom::si::length::kilometer;
+//! # use uom::si::length::meter;
+//! # use uom::si::time::second;
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! # //-------------------------------------------------------------------
+//! let route = Interval::closed(
+//!     Length::new::<meter>(250),
+//!     Length::new::<kilometer>(2));
+//! assert_eq!(route.size(), Some(Length::new::<meter>(1750)));
+//!
+//! let mut busy: Selection<Time> = Selection::from(Interval::right_open(
+//!     Time::new::<second>(0),
+//!     Time::new::<second>(30)));
+//! busy.union_in_place(Interval::right_open(
+//!     Time::new::<second>(60),
+//!     Time::new::<second>(75)));
+//! assert_eq!(busy.measure(), Some(Time::new::<second>(43)));
+//! # //-------------------------------------------------------------------
+//! #     Ok(())
+//! # }
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::measure::CheckedAdd;
+use crate::measure::Measurable;
+use crate::measure::Ratio;
+use crate::normalize::Finite;
+
+// External library imports.
+use uom::Conversion;
+use uom::num_traits::Bounded;
+use uom::num_traits::CheckedSub;
+use uom::num_traits::Num;
+use uom::si::Dimension;
+use uom::si::Quantity;
+use uom::si::Units;
+
+// Standard library imports.
+use std::marker::PhantomData;
+
+
+/// Returns the quantity with the given value in base units.
+const fn from_base<D, U, V>(value: V) -> Quantity<D, U, V>
+    where
+        D: Dimension + ?Sized,
+        U: Units<V> + ?Sized,
+        V: Num + Conversion<V>,
+{
+    Quantity { dimension: PhantomData, units: PhantomData, value }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Finite implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// Quantities are counted in base units.
+impl<D, U, V> Finite for Quantity<D, U, V>
+    where
+        D: Dimension + ?Sized,
+        U: Units<V> + ?Sized,
+        V: Num + Conversion<V> + Finite,
+{
+    const MINIMUM: Self = from_base(V::MINIMUM);
+    const MAXIMUM: Self = from_base(V::MAXIMUM);
+
+    fn pred(&self) -> Option<Self> {
+        self.value.pred().map(from_base)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.value.succ().map(from_base)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measurable implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Spans are quantities of the same dimension, saturating at the maximum value
+// of the storage type. Extents count base units inclusively.
+impl<D, U, V> Measurable for Quantity<D, U, V>
+    where
+        D: Dimension + ?Sized,
+        U: Units<V> + ?Sized,
+        V: Num + Conversion<V> + CheckedSub + CheckedAdd + Bounded,
+{
+    type Length = Self;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        from_base(upper.value
+            .checked_sub(&lower.value)
+            .unwrap_or_else(V::max_value))
+    }
+
+    fn extent(lower: &Self, upper: &Self) -> Option<Self::Length> {
+        upper.value
+            .checked_sub(&lower.value)
+            .and_then(|span| CheckedAdd::checked_add(&span, &V::one()))
+            .map(from_base)
+    }
+}
+
+impl<D, U, V> CheckedAdd for Quantity<D, U, V>
+    where
+        D: Dimension + ?Sized,
+        U: Units<V> + ?Sized,
+        V: Num + Conversion<V> + CheckedAdd,
+{
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.value.checked_add(&other.value).map(from_base)
+    }
+}
+
+impl<D, U, V> Ratio for Quantity<D, U, V>
+    where
+        D: Dimension + ?Sized,
+        U: Units<V> + ?Sized,
+        V: Num + Conversion<V> + Ratio,
+{
+    fn ratio(&self, whole: &Self) -> f64 {
+        self.value.ratio(&whole.value)
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides parsing and formatting of `Selection`s in range list syntax, as
+//! used for page ranges and CPU lists.
+//!
+//! A range list is a comma-separated list of items, each of which is a point
+//! (`8`), a closed range (`1-5`), a range unbounded above (`10-`), a range
+//! unbounded below (`-3`), or the full range (`-`). The list `1-5,8,10-`
+//! denotes the `Selection` `[1,5] ∪ {8} ∪ [10,∞)`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::str::FromStr;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a `Selection` from range list syntax. Whitespace around items and
+/// their points is ignored, and an empty list parses as an empty `Selection`.
+///
+/// An item starting with `-` is first parsed as a point, so that negative
+/// points of signed types are read correctly. The range separator of any
+/// other item is the first `-` after its first character.
+///
+/// # Errors
+///
+/// Returns a [`RangeListParseError::InvalidItem`] if an item is empty, or a
+/// [`RangeListParseError::InvalidValue`] if a point could not be parsed.
+///
+/// [`RangeListParseError::InvalidItem`]: enum.RangeListParseError.html#variant.InvalidItem
+/// [`RangeListParseError::InvalidValue`]: enum.RangeListParseError.html#variant.InvalidValue
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::range_list::parse_range_list;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<u32> = parse_range_list("1-5,8,10-").unwrap();
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(1, 5),
+///     Interval::point(8),
+///     Interval::closed(10, u32::MAX),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn parse_range_list<T>(s: &str)
+    -> Result<Selection<T>, RangeListParseError<T::Err>>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    let mut selection = Selection::new();
+    if s.trim().is_empty() { return Ok(selection); }
+
+    for item in s.split(',') {
+        selection.union_in_place(parse_item(item.trim())?);
+    }
+    Ok(selection)
+}
+
+/// Parses a single range list item.
+fn parse_item<T>(item: &str)
+    -> Result<Interval<T>, RangeListParseError<T::Err>>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    /// Parses a single point of a range list item.
+    fn parse_point<T>(point: &str) -> Result<T, RangeListParseError<T::Err>>
+        where T: FromStr
+    {
+        T::from_str(point.trim()).map_err(RangeListParseError::InvalidValue)
+    }
+
+    if item.is_empty() { return Err(RangeListParseError::InvalidItem); }
+    if let Ok(point) = T::from_str(item) { return Ok(Interval::point(point)); }
+
+    let split = item
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| c == '-')
+        .map(|(idx, _)| idx);
+
+    match (split, item.strip_prefix('-')) {
+        (Some(idx), _) => {
+            let lower = parse_point(&item[..idx])?;
+            let upper = item[idx + 1..].trim();
+            if upper.is_empty() {
+                Ok(Interval::unbounded_from(lower))
+            } else {
+                Ok(Interval::closed(lower, parse_point(upper)?))
+            }
+        },
+        (None, Some(upper)) if upper.trim().is_empty() => Ok(Interval::full()),
+        (None, Some(upper)) => Ok(Interval::unbounded_to(parse_point(upper)?)),
+        (None, None) => parse_point(item).map(Interval::point),
+    }
+}
+
+/// Error type returned by failure to parse a range list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeListParseError<E> {
+    /// An item of the list is empty.
+    InvalidItem,
+    /// An error occurred during a value parse.
+    InvalidValue(E),
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeList
+////////////////////////////////////////////////////////////////////////////////
+/// Displays a `Selection` of [`Finite`] points in range list syntax.
+///
+/// Each `Interval` is written as a point or a closed range, and an `Interval`
+/// extending to the maximum point is written unbounded above.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::range_list::RangeList;
+/// # use normalize_interval::range_list::parse_range_list;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<u32> = parse_range_list("8, 10-, 1-3, 4-5").unwrap();
+///
+/// assert_eq!(RangeList(&sel).to_string(), "1-5,8,10-");
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RangeList<'s, T>(pub &'s Selection<T>);
+
+impl<T> Display for RangeList<'_, T>
+    where T: Finite + Ord + Clone + Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for interval in self.0.interval_iter() {
+            let (Some(lower), Some(upper)) = (
+                interval.0.lower_point(),
+                interval.0.upper_point()) else { continue };
+
+            if !first { write!(f, ",")?; }
+            first = false;
+
+            if lower == upper {
+                write!(f, "{}", lower)?;
+            } else if *upper == T::MAXIMUM {
+                write!(f, "{}-", lower)?;
+            } else {
+                write!(f, "{}-{}", lower, upper)?;
+            }
+        }
+        Ok(())
+    }
+}
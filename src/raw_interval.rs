@@ -15,6 +15,7 @@ use crate::bound::Bound;
 // External library imports.
 #[cfg(feature="serde")] use serde::Deserialize;
 #[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="schemars")] use schemars::JsonSchema;
 use few::Few;
 
 // Standard library imports.
@@ -31,6 +32,7 @@ use std::str::FromStr;
 /// [`Interval`]: interval/struct.Interval.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="schemars", derive(JsonSchema))]
 pub enum RawInterval<T> {
     /// An interval containing no points.
     Empty,
@@ -77,6 +79,41 @@ impl<T> RawInterval<T> {
     pub fn is_full(&self) -> bool {
         matches!(self, Self::Full)
     }
+
+    // Bound point references
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a reference to the lower bound point of the interval, or `None`
+    /// if the interval is empty or has no finite lower bound.
+    pub fn lower_point(&self) -> Option<&T> {
+        use RawInterval::*;
+        match *self {
+            Point(ref p)        |
+            Open(ref p, _)      |
+            LeftOpen(ref p, _)  |
+            RightOpen(ref p, _) |
+            Closed(ref p, _)    |
+            UpFrom(ref p)       |
+            From(ref p)         => Some(p),
+            _                   => None,
+        }
+    }
+
+    /// Returns a reference to the upper bound point of the interval, or `None`
+    /// if the interval is empty or has no finite upper bound.
+    pub fn upper_point(&self) -> Option<&T> {
+        use RawInterval::*;
+        match *self {
+            Point(ref p)        |
+            Open(_, ref p)      |
+            LeftOpen(_, ref p)  |
+            RightOpen(_, ref p) |
+            Closed(_, ref p)    |
+            UpTo(ref p)         |
+            To(ref p)           => Some(p),
+            _                   => None,
+        }
+    }
 }
 
 impl<T> RawInterval<T> where T: Ord {
@@ -184,6 +221,62 @@ impl<T> RawInterval<T> where T: Ord {
             Full                    => true,
         }
     }
+
+    // Parsing
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Parses an interval written in interval notation, using the given
+    /// function to parse each of its points. This accepts the same syntax as
+    /// the `FromStr` implementation, which parses points using `T::from_str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IntervalParseError::InvalidInterval`] if the text is not
+    /// in interval notation, or an [`IntervalParseError::InvalidValue`]
+    /// containing the error returned by `parse_point` if a point could not be
+    /// parsed.
+    ///
+    /// [`IntervalParseError::InvalidInterval`]: enum.IntervalParseError.html#variant.InvalidInterval
+    /// [`IntervalParseError::InvalidValue`]: enum.IntervalParseError.html#variant.InvalidValue
+    pub fn parse_with<F, E>(s: &str, mut parse_point: F)
+        -> Result<Self, IntervalParseError<E>>
+        where F: FnMut(&str) -> Result<T, E>
+    {
+        use RawInterval::*;
+        // Parse empty interval.
+        if s.starts_with("Ø") { return Ok(Empty); }
+        // Parse point interval.
+        if let Ok(p) = parse_point(s) { return Ok(Point(p)); }
+
+        let (x, y) = s.split_once(',')
+            .ok_or(IntervalParseError::InvalidInterval)?;
+
+        let lb = if x.starts_with("(-∞") { 
+            Bound::Infinite
+        } else if let Some(res) = x.strip_prefix('(') {
+            Bound::Exclude(parse_point(res)
+                .map_err(IntervalParseError::InvalidValue)?)
+        } else if let Some(res) = x.strip_prefix('[') {
+            Bound::Include(parse_point(res)
+                .map_err(IntervalParseError::InvalidValue)?)
+        } else {
+            return Err(IntervalParseError::InvalidInterval);
+        };
+
+        let ub = if y.ends_with("∞)") { 
+            Bound::Infinite
+        } else if let Some(res) = y.strip_suffix(')') {
+            Bound::Exclude(parse_point(res)
+                .map_err(IntervalParseError::InvalidValue)?)
+        } else if let Some(res) = y.strip_suffix(']') {
+            Bound::Include(parse_point(res)
+                .map_err(IntervalParseError::InvalidValue)?)
+        } else {
+            return Err(IntervalParseError::InvalidInterval);
+        };
+
+        Ok(Self::new(lb, ub))
+    }
 }
 
 impl<T> RawInterval<T> where T: Clone {
@@ -232,22 +325,12 @@ impl<T> RawInterval<T> where T: Clone {
 
     /// Returns the greatest lower bound of the interval.
     pub fn infimum(&self) -> Option<T> {
-        use Bound::*;
-        match self.lower_bound() {
-            Some(Include(ref b)) => Some(b.clone()),
-            Some(Exclude(ref b)) => Some(b.clone()),
-            _ => None,
-        }
+        self.lower_point().cloned()
     }
     
     /// Returns the least upper bound of the interval.
     pub fn supremum(&self) -> Option<T> {
-        use Bound::*;
-        match self.upper_bound() {
-            Some(Include(ref b)) => Some(b.clone()),
-            Some(Exclude(ref b)) => Some(b.clone()),
-            _ => None,
-        }
+        self.upper_point().cloned()
     }
 }
 
@@ -384,6 +467,22 @@ impl<T> RawInterval<T> where T: Ord + Clone {
         }
     }
 
+    /// Returns the largest open interval that contains only points contained
+    /// within the interval.
+    #[must_use]
+    pub fn interior(&self) -> Self {
+        use RawInterval::*;
+        match self {
+            Point(_)        => Empty,
+            LeftOpen(l, r)  => Open(l.clone(), r.clone()),
+            RightOpen(l, r) => Open(l.clone(), r.clone()),
+            Closed(l, r)    => Open(l.clone(), r.clone()),
+            To(r)           => UpTo(r.clone()),
+            From(l)         => UpFrom(l.clone()),
+            _               => self.clone(),
+        }
+    }
+
     // Bulk set operations
     ////////////////////////////////////////////////////////////////////////////
 
@@ -392,7 +491,7 @@ impl<T> RawInterval<T> where T: Ord + Clone {
     pub fn enclose_all<I>(intervals: I) -> Self
         where I: Iterator<Item=Self>
     {
-        intervals.fold(Self::Full, |acc, i| acc.enclose(&i))
+        intervals.fold(Self::Empty, |acc, i| acc.enclose(&i))
     }
 
     /// Returns the intersection of all of the given intervals.
@@ -460,42 +559,7 @@ impl<T> FromStr for RawInterval<T> where T: Ord + FromStr {
     type Err = IntervalParseError<T::Err>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use RawInterval::*;
-        // Parse empty interval.
-        if s.starts_with("Ø") { return Ok(Empty); }
-        // Parse point interval.
-        if let Ok(p) = T::from_str(s) { return Ok(Point(p)); }
-
-        let (x, y) = s.split_once(',')
-            .ok_or(IntervalParseError::InvalidInterval)?;
-
-        let lb = if x.starts_with("(-∞") { 
-            Bound::Infinite
-        } else if let Some(res) = x.strip_prefix('(') {
-            Bound::Exclude(T::from_str(res)
-                .map_err(|e| IntervalParseError::InvalidValue(e))?)
-        } else if let Some(res) = x.strip_prefix('[') {
-            Bound::Include(T::from_str(res)
-                .map_err(|e| IntervalParseError::InvalidValue(e))?)
-        } else {
-            return Err(IntervalParseError::InvalidInterval);
-        };
-
-        let ub = if y.ends_with("∞)") { 
-            Bound::Infinite
-        } else if y.ends_with(')') {
-            let end = y.len() - 1;
-            Bound::Exclude(T::from_str(&y[..end])
-                .map_err(|e| IntervalParseError::InvalidValue(e))?)
-        } else if y.ends_with(']') {
-            let end = y.len() - 1;
-            Bound::Include(T::from_str(&y[..end])
-                .map_err(|e| IntervalParseError::InvalidValue(e))?)
-        } else {
-            return Err(IntervalParseError::InvalidInterval);
-        };
-
-        Ok(Self::new(lb, ub))
+        Self::parse_with(s, T::from_str)
     }
 }
 
@@ -11,21 +11,59 @@
 //!
 ////////////////////////////////////////////////////////////////////////////////
 
+// Public modules.
+pub mod capped;
+pub mod map_ops;
+
 // Internal library imports.
 use crate::bound::Bound;
+use crate::cidr::Ipv4Cidr;
+use crate::flat::FlatPoint;
 use crate::interval::Interval;
+use crate::interval::algorithms::Origin;
+use crate::interval::algorithms::pairwise_intersections;
+use crate::interval::algorithms::union_sorted_streams;
+use crate::measure::CheckedAdd;
+use crate::measure::Measurable;
+use crate::selection::capped::CappedSelection;
+use crate::selection::capped::FragmentPolicy;
+use crate::measure::Ratio;
+use crate::normalize::Align;
 use crate::normalize::Finite;
 use crate::normalize::Normalize;
+use crate::normalize::Reflect;
+use crate::ops::SelectionOp;
+use crate::prefix::selection_prefix_ranges;
 use crate::raw_interval::RawInterval;
+use crate::sorted_iter::AssumeSorted;
+use crate::sorted_iter::SortedDisjointIter;
+use crate::tine::Tine;
 use crate::tine_tree::TineTree;
 
 // External library imports.
+use few::Few;
 #[cfg(feature="serde")] use serde::Deserialize;
 #[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="schemars")] use schemars::JsonSchema;
+#[cfg(feature="schemars")] use schemars::Schema;
+#[cfg(feature="schemars")] use schemars::SchemaGenerator;
+#[cfg(feature="schemars")] use schemars::json_schema;
+#[cfg(feature="rand")] use rand::Rng;
+#[cfg(feature="rand")] use rand::distributions::uniform::SampleUniform;
 
 // Standard library imports.
+#[cfg(feature="schemars")] use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+use std::ops::Neg;
+use std::hash::Hasher;
 use std::iter::FromIterator;
 use std::iter::FusedIterator;
+use std::net::Ipv4Addr;
+use std::num::TryFromIntError;
 
 
 
@@ -33,13 +71,17 @@ use std::iter::FusedIterator;
 // Selection<T>
 ////////////////////////////////////////////////////////////////////////////////
 /// A possibly noncontiguous collection of `Interval`s of the type `T`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Equality and hashing are defined over the selected points, not the internal
+/// representation: two `Selection`s containing the same points will always
+/// compare and hash equally, regardless of the operations used to build them.
+#[derive(Debug, Clone)]
 #[repr(transparent)]
 #[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature="serde", serde(transparent))]
 #[cfg_attr(feature="serde", 
     serde(bound="for<'a> T: Ord + Serialize + Deserialize<'a> + Clone + 'a"))]
-pub struct Selection<T>(TineTree<T>);
+pub struct Selection<T>(pub (crate) TineTree<T>);
 
 impl<T> Default for Selection<T> 
     where
@@ -61,6 +103,29 @@ impl<T> Selection<T>
 {
     // Constructors
     ////////////////////////////////////////////////////////////////////////////
+
+    /// The empty `Selection`. Equal to [`Selection::new`], but usable in
+    /// constant expressions.
+    ///
+    /// [`Selection::new`]: #method.new
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// const NONE: Selection<i32> = Selection::EMPTY;
+    ///
+    /// assert!(NONE.is_empty());
+    /// assert_eq!(NONE, Selection::from(Interval::empty()));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub const EMPTY: Self = Self(TineTree::new());
     
     /// Constructs a new empty `Selection`.
     ///
@@ -111,6 +176,115 @@ impl<T> Selection<T>
         Interval::full().into()
     }
 
+    /// Constructs a new empty `Selection` with space for `intervals` disjoint
+    /// `Interval`s, so that it can be filled without reallocating.
+    ///
+    /// The `Selection` is stored contiguously until it holds more than
+    /// `intervals` or 16 `Interval`s, whichever is larger.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::with_capacity(4);
+    /// assert!(sel.capacity() >= 4);
+    ///
+    /// sel.union_in_place(Interval::closed(0, 2));
+    /// sel.union_in_place(Interval::closed(5, 7));
+    /// assert!(sel.capacity() >= 4);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(intervals: usize) -> Self {
+        Self(TineTree::with_capacity(intervals.saturating_mul(2)))
+    }
+
+    /// Constructs a `Selection` from disjoint `Interval`s in ascending order,
+    /// in linear time. The `Interval`s may be adjacent or empty.
+    ///
+    /// The order is guaranteed by the [`SortedDisjointIter`] bound, which is
+    /// implemented by the `Interval` iterators of this crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, 14),
+    ///     Interval::closed(20, 24),
+    /// ]);
+    ///
+    /// let tail = Selection::from_sorted_disjoint(sel.interval_iter().skip(1));
+    /// assert_eq!(tail, Selection::from([
+    ///     Interval::closed(10, 14),
+    ///     Interval::closed(20, 24),
+    /// ]));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SortedDisjointIter`]: ../sorted_iter/trait.SortedDisjointIter.html
+    #[must_use]
+    pub fn from_sorted_disjoint<I>(intervals: I) -> Self
+        where
+            I: IntoIterator<Item=Interval<T>>,
+            I::IntoIter: SortedDisjointIter<T>,
+    {
+        Self(TineTree::from_sorted(intervals
+            .into_iter()
+            .map(|interval| interval.0.denormalized())))
+    }
+
+    /// Constructs an empty [`CappedSelection`] of at most `max_fragments`
+    /// disjoint `Interval`s, handling edits which would exceed it by the
+    /// given [`FragmentPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::capped::FragmentPolicy;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel = Selection::<i32>::with_max_fragments(
+    ///     1,
+    ///     FragmentPolicy::MergeSmallestGap);
+    /// sel.union_in_place(Interval::closed(0, 4))?;
+    /// sel.union_in_place(Interval::closed(8, 9))?;
+    ///
+    /// assert_eq!(sel.selection(), &Selection::from(Interval::closed(0, 9)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CappedSelection`]: capped/struct.CappedSelection.html
+    /// [`FragmentPolicy`]: capped/enum.FragmentPolicy.html
+    #[must_use]
+    pub fn with_max_fragments(max_fragments: usize, policy: FragmentPolicy)
+        -> CappedSelection<T>
+        where
+            T: Finite + Measurable,
+            T::Length: PartialOrd,
+    {
+        CappedSelection::new(max_fragments, policy)
+    }
+
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
 
@@ -292,6 +466,121 @@ impl<T> Selection<T>
         self.0.upper_bound().and_then(|b| b.as_ref().cloned())
     }
 
+    /// Returns `true` if the `Selection` contains a point less than the given
+    /// point. The lower bound of the `Selection` is compared in place,
+    /// without cloning it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// assert_eq!(sel.starts_before(&-1), true);
+    /// assert_eq!(sel.starts_before(&-2), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn starts_before(&self, point: &T) -> bool {
+        // The points below the given point are normalized so that the lower
+        // bound can be compared in its denormalized form.
+        let below = match Tine::from_raw_interval(
+            RawInterval::UpTo(point.clone()).normalized())
+        {
+            Few::Zero      => return false,
+            Few::One(p)    => p.into_inner(),
+            Few::Two(_, u) => u.into_inner(),
+        };
+        self.0.lower_bound_ref()
+            .is_some_and(|lower| !below.precedes_lower(lower))
+    }
+
+    /// Returns `true` if the `Selection` is not empty and contains no point
+    /// less than the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// assert_eq!(sel.starts_at_or_after(&-2), true);
+    /// assert_eq!(sel.starts_at_or_after(&-1), false);
+    /// assert_eq!(Selection::<i32>::new().starts_at_or_after(&0), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn starts_at_or_after(&self, point: &T) -> bool {
+        !self.is_empty() && !self.starts_before(point)
+    }
+
+    /// Returns `true` if the `Selection` contains a point greater than the
+    /// given point. The upper bound of the `Selection` is compared in place,
+    /// without cloning it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// assert_eq!(sel.ends_after(&3), true);
+    /// assert_eq!(sel.ends_after(&4), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn ends_after(&self, point: &T) -> bool {
+        // The points above the given point are normalized so that the upper
+        // bound can be compared in its denormalized form.
+        let above = match Tine::from_raw_interval(
+            RawInterval::UpFrom(point.clone()).normalized())
+        {
+            Few::Zero      => return false,
+            Few::One(p)    => p.into_inner(),
+            Few::Two(l, _) => l.into_inner(),
+        };
+        self.0.upper_bound_ref()
+            .is_some_and(|upper| !upper.precedes_lower(&above))
+    }
+
+    /// Returns `true` if the `Selection` is not empty and contains no point
+    /// greater than the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// assert_eq!(sel.ends_at_or_before(&4), true);
+    /// assert_eq!(sel.ends_at_or_before(&3), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn ends_at_or_before(&self, point: &T) -> bool {
+        !self.is_empty() && !self.ends_after(point)
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
     ////////////////////////////////////////////////////////////////////////////
@@ -321,6 +610,61 @@ impl<T> Selection<T>
         self.0.is_empty()
     }
 
+    /// Returns the number of disjoint `Interval`s the `Selection` is
+    /// guaranteed to hold without allocating.
+    ///
+    /// A `Selection` which has outgrown its contiguous storage allocates as
+    /// it grows, so its capacity is then at most the number of `Interval`s it
+    /// already holds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::with_capacity(8);
+    /// assert!(sel.capacity() >= 8);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity() / 2
+    }
+
+    /// Returns the number of bounds stored by the `Selection`. This measures
+    /// the memory used by the `Selection`, and is computed without iterating.
+    /// It is at least the number of disjoint `Interval`s, and at most twice
+    /// it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// assert_eq!(sel.bound_count(), 2);
+    ///
+    /// sel.minus_in_place(Interval::point(2));
+    /// assert!(sel.bound_count() >= 3);
+    /// assert!(sel.bound_count() <= 4);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bound_count(&self) -> usize {
+        self.0.len()
+    }
+
     /// Returns `true` if the interval contains all points.
     ///
     /// # Example
@@ -473,90 +817,144 @@ impl<T> Selection<T>
         self.0.contains(point)
     }
 
-    // Set comparisons
-    ////////////////////////////////////////////////////////////////////////////
-    
-    /// Returns `true` if the `Selection` overlaps the given `Selection`.
+    /// Returns the nearest finite [`Bound`] of the `Selection` greater than
+    /// the given point, along with whether it opens or closes an `Interval`
+    /// of the `Selection`. Returns `None` if there is no such bound.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::BoundaryKind;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 15));
-    /// assert_eq!(a.intersects(&b), true);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(20, 30));
     ///
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(8, 12));
-    /// assert_eq!(a.intersects(&b), false);
+    /// assert_eq!(sel.next_boundary_after(&5),
+    ///     Some((Include(10), BoundaryKind::Upper)));
+    /// assert_eq!(sel.next_boundary_after(&10),
+    ///     Some((Include(20), BoundaryKind::Lower)));
+    /// assert_eq!(sel.next_boundary_after(&30), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn intersects(&self, other: &Self) -> bool {
-        // TODO: Make generic?
-        !self.0.intersect(&other.0).is_empty()
+    pub fn next_boundary_after(&self, point: &T)
+        -> Option<(Bound<T>, BoundaryKind)>
+    {
+        let query = Interval::point(point.clone()).0.denormalized();
+        self.0.interval_iter_from(&query)
+            .map(Interval::from)
+            .find_map(|interval| [
+                    (interval.lower_bound(), BoundaryKind::Lower),
+                    (interval.upper_bound(), BoundaryKind::Upper),
+                ]
+                .iter()
+                .cloned()
+                .find_map(|(bound, kind)| bound
+                    .filter(|b| b.as_ref().is_some_and(|p| p > point))
+                    .map(|b| (b, kind))))
     }
 
-    // Symmetric set operations
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns the `Selection` containing all points in not contained in the
-    /// `Selection`.
+    /// Returns the nearest finite [`Bound`] of the `Selection` less than the
+    /// given point, along with whether it opens or closes an `Interval` of
+    /// the `Selection`. Returns `None` if there is no such bound.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::BoundaryKind;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// 
-    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), 
-    ///     vec![
-    ///         Interval::unbounded_to(-3i32),
-    ///         Interval::unbounded_from(5i32)
-    ///     ]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(20, 30));
+    ///
+    /// assert_eq!(sel.prev_boundary_before(&25),
+    ///     Some((Include(20), BoundaryKind::Lower)));
+    /// assert_eq!(sel.prev_boundary_before(&20),
+    ///     Some((Include(10), BoundaryKind::Upper)));
+    /// assert_eq!(sel.prev_boundary_before(&0), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[must_use]
+    pub fn prev_boundary_before(&self, point: &T)
+        -> Option<(Bound<T>, BoundaryKind)>
+    {
+        let query = Interval::point(point.clone()).0.denormalized();
+        self.0.interval_iter_to(&query)
+            .rev()
+            .map(Interval::from)
+            .find_map(|interval| [
+                    (interval.upper_bound(), BoundaryKind::Upper),
+                    (interval.lower_bound(), BoundaryKind::Lower),
+                ]
+                .iter()
+                .cloned()
+                .find_map(|(bound, kind)| bound
+                    .filter(|b| b.as_ref().is_some_and(|p| p < point))
+                    .map(|b| (b, kind))))
+    }
+
+    /// Returns the total size of the `Interval`s in the `Selection`, or
+    /// `None` if any of them is infinite or the total is not representable.
+    /// The size of each `Interval` is the [`Measurable`] span between its
+    /// bounds, and the `measure` of an empty `Selection` is the default
+    /// `Length`.
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// [`Measurable`]: ../measure/trait.Measurable.html
     ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use std::i32;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// 
-    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), vec![
-    ///     Interval::closed(i32::MIN, -4i32),
-    ///     Interval::closed(6i32, i32::MAX),
-    /// ]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(10, 12));
+    /// assert_eq!(sel.measure(), Some(7));
+    ///
+    /// assert_eq!(Selection::<i32>::empty().measure(), Some(0));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn complement(&self) -> Self {
-        Self(self.0.complement())
+    pub fn measure(&self) -> Option<T::Length>
+        where
+            T: Measurable,
+            T::Length: CheckedAdd + Default,
+    {
+        self.interval_iter()
+            .try_fold(T::Length::default(), |total, interval| interval
+                .size()
+                .and_then(|size| total.checked_add(&size)))
     }
 
-    /// Returns the `Selection` containing all points in both the given
-    /// `Selection`s.
+    /// Returns the Jaccard index of the `Selection` and another, which is the
+    /// total [`extent`] of their intersection divided by the total `extent` of
+    /// their union. For [`Finite`] types, this counts the points of each,
+    /// so that equal single points have an index of one. Returns `None` if
+    /// either total is unavailable or the union is empty.
+    ///
+    /// [`extent`]: ../measure/trait.Measurable.html#method.extent
+    /// [`Finite`]: ../normalize/trait.Finite.html
     ///
     /// # Example
     ///
@@ -566,40 +964,1762 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(4, 7)]);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(0, 29));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(20, 39));
+    ///
+    /// assert_eq!(a.jaccard(&b), Some(0.25));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[must_use]
+    pub fn jaccard(&self, other: &Self) -> Option<f64>
+        where
+            T: Measurable,
+            T::Length: CheckedAdd + Default + PartialEq + Ratio,
+    {
+        let union = Self::total_extent(self.union(other).interval_iter())?;
+        let intersection = Self::total_extent(
+            self.intersect(other).interval_iter())?;
+        (union != T::Length::default()).then(|| intersection.ratio(&union))
+    }
+
+    /// Returns the fraction of the `Selection` which is overlapped by another,
+    /// which is the total [`extent`] of their intersection divided by the
+    /// total `extent` of the `Selection`. For [`Finite`] types, this counts
+    /// the points of each. Returns `None` if either total is unavailable or
+    /// the `Selection` is empty.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
+    /// [`extent`]: ../measure/trait.Measurable.html#method.extent
     /// [`Finite`]: ../normalize/trait.Finite.html
     ///
+    /// # Example
+    ///
     /// ```rust
     /// # use std::error::Error;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(5, 6)]);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(0, 29));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(20, 39));
+    ///
+    /// assert_eq!(a.overlap_fraction(&b), Some(1.0 / 3.0));
+    /// assert_eq!(b.overlap_fraction(&a), Some(0.5));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn intersect(&self, other: &Self) -> Self {
-        Self(self.0.intersect(&other.0))
+    pub fn overlap_fraction(&self, other: &Self) -> Option<f64>
+        where
+            T: Measurable,
+            T::Length: CheckedAdd + Default + PartialEq + Ratio,
+    {
+        let whole = Self::total_extent(self.interval_iter())?;
+        let intersection = Self::total_extent(
+            self.intersect(other).interval_iter())?;
+        (whole != T::Length::default()).then(|| intersection.ratio(&whole))
     }
 
-    /// Returns the `Selection` containing all points in either of the given
-    /// `Selection`s.
+    /// Returns the total [`extent`] of the parts of the given `Interval` which
+    /// are covered by the `Selection`, or `None` if any of them is infinite or
+    /// the total is not representable. For [`Finite`] types, this counts the
+    /// covered points. Only the `Interval`s of the `Selection` which may
+    /// intersect the given `Interval` are visited.
+    ///
+    /// [`extent`]: ../measure/trait.Measurable.html#method.extent
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(30, 50));
+    ///
+    /// assert_eq!(sel.covered_measure_in(&Interval::closed(5, 40)), Some(17));
+    /// assert_eq!(sel.covered_measure_in(&Interval::closed(10, 20)), Some(1));
+    /// assert_eq!(sel.covered_measure_in(&Interval::closed(12, 20)), Some(0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn covered_measure_in(&self, interval: &Interval<T>)
+        -> Option<T::Length>
+        where
+            T: Measurable,
+            T::Length: CheckedAdd + Default,
+    {
+        Self::total_extent(self.intersections_with(interval))
+    }
+
+    /// Returns the fraction of the given `Interval` which is covered by the
+    /// `Selection`, which is the total [`extent`] of their intersection
+    /// divided by the `extent` of the `Interval`. For [`Finite`] types, this
+    /// counts the points of each. Returns `None` if either total is
+    /// unavailable or the `Interval` is empty.
+    ///
+    /// [`extent`]: ../measure/trait.Measurable.html#method.extent
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.union_in_place(Interval::closed(30, 49));
+    ///
+    /// assert_eq!(sel.coverage_of(&Interval::closed(0, 39)), Some(0.5));
+    /// assert_eq!(sel.coverage_of(&Interval::point(5)), Some(1.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn coverage_of(&self, interval: &Interval<T>) -> Option<f64>
+        where
+            T: Measurable,
+            T::Length: CheckedAdd + Default + PartialEq + Ratio,
+    {
+        let whole = Self::total_extent(std::iter::once(interval.clone()))?;
+        let covered = Self::total_extent(self.intersections_with(interval))?;
+        (whole != T::Length::default()).then(|| covered.ratio(&whole))
+    }
+
+    /// Returns an iterator over the covered and uncovered parts of the given
+    /// domain, in order. Each part is paired with `true` if it is covered by
+    /// the `Selection`, and covered and uncovered parts alternate, together
+    /// tiling the domain exactly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(30, 50));
+    ///
+    /// assert_eq!(sel.spans(&Interval::closed(5, 40)).collect::<Vec<_>>(), [
+    ///     (Interval::closed(5, 10), true),
+    ///     (Interval::closed(11, 29), false),
+    ///     (Interval::closed(30, 40), true),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn spans(&self, domain: &Interval<T>)
+        -> impl Iterator<Item=(Interval<T>, bool)>
+    {
+        let mut spans: Vec<(Interval<T>, bool)> = Vec::new();
+        // The part of the domain following the last covered part.
+        let mut rest = domain.clone();
+        for interval in self.intersections_with(domain) {
+            let mut above = Interval::empty();
+            for part in interval.0.complement() {
+                let below = matches!(part.lower_bound(), Some(Bound::Infinite));
+                let part = Interval::from(part).intersect(&rest);
+                if !below {
+                    above = part;
+                } else if !part.is_empty() {
+                    spans.push((part, false));
+                }
+            }
+            // Adjacent covered parts are merged to preserve alternation.
+            match spans.last_mut() {
+                Some((last, true)) => *last = last.enclose(&interval),
+                _                  => spans.push((interval, true)),
+            }
+            rest = above;
+        }
+        if !rest.is_empty() {
+            spans.push((rest, false));
+        }
+        spans.into_iter()
+    }
+
+    /// Returns an iterator over the given points which are contained in the
+    /// `Selection`. The points must be in ascending order, so that they can
+    /// be checked against the `Interval`s of the `Selection` in a single pass
+    /// rather than by searching the `Selection` for each point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(30, 50));
+    ///
+    /// let points = sel.filter_points(vec![-3, 4, 10, 11, 25, 30, 70]);
+    /// assert_eq!(points.collect::<Vec<_>>(), [4, 10, 30]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn filter_points<'t, I>(&'t self, points: I)
+        -> impl Iterator<Item=T> + 't
+        where
+            I: IntoIterator<Item=T>,
+            I::IntoIter: 't,
+    {
+        self.classify_points(points)
+            .filter_map(|(point, contained)| contained.then_some(point))
+    }
+
+    /// Returns an iterator over the given points, each paired with `true` if
+    /// it is contained in the `Selection`. The points must be in ascending
+    /// order, as for [`filter_points`].
+    ///
+    /// [`filter_points`]: #method.filter_points
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    ///
+    /// let points = sel.classify_points(vec![-3, 4, 11]);
+    /// assert_eq!(points.collect::<Vec<_>>(),
+    ///     [(-3, false), (4, true), (11, false)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn classify_points<'t, I>(&'t self, points: I)
+        -> impl Iterator<Item=(T, bool)> + 't
+        where
+            I: IntoIterator<Item=T>,
+            I::IntoIter: 't,
+    {
+        let mut intervals = self.interval_iter().peekable();
+        points.into_iter().map(move |point| {
+            // Skip the `Interval`s which end before the point.
+            while intervals.peek().is_some_and(|i| i.0
+                .upper_point()
+                .is_some_and(|u| u < &point || (u == &point && !i.contains(u))))
+            {
+                let _ = intervals.next();
+            }
+            let contained = intervals.peek().is_some_and(|i| i.contains(&point));
+            (point, contained)
+        })
+    }
+
+    /// Returns whether each of the given points is contained in the
+    /// `Selection`. The points must be in ascending order, so that they can
+    /// be checked against the bounds of the `Selection` in a single pass
+    /// without normalizing its `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(30, 50));
+    ///
+    /// assert_eq!(sel.contains_sorted(&[-3, 4, 10, 11, 30, 70]),
+    ///     [false, true, true, false, true, false]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn contains_sorted(&self, points: &[T]) -> Vec<bool> {
+        let mut spans = self.raw_spans().peekable();
+        points.iter().map(|point| {
+            // Skip the spans which end before the point.
+            while spans.peek().is_some_and(|(_, upper)| match upper {
+                Bound::Include(u) => *u < point,
+                Bound::Exclude(u) => *u <= point,
+                Bound::Infinite   => false,
+            }) {
+                let _ = spans.next();
+            }
+            spans.peek().is_some_and(|(lower, _)| match lower {
+                Bound::Include(l) => *l <= point,
+                Bound::Exclude(l) => *l < point,
+                Bound::Infinite   => true,
+            })
+        }).collect()
+    }
+
+    /// Returns an iterator over the entries of the given map whose keys are
+    /// in the `Selection`, in key order. A range query is run for each
+    /// `Interval` of the `Selection`, so entries between the `Interval`s are
+    /// never visited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use std::collections::BTreeMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let map: BTreeMap<i32, char> = (0..26).zip('a'..).collect();
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(2, 4));
+    /// sel.union_in_place(Interval::unbounded_from(23));
+    ///
+    /// assert_eq!(sel.range_queries(&map).map(|(_, c)| *c).collect::<String>(),
+    ///     "cdexyz");
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn range_queries<'m, V>(&'m self, map: &'m BTreeMap<T, V>)
+        -> impl Iterator<Item=(&'m T, &'m V)> + 'm
+    {
+        self.interval_iter()
+            .filter_map(|interval| interval.try_into_range_bounds())
+            .flat_map(move |bounds| map.range(bounds))
+    }
+
+    /// Returns an iterator over the nonempty intersections of the `Interval`s
+    /// of the `Selection` with the given `Interval`, visiting only those
+    /// which may intersect it.
+    fn intersections_with<'a>(&'a self, interval: &'a Interval<T>)
+        -> impl Iterator<Item=Interval<T>> + 'a
+    {
+        let end = interval.supremum();
+        self.0.interval_iter_from(&interval.0.clone().denormalized())
+            .map(Interval::from)
+            .take_while(move |i| match (i.infimum(), &end) {
+                (Some(start), Some(end)) => start <= *end,
+                _                        => true,
+            })
+            .map(move |i| i.intersect(interval))
+            .filter(|i| !i.is_empty())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Smoothing
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Selection` with every gap narrower than `epsilon` filled,
+    /// merging the `Interval`s on either side of it. The width of a gap is the
+    /// [`Measurable`] span between the bounds around it.
+    ///
+    /// [`Measurable`]: ../measure/trait.Measurable.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let active: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(12, 20),
+    ///     Interval::closed(30, 40),
+    /// ]);
+    ///
+    /// assert_eq!(active.coalesce_gaps_smaller_than(5), Selection::from([
+    ///     Interval::closed(0, 20),
+    ///     Interval::closed(30, 40),
+    /// ]));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn coalesce_gaps_smaller_than(&self, epsilon: T::Length) -> Self
+        where
+            T: Measurable,
+            T::Length: PartialOrd,
+    {
+        let gaps: Vec<_> = self
+            .interval_iter()
+            .zip(self.interval_iter().skip(1))
+            .filter_map(|(a, b)| Some((a.supremum()?, b.infimum()?)))
+            .filter(|(end, start)| T::span(end, start) < epsilon)
+            .collect();
+
+        let mut coalesced = self.clone();
+        for (end, start) in gaps {
+            coalesced.union_in_place(Interval::closed(end, start));
+        }
+        coalesced
+    }
+
+    /// Returns the `Selection` without the `Interval`s narrower than
+    /// `min_len`. The width of an `Interval` is its [`size`], and infinite
+    /// `Interval`s are always retained.
+    ///
+    /// Together with [`coalesce_gaps_smaller_than`], this can be used to
+    /// remove short runs and short gaps from an interval signal.
+    ///
+    /// [`size`]: ../interval/struct.Interval.html#method.size
+    /// [`coalesce_gaps_smaller_than`]: #method.coalesce_gaps_smaller_than
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let active: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(14, 15),
+    ///     Interval::closed(30, 40),
+    /// ]);
+    ///
+    /// assert_eq!(active.drop_intervals_shorter_than(5), Selection::from([
+    ///     Interval::closed(0, 10),
+    ///     Interval::closed(30, 40),
+    /// ]));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn drop_intervals_shorter_than(&self, min_len: T::Length) -> Self
+        where
+            T: Measurable,
+            T::Length: PartialOrd,
+    {
+        self.interval_iter()
+            .filter(|interval| interval
+                .size()
+                .is_none_or(|size| size >= min_len))
+            .collect()
+    }
+
+    /// Returns an iterator over consecutive windows of the given width
+    /// spanning the `domain`, paired with the total [`extent`] of the
+    /// `Selection` within each window. For [`Finite`] types, this counts the
+    /// selected points of each window.
+    ///
+    /// Each window includes its start and excludes the start of the next
+    /// window, and the last window is clipped to the `domain`, including when
+    /// its end is not representable by `T`. No windows are produced if the
+    /// `domain` is empty or unbounded, or if `window_width` is not greater
+    /// than its default value. Iteration stops early if the coverage of a
+    /// window is not representable by `Length`.
+    ///
+    /// [`extent`]: ../measure/trait.Measurable.html#method.extent
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let busy: Selection<u32> = Selection::from([
+    ///     Interval::closed(0, 30),
+    ///     Interval::closed(90, 180),
+    /// ]);
+    /// let windows: Vec<_> = busy
+    ///     .coverage_per_window(100, &Interval::closed(0, 250))
+    ///     .collect();
+    ///
+    /// assert_eq!(windows, [
+    ///     (Interval::closed(0, 99), 41),
+    ///     (Interval::closed(100, 199), 81),
+    ///     (Interval::closed(200, 250), 0),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn coverage_per_window(&self, window_width: T, domain: &Interval<T>)
+        -> impl Iterator<Item=(Interval<T>, T::Length)>
+        where
+            T: Measurable + CheckedAdd + Default,
+            T::Length: CheckedAdd + Default,
+    {
+        let intervals: Vec<Interval<T>> = self.interval_iter().collect();
+        let domain = domain.clone();
+        let end = domain.supremum();
+        let mut start = domain.infimum()
+            .filter(|_| window_width > T::default());
+        // The index of the first `Interval` which may overlap the window.
+        let mut first = 0;
+
+        std::iter::from_fn(move || {
+            let (lower, end) = (start.take()?, end.as_ref()?);
+            if lower > *end { return None; }
+            // A window whose end is not representable is the last window.
+            let upper = lower.checked_add(&window_width);
+            let window = match &upper {
+                Some(upper) => Interval::right_open(lower, upper.clone()),
+                None        => Interval::closed(lower, end.clone()),
+            }.intersect(&domain);
+
+            let window_lower = window.infimum();
+            while intervals.get(first).is_some_and(|i| i
+                .supremum()
+                .is_some_and(|sup| Some(sup) < window_lower))
+            {
+                first += 1;
+            }
+
+            let covered = Self::total_extent(intervals[first..]
+                .iter()
+                .take_while(|i| i.infimum().is_none_or(|inf| upper
+                    .as_ref()
+                    .is_none_or(|upper| inf < *upper)))
+                .map(|i| i.intersect(&window))
+                .filter(|i| !i.is_empty()))?;
+            start = upper;
+            Some((window, covered))
+        })
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Canonicalization
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Rewrites the internal representation of the `Selection` into its
+    /// canonical form.
+    ///
+    /// Set operations may leave equivalent `Selection`s with different internal
+    /// representations. This does not affect equality or hashing, which are
+    /// always computed over the canonical form, but it may be used to make
+    /// subsequent comparisons cheaper.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// a.minus_in_place(Interval::closed(4, 6));
+    /// a.union_in_place(Interval::closed(4, 6));
+    /// a.canonicalize();
+    ///
+    /// assert_eq!(a, Selection::from(Interval::closed(0, 10)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.0 = self.canonical_intervals()
+            .into_iter()
+            .map(Normalize::denormalized)
+            .collect();
+    }
+
+    /// Feeds the canonical form of the `Selection` into the given [`Hasher`].
+    ///
+    /// The result depends only on the points in the `Selection`, so it is
+    /// suitable for keying caches by `Selection`. This is the same hash
+    /// produced by the `Hash` implementation.
+    ///
+    /// [`Hasher`]: https://doc.rust-lang.org/std/hash/trait.Hasher.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// # use std::hash::Hasher;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// a.minus_in_place(Interval::closed(6, 10));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    ///
+    /// let mut ha = DefaultHasher::new();
+    /// a.stable_hash(&mut ha);
+    /// let mut hb = DefaultHasher::new();
+    /// b.stable_hash(&mut hb);
+    ///
+    /// assert_eq!(ha.finish(), hb.finish());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn stable_hash<H>(&self, state: &mut H) where T: Hash, H: Hasher {
+        self.canonical_intervals().hash(state);
+    }
+
+    /// Returns the total [`Measurable::extent`] of the given `Interval`s, or
+    /// `None` if any of them is infinite or the total is not representable.
+    fn total_extent<I>(intervals: I) -> Option<T::Length>
+        where
+            I: Iterator<Item=Interval<T>>,
+            T: Measurable,
+            T::Length: CheckedAdd + Default,
+    {
+        intervals
+            .map(|interval| interval.0)
+            .try_fold(T::Length::default(), |total, interval| {
+                let lower = interval.lower_point()?;
+                let upper = interval.upper_point()?;
+                total.checked_add(&T::extent(lower, upper)?)
+            })
+    }
+
+    /// Removes any intervals which contain no points after normalization.
+    /// These can be left behind when intersecting denormalized intervals
+    /// whose bounds overlap without containing any points.
+    fn remove_empty_intervals(&mut self) {
+        if self.0.interval_iter().any(|i| i.normalized().is_empty()) {
+            *self = self.interval_iter().filter(|i| !i.is_empty()).collect();
+        }
+    }
+
+    /// Returns the normalized intervals of the `Selection` with any intervals
+    /// that are adjacent after normalization merged.
+    fn canonical_intervals(&self) -> Vec<RawInterval<T>> {
+        let mut canonical: Vec<RawInterval<T>> = Vec::new();
+        let normalized = self.0
+            .interval_iter()
+            .map(Normalize::normalized)
+            .filter(|i| !i.is_empty());
+
+        for interval in normalized {
+            if let Some(last) = canonical.last_mut() {
+                let a = last.clone().denormalized();
+                let b = interval.clone().denormalized();
+                if a.intersects(&b) || a.is_adjacent_to(&b) {
+                    *last = a.enclose(&b).normalized();
+                    continue;
+                }
+            }
+            canonical.push(interval);
+        }
+        canonical
+    }
+
+    // Set comparisons
+    ////////////////////////////////////////////////////////////////////////////
+    
+    /// Returns `true` if the `Selection` overlaps the given `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 15));
+    /// assert_eq!(a.intersects(&b), true);
+    ///
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(8, 12));
+    /// assert_eq!(a.intersects(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        // TODO: Make generic?
+        !self.0.intersect(&other.0).is_empty()
+    }
+
+    /// Returns `true` if every point of the `Selection` is in the given
+    /// `Selection`. An empty `Selection` is within every `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(6, 9),
+    /// ]);
+    /// let b: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// assert_eq!(a.within(&b), true);
+    /// assert_eq!(b.within(&a), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn within(&self, other: &Self) -> bool {
+        // Compare normalized intervals, so that the result does not depend on
+        // how either `Selection` is stored. Each interval must lie within the
+        // first interval of `other` which does not end before it.
+        let mut others = other.interval_iter().peekable();
+        self.interval_iter().all(|interval| {
+            while others.next_if(|o| o.entirely_before(&interval)).is_some() {}
+            others.peek().is_some_and(|o| interval.within(o))
+        })
+    }
+
+    /// Returns `true` if every point of the given `Selection` is in the
+    /// `Selection`, and the `Selection`s are not equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// let b: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(5, 9),
+    /// ]);
+    /// assert_eq!(a.properly_contains(&b), false);
+    ///
+    /// let b: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// assert_eq!(a.properly_contains(&b), true);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn properly_contains(&self, other: &Self) -> bool {
+        other.within(self) && self != other
+    }
+
+    /// Returns `true` if every point of the `Selection` is less than every
+    /// point of the given `Selection`. This is vacuously `true` if either
+    /// `Selection` is empty. Only the extreme bounds of the `Selection`s are
+    /// compared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(8, 9),
+    /// ]);
+    /// let b: Selection<i32> = Selection::from(Interval::closed(10, 20));
+    /// assert_eq!(a.entirely_before(&b), true);
+    ///
+    /// let b: Selection<i32> = Selection::from(Interval::closed(5, 7));
+    /// assert_eq!(a.entirely_before(&b), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn entirely_before(&self, other: &Self) -> bool {
+        match (self.upper_bound(), other.lower_bound()) {
+            (Some(upper), Some(lower)) => upper.precedes_lower(&lower),
+            _                          => true,
+        }
+    }
+
+    /// Returns `true` if every point of the `Selection` is greater than every
+    /// point of the given `Selection`. This is vacuously `true` if either
+    /// `Selection` is empty. Only the extreme bounds of the `Selection`s are
+    /// compared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(10, 20));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// assert_eq!(a.entirely_after(&b), true);
+    /// assert_eq!(b.entirely_after(&a), false);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn entirely_after(&self, other: &Self) -> bool {
+        other.entirely_before(self)
+    }
+
+    /// Returns `true` if the `Selection` is equal to another up to the given
+    /// tolerance.
+    ///
+    /// Gaps within either `Selection` no wider than `tolerance` are first
+    /// closed, merging the `Interval`s around them. The `Selection`s are then
+    /// equal within the tolerance if they have the same number of `Interval`s,
+    /// and the corresponding bounds of each pair of `Interval`s are either both
+    /// infinite or no more than `tolerance` apart. Whether bounds are open or
+    /// closed is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let measured: Selection<i32> = Selection::from([
+    ///     Interval::closed(98, 153),
+    ///     Interval::closed(155, 301),
+    /// ]);
+    /// let expected = Selection::from(Interval::closed(100, 300));
+    ///
+    /// assert!(measured.eq_within(&expected, 2));
+    /// assert!(!measured.eq_within(&expected, 1));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn eq_within(&self, other: &Self, tolerance: T::Length) -> bool
+        where
+            T: Measurable,
+            T::Length: PartialOrd,
+    {
+        let within = |a: &Option<T>, b: &Option<T>| match (a, b) {
+            (Some(a), Some(b)) if a <= b => T::span(a, b) <= tolerance,
+            (Some(a), Some(b))           => T::span(b, a) <= tolerance,
+            (None,    None)              => true,
+            _                            => false,
+        };
+
+        let a = self.bounds_merged_within(&tolerance);
+        let b = other.bounds_merged_within(&tolerance);
+        a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)|
+            within(&a.0, &b.0) && within(&a.1, &b.1))
+    }
+
+    /// Returns the infimum and supremum of each `Interval` in the `Selection`
+    /// after merging any `Interval`s separated by gaps no wider than
+    /// `tolerance`. Infinite bounds are `None`.
+    fn bounds_merged_within(&self, tolerance: &T::Length)
+        -> Vec<(Option<T>, Option<T>)>
+        where
+            T: Measurable,
+            T::Length: PartialOrd,
+    {
+        let mut bounds: Vec<(Option<T>, Option<T>)> = Vec::new();
+        for interval in self.interval_iter() {
+            let (lower, upper) = (interval.infimum(), interval.supremum());
+            if let Some((_, last_upper)) = bounds.last_mut() {
+                let narrow = match (last_upper.as_ref(), lower.as_ref()) {
+                    (Some(end), Some(start)) =>
+                        T::span(end, start) <= *tolerance,
+                    _ => false,
+                };
+                if narrow {
+                    *last_upper = upper;
+                    continue;
+                }
+            }
+            bounds.push((lower, upper));
+        }
+        bounds
+    }
+
+    // Symmetric set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Selection` containing all points in not contained in the
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// 
+    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), 
+    ///     vec![
+    ///         Interval::unbounded_to(-3i32),
+    ///         Interval::unbounded_from(5i32)
+    ///     ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::i32;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// 
+    /// assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), vec![
+    ///     Interval::closed(i32::MIN, -4i32),
+    ///     Interval::closed(6i32, i32::MAX),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn complement(&self) -> Self {
+        Self(self.0.complement())
+    }
+
+    /// Returns the `Selection` containing all points in both the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(4, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(5, 6)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut intersection = Self(self.0.intersect(&other.0));
+        intersection.remove_empty_intervals();
+        intersection
+    }
+
+    /// Returns the `Selection` containing all points in either of the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-3, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-2, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0))
+    }
+
+    /// Returns the `Selection` containing all points in any of the given
+    /// `Selection`s.
+    ///
+    /// The `Interval`s of each `Selection` are already sorted, so they are
+    /// combined by a single k-way merge rather than by successive unions.
+    /// This is much faster when there are many `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let days: Vec<Selection<i32>> = (0..3)
+    ///     .map(|day| Interval::closed(day * 24 + 9, day * 24 + 17).into())
+    ///     .collect();
+    /// let merged = Selection::union_from_sorted_selections(&days);
+    ///
+    /// assert_eq!(merged.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::closed(9, 17),
+    ///     Interval::closed(33, 41),
+    ///     Interval::closed(57, 65),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn union_from_sorted_selections(selections: &[Self]) -> Self {
+        Self::from_sorted_disjoint(union_sorted_streams(selections
+            .iter()
+            .map(Self::interval_iter)))
+    }
+
+    /// Returns the `Selection` containing all points in the `Selection` which
+    /// are not in the given `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.minus(&b).interval_iter().collect::<Vec<_>>(),
+    ///     vec![Interval::right_open(-3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn minus(&self, other: &Self) -> Self {
+        Self(self.0.minus(&other.0))
+    }
+
+    /// Returns the `Selection`s containing the points in the `Selection` which
+    /// are inside and outside of the given window `Interval`, respectively.
+    /// This is equivalent to, but cheaper than, computing the intersection
+    /// and difference separately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.union_in_place(Interval::closed(10, 13));
+    /// let (inside, outside) = sel.partition(&Interval::closed(5, 11));
+    ///
+    /// assert_eq!(inside.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(5, 7), Interval::closed(10, 11)]);
+    /// assert_eq!(outside.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 4), Interval::closed(12, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn partition(&self, window: &Interval<T>) -> (Self, Self) {
+        let mut inside = Self::new();
+        let mut outside = Self::new();
+        for interval in self.interval_iter() {
+            let (below, within, above) = interval.split_by(window);
+            inside.union_in_place(within);
+            outside.union_in_place(below);
+            outside.union_in_place(above);
+        }
+        (inside, outside)
+    }
+
+    /// Returns the common refinement of the `Selection` and the given
+    /// `Selection`: the maximal `Interval`s of points in either `Selection`
+    /// which lie entirely inside or outside of each, in order. Each `Interval`
+    /// is tagged with whether it is in the `Selection` and in the given
+    /// `Selection`, respectively.
+    ///
+    /// Points outside of both `Selection`s are omitted, so the `Interval`s do
+    /// not partition the whole domain. The `Interval`s are found in a single
+    /// pass over both `Selection`s with [`pairwise_intersections`].
+    ///
+    /// [`pairwise_intersections`]: ../interval/algorithms/fn.pairwise_intersections.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let b: Selection<i32> = Selection::from([
+    ///     Interval::closed(5, 15),
+    ///     Interval::closed(20, 25),
+    /// ]);
+    ///
+    /// assert_eq!(a.common_refinement(&b), [
+    ///     (Interval::closed(0, 4), true, false),
+    ///     (Interval::closed(5, 10), true, true),
+    ///     (Interval::closed(11, 15), false, true),
+    ///     (Interval::closed(20, 25), false, true),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn common_refinement(&self, other: &Self)
+        -> Vec<(Interval<T>, bool, bool)>
+    {
+        pairwise_intersections(self.interval_iter(), other.interval_iter())
+            .map(|(interval, origin)| match origin {
+                Origin::A    => (interval, true, false),
+                Origin::B    => (interval, false, true),
+                Origin::Both => (interval, true, true),
+            })
+            .collect()
+    }
+
+    /// Resolves a stack of overlapping layers, returning the visible points of
+    /// each layer in the order given.
+    ///
+    /// Each point is visible in the layer with the highest priority which
+    /// contains it. Of layers with equal priority, the earlier layer is on top.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let work = Selection::from(Interval::closed(9, 17));
+    /// let lunch = Selection::from(Interval::closed(12, 13));
+    /// let visible = Selection::overlay([(0, work), (1, lunch)]);
+    ///
+    /// assert_eq!(visible[0].1.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(9, 11), Interval::closed(14, 17)]);
+    /// assert_eq!(visible[1].1.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(12, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlay<P, I>(layers: I) -> Vec<(P, Self)>
+        where
+            P: Ord,
+            I: IntoIterator<Item=(P, Self)>,
+    {
+        let mut layers: Vec<(P, Self)> = layers.into_iter().collect();
+        let mut order: Vec<usize> = (0..layers.len()).collect();
+        order.sort_by(|&a, &b| layers[b].0.cmp(&layers[a].0));
+
+        let mut covered = Self::new();
+        for idx in order {
+            let visible = layers[idx].1.minus(&covered);
+            covered = covered.union(&layers[idx].1);
+            layers[idx].1 = visible;
+        }
+        layers
+    }
+
+    /// Returns the smallest `Interval` containing all of the points in the 
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn enclose(&self) -> Interval<T> {
+        Interval(self.0.enclose().normalized())
+    }
+
+    /// Returns the smallest `Interval` containing all of the points in the
+    /// `Selection`. This is an alias of [`enclose`] for use as the abstraction
+    /// of a Galois connection whose concretization is
+    /// [`Interval::concretized`].
+    ///
+    /// For any `Selection` `s` and `Interval` `i`, the connection satisfies
+    /// the following laws:
+    ///
+    /// + `s` is contained in `i.concretized()` if and only if
+    ///   `s.abstracted()` is contained in `i`.
+    /// + `s` is contained in `s.abstracted().concretized()`, so abstraction
+    ///   only ever loses precision.
+    /// + `i.concretized().abstracted() == i`, so no precision is lost by
+    ///   concretization.
+    ///
+    /// [`enclose`]: #method.enclose
+    /// [`Interval::concretized`]: ../interval/struct.Interval.html#method.concretized
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 2));
+    /// sel.union_in_place(Interval::closed(8, 9));
+    ///
+    /// assert_eq!(sel.abstracted(), Interval::closed(0, 9));
+    /// assert!(sel.minus(&sel.abstracted().concretized()).is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn abstracted(&self) -> Interval<T> {
+        self.enclose()
+    }
+
+    /// Returns the smallest closed `Interval` containing all of the points
+    /// in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.enclose(), Interval::closed(-2, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn closure(&self) -> Interval<T> {
+        Interval(self.0.closure().normalized())
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Removes all points from the `Selection`, retaining its allocated
+    /// storage so that it can be refilled without reallocating.
+    ///
+    /// Only a `Selection` within its [`capacity`] is stored contiguously, so
+    /// clearing a larger `Selection` releases its storage.
+    ///
+    /// [`capacity`]: #method.capacity
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.clear();
+    /// assert!(sel.is_empty());
+    ///
+    /// sel.union_in_place(Interval::closed(12, 15));
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(12, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Reduces the `Selection` to only those points contained in the given
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.intersect_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::open(2, 5)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.intersect_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        self.0.intersect_in_place_owned(interval.0.denormalized());
+        self.remove_empty_intervals();
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.union_in_place(Interval::open(12, 15));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 7), Interval::open(12, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
+    /// sel.union_in_place(Interval::open(7, 10));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-2, 9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        self.0.union_in_place_owned(interval.0.denormalized());
+    }
+
+    /// Removes all of the points in the given `Interval` from the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::closed(2, 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        self.0.minus_in_place_owned(interval.0.denormalized());
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`,
+    /// unless that would grow the `Selection` to more than `max_bounds`
+    /// bounds, as counted by [`Selection::bound_count`]. This bounds the
+    /// memory used by a `Selection` built from untrusted input.
+    ///
+    /// An operation which does not increase the bound count always succeeds,
+    /// even if the `Selection` is already over the limit. Within two bounds of
+    /// the limit, the `Selection` is copied so that the operation can be
+    /// undone.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SizeLimitError`] without modifying the `Selection` if the
+    /// result would have more than `max_bounds` bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    ///
+    /// assert!(sel.try_union_in_place(Interval::closed(10, 14), 2).is_err());
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 4)));
+    ///
+    /// sel.try_union_in_place(Interval::closed(3, 14), 2)?;
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 14)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Selection::bound_count`]: #method.bound_count
+    /// [`SizeLimitError`]: struct.SizeLimitError.html
+    pub fn try_union_in_place(
+        &mut self,
+        interval: Interval<T>,
+        max_bounds: usize)
+        -> Result<(), SizeLimitError>
+    {
+        let count = self.bound_count();
+        // A union adds at most two bounds.
+        if count.saturating_add(2) <= max_bounds {
+            self.union_in_place(interval);
+            return Ok(());
+        }
+        // Near the limit, the `Selection` is copied so that the union can be
+        // undone.
+        let before = self.clone();
+        self.union_in_place(interval);
+        let grown = self.bound_count();
+        if grown > count && grown > max_bounds {
+            *self = before;
+            return Err(SizeLimitError);
+        }
+        Ok(())
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `Selection`, unless that would grow the `Selection` to more than
+    /// `max_bounds` bounds, as counted by [`Selection::bound_count`]. This
+    /// bounds the memory used by a `Selection` built from untrusted input,
+    /// such as a long run of removed points which would each split an
+    /// `Interval`.
+    ///
+    /// An operation which does not increase the bound count always succeeds,
+    /// even if the `Selection` is already over the limit. Within two bounds of
+    /// the limit, the `Selection` is copied so that the operation can be
+    /// undone.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SizeLimitError`] without modifying the `Selection` if the
+    /// result would have more than `max_bounds` bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 99));
+    ///
+    /// let mut removed = 0;
+    /// for point in (1..99).step_by(2) {
+    ///     if sel.try_minus_in_place(Interval::point(point), 16).is_err() {
+    ///         break;
+    ///     }
+    ///     removed += 1;
+    /// }
+    /// assert!(removed < 49);
+    /// assert!(sel.bound_count() <= 16);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Selection::bound_count`]: #method.bound_count
+    /// [`SizeLimitError`]: struct.SizeLimitError.html
+    pub fn try_minus_in_place(
+        &mut self,
+        interval: Interval<T>,
+        max_bounds: usize)
+        -> Result<(), SizeLimitError>
+    {
+        let count = self.bound_count();
+        // A difference adds at most two bounds.
+        if count.saturating_add(2) <= max_bounds {
+            self.minus_in_place(interval);
+            return Ok(());
+        }
+        // Near the limit, the `Selection` is copied so that the difference can
+        // be undone.
+        let before = self.clone();
+        self.minus_in_place(interval);
+        let grown = self.bound_count();
+        if grown > count && grown > max_bounds {
+            *self = before;
+            return Err(SizeLimitError);
+        }
+        Ok(())
+    }
+
+    /// Toggles the points in the given `Interval`, so that each is in the
+    /// `Selection` if and only if it was not. This is the symmetric difference
+    /// of the `Selection` and the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// sel.toggle_in_place(Interval::closed(3, 7));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 2), Interval::closed(5, 7)]);
+    ///
+    /// sel.toggle_in_place(Interval::closed(3, 4));
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 7)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn toggle_in_place(&mut self, interval: Interval<T>) {
+        let normalized = interval.0;
+        let denormalized = normalized.clone().denormalized();
+        self.0.toggle_in_place_owned(denormalized.clone());
+
+        // Toggling may leave a point just outside the `Interval` and the
+        // adjacent point just inside it in separate intervals, so these are
+        // rejoined by a union over both points.
+        let bounds = [
+            (denormalized.infimum(), normalized.infimum()),
+            (normalized.supremum(), denormalized.supremum()),
+        ];
+        for (below, above) in bounds.iter().cloned() {
+            if let (Some(below), Some(above)) = (below, above) {
+                if below != above
+                    && self.contains(&below)
+                    && self.contains(&above)
+                {
+                    self.union_in_place(Interval::closed(below, above));
+                }
+            }
+        }
+    }
+
+    /// Removes and returns all of the points greater than or equal to the
+    /// given point, splitting the `Interval` containing it. Like
+    /// `BTreeSet::split_off`, this takes logarithmic time for large
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.union_in_place(Interval::closed(20, 30));
+    ///
+    /// let above = sel.split_off(&5);
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 4)));
+    /// assert_eq!(above, Selection::from([
+    ///     Interval::closed(5, 10),
+    ///     Interval::closed(20, 30),
+    /// ]));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, at: &T) -> Self {
+        Self(self.0.split_off(at))
+    }
+
+    /// Moves all of the points of the given `Selection` into the `Selection`.
+    /// If every `Interval` of one `Selection` lies below those of the other,
+    /// such as for the results of sharded work, they are concatenated rather
+    /// than merged one `Interval` at a time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// sel.append(Selection::from(Interval::closed(20, 30)));
+    /// sel.append(Selection::from(Interval::closed(5, 25)));
+    ///
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 30)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn append(&mut self, other: Self) {
+        self.0.append(other.0);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Operation application
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Applies the given [`SelectionOp`] to the `Selection`.
+    ///
+    /// [`SelectionOp`]: ../ops/enum.SelectionOp.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::ops::SelectionOp;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// sel.apply(SelectionOp::Union(Interval::closed(0, 10)));
+    /// sel.apply(SelectionOp::Minus(Interval::closed(3, 5)));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 2), Interval::closed(6, 10)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apply(&mut self, op: SelectionOp<T>) {
+        use SelectionOp::*;
+        match op {
+            Union(interval)     => self.union_in_place(interval),
+            Intersect(interval) => self.intersect_in_place(interval),
+            Minus(interval)     => self.minus_in_place(interval),
+            Complement          => *self = self.complement(),
+            Clear               => self.clear(),
+            Batch(ops)          => self.apply_all(ops),
+        }
+    }
+
+    /// Applies the given [`SelectionOp`] to the `Selection`, returning the
+    /// operation which will undo it.
+    ///
+    /// The inverse operation is computed from the points actually added or
+    /// removed, so applying it restores the `Selection` exactly without
+    /// requiring a copy of its prior state.
+    ///
+    /// [`SelectionOp`]: ../ops/enum.SelectionOp.html
     ///
     /// # Example
     ///
@@ -607,42 +2727,183 @@ impl<T> Selection<T>
     /// # use std::error::Error;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
+    /// # use normalize_interval::ops::SelectionOp;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-3, 13)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let undo = sel.apply_invertible(
+    ///     SelectionOp::Union(Interval::closed(5, 15)));
+    ///
+    /// assert_eq!(undo, SelectionOp::Minus(Interval::closed(11, 15)));
+    ///
+    /// sel.apply(undo);
+    /// assert_eq!(sel, Selection::from(Interval::closed(0, 10)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn apply_invertible(&mut self, op: SelectionOp<T>) -> SelectionOp<T> {
+        use SelectionOp::*;
+        match op {
+            Union(interval) => {
+                let added = Self::from(interval.clone()).minus(self);
+                self.union_in_place(interval);
+                SelectionOp::batch(added
+                    .into_interval_iter()
+                    .map(Minus)
+                    .collect())
+            },
+            Intersect(interval) => {
+                let removed = self.minus(&Self::from(interval.clone()));
+                self.intersect_in_place(interval);
+                SelectionOp::batch(removed
+                    .into_interval_iter()
+                    .map(Union)
+                    .collect())
+            },
+            Minus(interval) => {
+                let removed = self.intersect(&Self::from(interval.clone()));
+                self.minus_in_place(interval);
+                SelectionOp::batch(removed
+                    .into_interval_iter()
+                    .map(Union)
+                    .collect())
+            },
+            Complement => {
+                *self = self.complement();
+                Complement
+            },
+            Clear => {
+                let removed = std::mem::take(self);
+                SelectionOp::batch(removed
+                    .into_interval_iter()
+                    .map(Union)
+                    .collect())
+            },
+            Batch(ops) => {
+                let mut inverses: Vec<_> = ops
+                    .into_iter()
+                    .map(|op| self.apply_invertible(op))
+                    .collect();
+                inverses.reverse();
+                SelectionOp::batch(inverses)
+            },
+        }
+    }
+
+    /// Applies each of the given [`SelectionOp`]s to the `Selection` in order.
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// [`SelectionOp`]: ../ops/enum.SelectionOp.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::ops::SelectionOp;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// sel.apply_all(vec![
+    ///     SelectionOp::Union(Interval::closed(0, 10)),
+    ///     SelectionOp::Intersect(Interval::closed(5, 15)),
+    ///     SelectionOp::Complement,
+    /// ]);
+    ///
+    /// assert_eq!(sel.contains(&7), false);
+    /// assert_eq!(sel.contains(&12), true);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apply_all<I>(&mut self, ops: I)
+        where I: IntoIterator<Item=SelectionOp<T>>
+    {
+        for op in ops {
+            self.apply(op);
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`, in
+    /// ascending order.
+    #[must_use]
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(self.0.interval_iter())
+    }
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`, in
+    /// ascending order.
+    #[must_use]
+    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
+        IntoIntervalIter(self.0.into_iter())
+    }
+
+    /// Returns an iterator over the lower and upper bounds of each of the
+    /// `Interval`s in the `Selection`, borrowed without cloning or
+    /// normalizing them.
+    ///
+    /// The bounds are those stored by the `Selection`, which for [`Finite`]
+    /// types are the exclusive bounds of the denormalized `Interval`s. They
+    /// therefore describe the same points as the `Interval`s yielded by
+    /// [`interval_iter`], but may differ from their bounds.
     ///
     /// [`Finite`]: ../normalize/trait.Finite.html
+    /// [`interval_iter`]: #method.interval_iter
+    ///
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.union(&b).interval_iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-2, 12)]);
+    /// let sel: Selection<i32> = Selection::from([
+    ///     Interval::closed(0, 9),
+    ///     Interval::point(20),
+    /// ]);
+    ///
+    /// assert_eq!(sel.raw_spans().collect::<Vec<_>>(), [
+    ///     (Bound::Exclude(&-1), Bound::Exclude(&10)),
+    ///     (Bound::Exclude(&19), Bound::Exclude(&21)),
+    /// ]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn union(&self, other: &Self) -> Self {
-        Self(self.0.union(&other.0))
+    pub fn raw_spans(&self) -> RawSpans<'_, T> {
+        RawSpans(self.0.bound_iter())
     }
+}
 
-    /// Returns the `Selection` containing all points in the `Selection` which
-    /// are not in the given `Selection`s.
+impl<T> Selection<T> 
+    where T: Ord + Clone + Finite, 
+{
+    /// Returns an iterator over each of the points in the `Selection`.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.0.interval_iter(),
+            cursor: PointCursor::new(Interval::full()),
+        }
+    }
+
+    /// Returns the smallest `Selection` covering this one whose `Interval`s
+    /// are aligned to whole blocks of the given quantum. See
+    /// [`Interval::aligned_outward`].
+    ///
+    /// [`Interval::aligned_outward`]: ../interval/struct.Interval.html#method.aligned_outward
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
     ///
     /// # Example
     ///
@@ -652,21 +2913,31 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.minus(&b).interval_iter().collect::<Vec<_>>(),
-    ///     vec![Interval::right_open(-3, 4)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(3, 7));
+    /// sel.union_in_place(Interval::closed(12, 14));
+    ///
+    /// assert_eq!(sel.aligned_outward(&10).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 19)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn minus(&self, other: &Self) -> Self {
-        Self(self.0.minus(&other.0))
+    pub fn aligned_outward(&self, quantum: &T) -> Self where T: Align {
+        self.interval_iter()
+            .map(|interval| interval.aligned_outward(quantum))
+            .collect()
     }
 
-    /// Returns the smallest `Interval` containing all of the points in the 
-    /// `Selection`.
+    /// Returns the largest `Selection` covered by this one whose `Interval`s
+    /// are aligned to whole blocks of the given quantum. See
+    /// [`Interval::aligned_inward`].
+    ///
+    /// [`Interval::aligned_inward`]: ../interval/struct.Interval.html#method.aligned_inward
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
     ///
     /// # Example
     ///
@@ -676,22 +2947,26 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(3, 27));
+    /// sel.union_in_place(Interval::closed(32, 38));
     ///
-    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    /// assert_eq!(sel.aligned_inward(&10).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(10, 19)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn enclose(&self) -> Interval<T> {
-        Interval(self.0.enclose().normalized())
+    pub fn aligned_inward(&self, quantum: &T) -> Self where T: Align {
+        self.interval_iter()
+            .map(|interval| interval.aligned_inward(quantum))
+            .collect()
     }
 
-    /// Returns the smallest closed `Interval` containing all of the points
-    /// in the `Selection`.
+    /// Returns the mirror image of the `Selection` around the given center
+    /// point. See [`Interval::reflected`].
+    ///
+    /// [`Interval::reflected`]: ../interval/struct.Interval.html#method.reflected
     ///
     /// # Example
     ///
@@ -701,25 +2976,82 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
+    /// let mut window: Selection<i32> = Selection::from(Interval::closed(1, 3));
+    /// window.union_in_place(Interval::closed(6, 10));
     ///
-    /// assert_eq!(sel.enclose(), Interval::closed(-2, 13));
+    /// assert_eq!(window.reflected(&0), Selection::from([
+    ///     Interval::closed(-10, -6),
+    ///     Interval::closed(-3, -1),
+    /// ]));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn closure(&self) -> Interval<T> {
-        Interval(self.0.closure().normalized())
+    pub fn reflected(&self, center: &T) -> Self where T: Reflect {
+        // Reflection reverses the order of the `Interval`s.
+        Self::from_sorted_disjoint(AssumeSorted::new(self
+            .interval_iter()
+            .rev()
+            .map(|interval| interval.reflected(center))))
     }
 
-    // In-place operations
-    ////////////////////////////////////////////////////////////////////////////
+    /// Projects the `Selection` onto the grid of blocks of the given quantum,
+    /// returning the whole blocks which either cover or are covered by the
+    /// `Selection`, depending on the given [`QuantizeMode`].
+    ///
+    /// [`QuantizeMode`]: enum.QuantizeMode.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantum` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::QuantizeMode;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(5, 25));
+    /// sel.union_in_place(Interval::closed(42, 44));
+    ///
+    /// assert_eq!(sel.quantize(&10, QuantizeMode::Cover)
+    ///         .interval_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 29), Interval::closed(40, 49)]);
+    ///
+    /// assert_eq!(sel.quantize(&10, QuantizeMode::Within)
+    ///         .interval_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     [Interval::closed(10, 19)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn quantize(&self, quantum: &T, mode: QuantizeMode) -> Self
+        where T: Align
+    {
+        match mode {
+            QuantizeMode::Cover  => self.aligned_outward(quantum),
+            QuantizeMode::Within => self.aligned_inward(quantum),
+        }
+    }
 
-    /// Reduces the `Selection` to only those points contained in the given
-    /// `Interval`.
+    /// Returns the earliest `Interval` of `duration` points within the
+    /// `Selection` which starts no earlier than `not_before`, and at a multiple
+    /// of `alignment` if one is given. The `Selection` is treated as the free
+    /// time available for the `Interval`.
+    ///
+    /// Returns `None` if no such `Interval` exists or if `duration` is not
+    /// positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is not positive.
     ///
     /// # Example
     ///
@@ -729,40 +3061,408 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.intersect_in_place(Interval::open(2, 5));
+    /// let mut free: Selection<u32> = Selection::from(Interval::closed(0, 40));
+    /// free.minus_in_place(Interval::closed(10, 25));
     ///
-    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::open(2, 5)]);
+    /// assert_eq!(free.earliest_fit(3, &7, None), Some(Interval::closed(7, 9)));
+    /// assert_eq!(free.earliest_fit(5, &7, None),
+    ///     Some(Interval::closed(26, 30)));
+    /// assert_eq!(free.earliest_fit(20, &0, None), None);
+    /// assert_eq!(free.earliest_fit(5, &7, Some(15)),
+    ///     Some(Interval::closed(30, 34)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn earliest_fit(&self,
+        duration: T,
+        not_before: &T,
+        alignment: Option<T>)
+        -> Option<Interval<T>>
+        where
+            T: Align + Measurable + Add<Output=T> + Default,
+            T::Length: PartialOrd,
+    {
+        // The last point of the slot is `duration - 1` points after its start.
+        let zero = T::default();
+        if duration <= zero { return None; }
+        let offset = duration.pred()?;
+        let offset_span = T::span(&zero, &offset);
+
+        for interval in self.interval_iter() {
+            let (Some(lower), Some(upper)) = (
+                interval.0.lower_point(),
+                interval.0.upper_point()) else { continue };
+            if upper < not_before { continue; }
+
+            let mut start = lower.max(not_before).clone();
+            if let Some(quantum) = &alignment {
+                if start.block_start(quantum).as_ref() != Some(&start) {
+                    match start.block_end(quantum).and_then(|end| end.succ()) {
+                        Some(next) => start = next,
+                        None       => return None,
+                    }
+                }
+            }
+            if start <= *upper && T::span(&start, upper) >= offset_span {
+                let end = start.clone() + offset;
+                return Some(Interval::closed(start, end));
+            }
+        }
+        None
+    }
+
+    /// Splits the `Selection` into the given number of shards with nearly
+    /// equal numbers of points, cutting `Interval`s where necessary. The
+    /// shards are in order, and their sizes differ by at most one point. If
+    /// there are fewer points than shards, the last shards are empty.
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// Points are counted exactly in a `u128` from the [`Measurable`] span of
+    /// each `Interval`, so only a `Selection` of more than `u128::MAX` points
+    /// is counted as smaller than it is.
+    ///
+    /// [`Measurable`]: ../measure/trait.Measurable.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let pending: Selection<u32> = Selection::from([
+    ///     Interval::closed(0, 9),
+    ///     Interval::closed(100, 103),
+    /// ]);
+    /// let shards = pending.shard(3);
+    ///
+    /// assert_eq!(shards[0].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 4)]);
+    /// assert_eq!(shards[1].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(5, 9)]);
+    /// assert_eq!(shards[2].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(100, 103)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn shard(&self, n: usize) -> Vec<Self>
+        where
+            T: Measurable + Add<Output=T> + TryFrom<u128>,
+            u128: TryFrom<T::Length>,
+    {
+        assert!(n > 0, "shard count must be positive");
+        // The number of points from `lower` to `upper`, saturating.
+        let count = |lower: &T, upper: &T| u128::try_from(T::span(lower, upper))
+            .map_or(u128::MAX, |span| span.saturating_add(1));
+        // The point `offset` points after `point`. An offset within an
+        // `Interval` may not fit a signed `T`, but half of it always does.
+        let advance = |point: T, offset: u128| {
+            let step = |point: T, offset: u128| point + T::try_from(offset)
+                .ok()
+                .expect("offset within interval");
+            let half = offset / 2;
+            step(step(point, half), offset - half)
+        };
+
+        let total = self.interval_iter()
+            .filter_map(|i| Some(count(&i.infimum()?, &i.supremum()?)))
+            .fold(0_u128, u128::saturating_add);
+        let shards_u128 = <u128 as TryFrom<usize>>::try_from(n)
+            .expect("shard count fits u128");
+        let quota = total / shards_u128;
+        let extra = usize::try_from(total % shards_u128)
+            .expect("remainder less than shard count");
+
+        let mut shards = Vec::with_capacity(n);
+        let mut current = Self::new();
+        let mut remaining = quota + u128::from(extra > 0);
+        for interval in self.interval_iter() {
+            let (Some(mut lower), Some(upper)) = (
+                interval.infimum(),
+                interval.supremum()) else { continue };
+            loop {
+                let size = count(&lower, &upper);
+                if size <= remaining || shards.len() + 1 == n {
+                    current.union_in_place(Interval::closed(lower, upper));
+                    remaining = remaining.saturating_sub(size);
+                    if remaining == 0 && shards.len() + 1 < n {
+                        shards.push(std::mem::take(&mut current));
+                        remaining = quota + u128::from(shards.len() < extra);
+                    }
+                    break;
+                }
+                // Fill the current shard from the start of the `Interval`.
+                let cut = advance(lower.clone(), remaining - 1);
+                let next = cut.succ().expect("cut before interval end");
+                current.union_in_place(Interval::closed(lower, cut));
+                shards.push(std::mem::take(&mut current));
+                remaining = quota + u128::from(shards.len() < extra);
+                lower = next;
+            }
+        }
+        shards.push(current);
+        shards.resize_with(n, Self::new);
+        shards
+    }
+
+    /// Returns the flat binary encoding of the `Selection`, which can be read
+    /// without decoding by a [`SelectionView`].
+    ///
+    /// [`SelectionView`]: ../flat/struct.SelectionView.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u16> = Selection::from(Interval::closed(3, 0x102));
+    ///
+    /// assert_eq!(sel.serialize_flat(), [3, 0, 2, 1]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn serialize_flat(&self) -> Vec<u8> where T: FlatPoint {
+        let mut bytes = Vec::new();
+        let mut bounds: Option<(T, T)> = None;
+        for interval in self.interval_iter() {
+            let (Some(lower), Some(upper)) = (
+                interval.0.lower_point(),
+                interval.0.upper_point()) else { continue };
+            bounds = Some(match bounds {
+                // Adjacent `Interval`s are merged so that the encoded points
+                // are strictly increasing between `Interval`s.
+                Some((first, last)) if last.succ().as_ref() == Some(lower) =>
+                    (first, upper.clone()),
+                Some((first, last)) => {
+                    first.write_flat(&mut bytes);
+                    last.write_flat(&mut bytes);
+                    (lower.clone(), upper.clone())
+                },
+                None => (lower.clone(), upper.clone()),
+            });
+        }
+        if let Some((first, last)) = bounds {
+            first.write_flat(&mut bytes);
+            last.write_flat(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Returns the `Selection` of the same points converted to another
+    /// [`Finite`] type, such as a wider integer type. The conversion must
+    /// preserve the order of points.
     ///
     /// [`Finite`]: ../normalize/trait.Finite.html
     ///
+    /// # Example
+    ///
     /// ```rust
     /// # use std::error::Error;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.intersect_in_place(Interval::open(2, 5));
+    /// let sel: Selection<u16> = Selection::from(Interval::closed(3, 700));
     ///
-    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(3, 4)]);
+    /// assert_eq!(sel.convert::<u32>(),
+    ///     Selection::from(Interval::closed(3, 700)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
-        self.0.intersect_in_place(&interval.0.denormalized());
+    #[must_use]
+    pub fn convert<U>(&self) -> Selection<U>
+        where
+            U: From<T> + Ord + Clone + Finite,
+            RawInterval<U>: Normalize,
+    {
+        self.interval_iter()
+            .filter_map(|interval| match (
+                interval.0.lower_point(),
+                interval.0.upper_point())
+            {
+                (Some(lower), Some(upper)) => Some(Interval::closed(
+                    U::from(lower.clone()),
+                    U::from(upper.clone()))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the `Selection` of the same points converted to another
+    /// [`Finite`] type, such as a narrower integer type. The conversion must
+    /// preserve the order of points.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Errors
+    ///
+    /// Returns the conversion error if any bound of an `Interval` of the
+    /// `Selection` cannot be converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u32> = Selection::from(Interval::closed(3, 700));
+    ///
+    /// assert_eq!(sel.try_convert::<u16>()?,
+    ///     Selection::from(Interval::closed(3, 700)));
+    /// assert!(sel.try_convert::<u8>().is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_convert<U>(&self) -> Result<Selection<U>, U::Error>
+        where
+            U: TryFrom<T> + Ord + Clone + Finite,
+            RawInterval<U>: Normalize,
+    {
+        self.interval_iter()
+            .filter_map(|interval| match (
+                interval.0.lower_point(),
+                interval.0.upper_point())
+            {
+                (Some(lower), Some(upper)) => Some(
+                    U::try_from(lower.clone()).and_then(|lower|
+                        U::try_from(upper.clone()).map(|upper|
+                            Interval::closed(lower, upper)))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the `Selection` of the points representable in another
+    /// [`Finite`] type, converted to that type. Points outside of the range
+    /// of the type are dropped, so the conversion never fails. The
+    /// conversions must preserve the order of points.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i64> = Selection::from(Interval::closed(-9, 4));
+    /// sel.union_in_place(Interval::closed(10, 5_000_000_000));
+    ///
+    /// assert_eq!(sel.clamped_to_type::<u32>(), Selection::from([
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(10, u32::MAX),
+    /// ]));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn clamped_to_type<U>(&self) -> Selection<U>
+        where
+            T: TryFrom<U>,
+            RawInterval<T>: Normalize,
+            U: TryFrom<T> + Ord + Clone + Finite,
+            RawInterval<U>: Normalize,
+    {
+        // A bound of `U` which is not a point of `T` lies beyond the points
+        // of `T`, so it does not clip them.
+        let range = match (
+            T::try_from(U::MINIMUM).ok(),
+            T::try_from(U::MAXIMUM).ok())
+        {
+            (Some(lower), Some(upper)) => Interval::closed(lower, upper),
+            (Some(lower), None)        => Interval::unbounded_from(lower),
+            (None,        Some(upper)) => Interval::unbounded_to(upper),
+            (None,        None)        => Interval::full(),
+        };
+        Selection::from_sorted_disjoint(AssumeSorted::new(self
+            .intersections_with(&range)
+            .filter_map(|interval| match (
+                interval.infimum(),
+                interval.supremum())
+            {
+                (Some(lower), Some(upper)) => Some(Interval::closed(
+                    U::try_from(lower).ok()?,
+                    U::try_from(upper).ok()?)),
+                _ => None,
+            })))
+    }
+}
+
+#[cfg(feature="rand")]
+impl<T> Selection<T> where T: Ord + Clone + Finite + SampleUniform {
+    /// Returns a random sub-selection of the `Selection` made of at most
+    /// `fragments` `Interval`s. Requires the `rand` feature.
+    ///
+    /// Each fragment is a [`random_subinterval`] of a uniformly chosen
+    /// `Interval` of the `Selection`. Overlapping and adjacent fragments are
+    /// merged, so larger values of `fragments` produce more fragmented but
+    /// also more complete sub-selections. The result is empty if the
+    /// `Selection` is empty or `fragments` is zero.
+    ///
+    /// [`random_subinterval`]: ../interval/struct.Interval.html#method.random_subinterval
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u32> = Selection::from([
+    ///     Interval::closed(0, 999),
+    ///     Interval::closed(5000, 5999),
+    /// ]);
+    /// let sub = sel.random_subselection(&mut rand::thread_rng(), 8);
+    ///
+    /// assert!(sub.interval_iter().count() <= 8);
+    /// assert!(sub.minus(&sel).is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn random_subselection<R>(&self, rng: &mut R, fragments: usize)
+        -> Self
+        where R: Rng + ?Sized
+    {
+        let intervals: Vec<_> = self.interval_iter().collect();
+        if intervals.is_empty() { return Self::new(); }
+
+        let mut sub = Self::new();
+        for _ in 0..fragments {
+            let interval = &intervals[rng.gen_range(0..intervals.len())];
+            sub.union_in_place(interval.random_subinterval(rng));
+        }
+        sub
     }
+}
 
-    /// Adds all of the points in the given `Interval` to the `Selection`.
+impl Selection<usize> {
+    /// Constructs a `Selection` from an iterator of start index and length
+    /// pairs, as used by diff hunks and `splice`-style arguments.
+    /// Zero-length pairs select nothing.
     ///
     /// # Example
     ///
@@ -772,19 +3472,28 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.union_in_place(Interval::open(12, 15));
+    /// let sel = Selection::from_start_len_pairs([(2, 3), (5, 2), (9, 0)]);
     ///
     /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 7), Interval::open(12, 15)]);
+    ///     [Interval::closed(2, 6)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn from_start_len_pairs<I>(pairs: I) -> Self
+        where I: IntoIterator<Item=(usize, usize)>
+    {
+        pairs
+            .into_iter()
+            .map(|(start, len)| Interval::from_start_len(start, len))
+            .collect()
+    }
+
+    /// Returns the start index and length of each `Interval` in the
+    /// `Selection`, in order. Adjacent `Interval`s are merged, so no pair
+    /// will be zero-length.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -792,89 +3501,114 @@ impl<T> Selection<T>
     /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
-    /// sel.union_in_place(Interval::open(7, 10));
+    /// let mut sel = Selection::from(Interval::closed(2, 4));
+    /// sel.union_in_place(Interval::right_open(8, 10));
     ///
-    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-2, 9)]);
+    /// assert_eq!(sel.to_start_len_pairs(), [(2, 3), (8, 2)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union_in_place(&mut self, interval: Interval<T>) {
-        self.0.union_in_place(&interval.0.denormalized());
+    #[must_use]
+    pub fn to_start_len_pairs(&self) -> Vec<(usize, usize)> {
+        self.interval_iter()
+            .filter_map(|interval| interval.to_start_len())
+            .collect()
     }
+}
 
-    /// Removes all of the points in the given `Interval` from the `Selection`.
+impl Selection<Ipv4Addr> {
+    /// Constructs a `Selection` of the addresses in the given CIDR blocks.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
+    /// # use normalize_interval::cidr::Ipv4Cidr;
+    /// # use std::net::Ipv4Addr;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::open(2, 5));
+    /// let sel = Selection::from_cidrs([
+    ///     Ipv4Cidr::new(Ipv4Addr::new(10, 0, 0, 0), 9).unwrap(),
+    ///     Ipv4Cidr::new(Ipv4Addr::new(10, 128, 0, 0), 9).unwrap(),
+    /// ]);
     ///
-    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    /// assert_eq!(sel.to_cidrs(), [
+    ///     Ipv4Cidr::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap(),
+    /// ]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn from_cidrs<I>(cidrs: I) -> Self
+        where I: IntoIterator<Item=Ipv4Cidr>
+    {
+        cidrs
+            .into_iter()
+            .map(|cidr| cidr.interval())
+            .collect()
+    }
+
+    /// Returns the fewest CIDR blocks which exactly cover the `Selection`, in
+    /// ascending order.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
     /// # use normalize_interval::Interval;
     /// # use normalize_interval::Selection;
+    /// # use std::net::Ipv4Addr;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::closed(2, 5));
+    /// let mut allowed = Selection::from(Interval::closed(
+    ///     Ipv4Addr::new(192, 168, 0, 0),
+    ///     Ipv4Addr::new(192, 168, 255, 255)));
+    /// allowed.minus_in_place(Interval::closed(
+    ///     Ipv4Addr::new(192, 168, 64, 0),
+    ///     Ipv4Addr::new(192, 168, 255, 255)));
+    /// allowed.minus_in_place(Interval::point(Ipv4Addr::new(192, 168, 0, 0)));
     ///
-    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
+    /// let rules: Vec<_> = allowed
+    ///     .to_cidrs()
+    ///     .iter()
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(rules, [
+    ///     "192.168.0.1/32", "192.168.0.2/31", "192.168.0.4/30",
+    ///     "192.168.0.8/29", "192.168.0.16/28", "192.168.0.32/27",
+    ///     "192.168.0.64/26", "192.168.0.128/25", "192.168.1.0/24",
+    ///     "192.168.2.0/23", "192.168.4.0/22", "192.168.8.0/21",
+    ///     "192.168.16.0/20", "192.168.32.0/19",
+    /// ]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus_in_place(&mut self, interval: Interval<T>) {
-        self.0.minus_in_place(&interval.0.denormalized());
-    }
-
-    ////////////////////////////////////////////////////////////////////////////
-    // Iterator conversions
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
-    #[must_use]
-    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
-        IntervalIter(self.0.interval_iter())
-    }
-
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
+    #[allow(clippy::missing_panics_doc)]
     #[must_use]
-    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
-        IntoIntervalIter(self.0.into_iter())
-    }
-}
+    pub fn to_cidrs(&self) -> Vec<Ipv4Cidr> {
+        let numeric: Selection<u64> = self
+            .interval_iter()
+            .filter_map(|interval| Some(Interval::closed(
+                u64::from(u32::from(*interval.0.lower_point()?)),
+                u64::from(u32::from(*interval.0.upper_point()?)))))
+            .collect();
 
-impl<T> Selection<T> 
-    where T: Ord + Clone + Finite, 
-{
-    /// Returns an iterator over each of the points in the `Selection`.
-    #[must_use]
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            intervals: self.0.interval_iter(),
-            current: Interval::empty().iter(),
-        }
+        // Binary prefix ranges are CIDR blocks, with the range length giving
+        // the number of host bits.
+        selection_prefix_ranges(&numeric, 2)
+            .into_iter()
+            .map(|(prefix, host_bits)| {
+                let address = u32::try_from(prefix << host_bits)
+                    .expect("address within IPv4 range");
+                let prefix_len = u8::try_from(32 - host_bits)
+                    .expect("valid prefix length");
+                Ipv4Cidr::new(Ipv4Addr::from(address), prefix_len)
+                    .expect("valid prefix length")
+            })
+            .collect()
     }
 }
 
@@ -887,7 +3621,7 @@ impl<T> IntoIterator for Selection<T>
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
             intervals: self.0.into_iter(),
-            current: Interval::empty().iter(),
+            cursor: PointCursor::new(Interval::full()),
         }
     }
 }
@@ -901,11 +3635,38 @@ impl<'a, T> IntoIterator for &'a Selection<T>
     fn into_iter(self) -> Self::IntoIter {
         Iter {
             intervals: self.0.interval_iter(),
-            current: Interval::empty().iter(),
+            cursor: PointCursor::new(Interval::full()),
         }
     }
 }
 
+impl<T> PartialEq for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 ||
+            self.canonical_intervals() == other.canonical_intervals()
+    }
+}
+
+impl<T> Eq for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<T> Hash for Selection<T>
+    where
+        T: Ord + Clone + Hash,
+        RawInterval<T>: Normalize,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stable_hash(state);
+    }
+}
+
 impl<T> Extend<Interval<T>> for Selection<T>
     where
         T: Ord + Clone,
@@ -914,11 +3675,34 @@ impl<T> Extend<Interval<T>> for Selection<T>
     fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
         for interval in iter {
             let raw = interval.0.denormalized();
-            self.0.union_in_place(&raw);
+            self.0.union_in_place_owned(raw);
+        }
+    }
+}
+
+impl<T> Extend<T> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=T> {
+        for item in iter {
+            let raw = Interval::point(item).0.denormalized();
+            self.0.union_in_place_owned(raw);
         }
     }
 }
 
+impl<'a, T> Extend<&'a T> for Selection<T>
+    where
+        T: Ord + Clone + 'a,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=&'a T> {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
 impl<T> From<Interval<T>> for Selection<T>
     where
         T: Ord + Clone,
@@ -930,6 +3714,26 @@ impl<T> From<Interval<T>> for Selection<T>
     }
 }
 
+impl<T, const N: usize> From<[Interval<T>; N]> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(intervals: [Interval<T>; N]) -> Self {
+        IntoIterator::into_iter(intervals).collect()
+    }
+}
+
+impl<T> From<Vec<Interval<T>>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(intervals: Vec<Interval<T>>) -> Self {
+        intervals.into_iter().collect()
+    }
+}
+
 impl<T> FromIterator<Interval<T>> for Selection<T>
     where
         T: Ord + Clone,
@@ -939,7 +3743,7 @@ impl<T> FromIterator<Interval<T>> for Selection<T>
         let mut selection = Self::new();
         for interval in iter {
             let raw = interval.0.denormalized();
-            selection.0.union_in_place(&raw);
+            selection.0.union_in_place_owned(raw);
         }
         selection
     }
@@ -954,12 +3758,181 @@ impl<T> FromIterator<T> for Selection<T>
         let mut selection = Self::new();
         for item in iter {
             let raw = Interval::point(item).0.denormalized();
-            selection.0.union_in_place(&raw);
+            selection.0.union_in_place_owned(raw);
         }
         selection
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// JSON schema implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// A `Selection` is serialized as its ordered `Tine`s, which are the bounds of
+// its denormalized `Interval`s.
+#[cfg(feature="schemars")]
+impl<T> JsonSchema for Selection<T> where T: JsonSchema {
+    fn schema_name() -> Cow<'static, str> {
+        "Selection".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("normalize_interval::Selection<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "array",
+            "uniqueItems": true,
+            "items": generator.subschema_for::<Tine<T>>(),
+            "description": "The ordered bounds of the intervals of a \
+                selection.",
+        })
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer conversions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements lossless conversions from `Selection`s of builtin integer types
+/// to `Selection`s of wider integer types.
+macro_rules! std_integer_widening_impl {
+    // For each given type and the types it widens to...
+    ($($s:ident => $($t:ident),*;)*) => {
+        $($(impl From<Selection<$s>> for Selection<$t> {
+            fn from(selection: Selection<$s>) -> Self {
+                selection.convert()
+            }
+        })*)*
+    };
+}
+
+/// Implements checked conversions from `Selection`s of builtin integer types
+/// to `Selection`s of integer types which may not hold all of their points.
+macro_rules! std_integer_narrowing_impl {
+    // For each given type and the types it narrows to...
+    ($($s:ident => $($t:ident),*;)*) => {
+        $($(impl TryFrom<Selection<$s>> for Selection<$t> {
+            type Error = TryFromIntError;
+
+            fn try_from(selection: Selection<$s>)
+                -> Result<Self, Self::Error>
+            {
+                selection.try_convert()
+            }
+        })*)*
+    };
+}
+
+// Provide conversions mirroring those of the builtin integer types.
+std_integer_widening_impl![
+    u8    => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize;
+    u16   => u32, u64, u128, usize, i32, i64, i128;
+    u32   => u64, u128, i64, i128;
+    u64   => u128, i128;
+    i8    => i16, i32, i64, i128, isize;
+    i16   => i32, i64, i128, isize;
+    i32   => i64, i128;
+    i64   => i128;
+];
+
+std_integer_narrowing_impl![
+    u8    => i8;
+    u16   => u8, i8, i16, isize;
+    u32   => u8, u16, usize, i8, i16, i32, isize;
+    u64   => u8, u16, u32, usize, i8, i16, i32, i64, isize;
+    u128  => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize;
+    usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize;
+    i8    => u8, u16, u32, u64, u128, usize;
+    i16   => u8, u16, u32, u64, u128, usize, i8;
+    i32   => u8, u16, u32, u64, u128, usize, i8, i16, isize;
+    i64   => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize;
+    i128  => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize;
+    isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128;
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer negation
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implements negation of `Selection`s of builtin signed integer types, as
+/// the `Selection` of the negated points. The negation of the minimum point
+/// is not representable, so it is dropped.
+macro_rules! std_integer_neg_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Neg for Selection<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.reflected(&0)
+            }
+        }
+
+        impl Neg for &Selection<$t> {
+            type Output = Selection<$t>;
+
+            fn neg(self) -> Selection<$t> {
+                self.reflected(&0)
+            }
+        })*
+    };
+}
+
+// Provide negation mirroring that of the builtin signed integer types.
+std_integer_neg_impl![i8, i16, i32, i64, i128, isize];
+
+////////////////////////////////////////////////////////////////////////////////
+// QuantizeMode
+////////////////////////////////////////////////////////////////////////////////
+/// Determines which blocks are selected by [`Selection::quantize`].
+///
+/// [`Selection::quantize`]: struct.Selection.html#method.quantize
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuantizeMode {
+    /// Select every block containing any selected point.
+    Cover,
+    /// Select only the blocks whose points are all selected.
+    Within,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// BoundaryKind
+////////////////////////////////////////////////////////////////////////////////
+/// Determines whether a boundary found by [`Selection::next_boundary_after`]
+/// or [`Selection::prev_boundary_before`] opens or closes an `Interval`.
+///
+/// [`Selection::next_boundary_after`]: struct.Selection.html#method.next_boundary_after
+/// [`Selection::prev_boundary_before`]: struct.Selection.html#method.prev_boundary_before
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryKind {
+    /// The lower bound of an `Interval`, which opens a run of points.
+    Lower,
+    /// The upper bound of an `Interval`, which closes a run of points.
+    Upper,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SizeLimitError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when a fallible [`Selection`] operation would store
+/// more bounds than its limit allows.
+///
+/// [`Selection`]: struct.Selection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeLimitError;
+
+impl fmt::Display for SizeLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "selection size limit exceeded")
+    }
+}
+
+impl std::error::Error for SizeLimitError {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIntervalIter
 ////////////////////////////////////////////////////////////////////////////////
@@ -1010,7 +3983,7 @@ impl<T> FusedIterator for IntoIntervalIter<T>
 pub struct IntervalIter<'t, T>(crate::tine_tree::Iter<'t, T>)
     where T: Ord + Clone;
 
-impl<'t, T> Iterator for IntervalIter<'t, T> 
+impl<T> Iterator for IntervalIter<'_, T> 
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
@@ -1026,7 +3999,7 @@ impl<'t, T> Iterator for IntervalIter<'t, T>
 }
 
 
-impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T> 
+impl<T> DoubleEndedIterator for IntervalIter<'_, T> 
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
@@ -1039,13 +4012,181 @@ impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T>
     }
 }
 
-impl<'t, T> FusedIterator for IntervalIter<'t, T> 
+impl<T> FusedIterator for IntervalIter<'_, T> 
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {}
 
 
+////////////////////////////////////////////////////////////////////////////////
+// RawSpans
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the borrowed bounds of the `Interval`s of a
+/// `Selection`. Returned by [`Selection::raw_spans`].
+///
+/// [`Selection::raw_spans`]: struct.Selection.html#method.raw_spans
+#[derive(Debug)]
+pub struct RawSpans<'t, T>(crate::tine_tree::BoundIter<'t, T>)
+    where T: Ord + Clone;
+
+impl<'t, T> Iterator for RawSpans<'t, T>
+    where T: Ord + Clone,
+{
+    type Item = (Bound<&'t T>, Bound<&'t T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for RawSpans<'_, T>
+    where T: Ord + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> FusedIterator for RawSpans<'_, T>
+    where T: Ord + Clone,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IterState
+////////////////////////////////////////////////////////////////////////////////
+/// The progress of an iteration over the points of a `Selection`, which can
+/// be saved and used to resume the iteration later.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::selection::Iter;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<u32> = Selection::from([
+///     Interval::closed(10, 12),
+///     Interval::closed(20, 22),
+/// ]);
+/// let mut points = sel.iter();
+/// assert_eq!(points.next(), Some(10));
+/// assert_eq!(points.next(), Some(11));
+/// let state = points.state();
+/// assert_eq!(state.remaining(), &Interval::unbounded_from(12));
+///
+/// let resumed = Iter::resume(&sel, &state);
+/// assert_eq!(resumed.collect::<Vec<_>>(), [12, 20, 21, 22]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+pub struct IterState<T> {
+    /// The `Interval` containing the points which have not been yielded.
+    remaining: Interval<T>,
+}
+
+impl<T> IterState<T> {
+    /// Returns the `Interval` containing the points which have not been
+    /// yielded.
+    #[must_use]
+    pub const fn remaining(&self) -> &Interval<T> {
+        &self.remaining
+    }
+}
+
+/// The points at either end of an iteration over the points of a
+/// `Selection`.
+#[derive(Debug)]
+struct PointCursor<T> where T: Ord + Clone {
+    /// The points remaining in the `Interval` at the front.
+    front: crate::interval::Iter<T>,
+    /// The points remaining in the `Interval` at the back.
+    back: crate::interval::Iter<T>,
+    /// The `Interval` containing the points to be yielded.
+    window: Interval<T>,
+    /// The last point yielded from the front.
+    after: Option<T>,
+    /// The last point yielded from the back.
+    before: Option<T>,
+}
+
+impl<T> PointCursor<T> where T: Ord + Clone + Finite {
+    /// Constructs a new `PointCursor` yielding the points within the given
+    /// `Interval`.
+    fn new(window: Interval<T>) -> Self {
+        Self {
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+            window,
+            after: None,
+            before: None,
+        }
+    }
+
+    /// Returns the next point from the front, drawing `Interval`s from the
+    /// given iterator as needed.
+    fn next<I>(&mut self, intervals: &mut I) -> Option<T>
+        where I: Iterator<Item=RawInterval<T>>
+    {
+        loop {
+            if let Some(point) = self.front.next() {
+                self.after = Some(point.clone());
+                return Some(point);
+            }
+            if let Some(interval) = intervals.next() {
+                self.front = Interval::from(interval.normalized())
+                    .intersect(&self.window)
+                    .iter();
+            } else {
+                let point = self.back.next()?;
+                self.after = Some(point.clone());
+                return Some(point);
+            }
+        }
+    }
+
+    /// Returns the next point from the back, drawing `Interval`s from the
+    /// given iterator as needed.
+    fn next_back<I>(&mut self, intervals: &mut I) -> Option<T>
+        where I: DoubleEndedIterator<Item=RawInterval<T>>
+    {
+        loop {
+            if let Some(point) = self.back.next_back() {
+                self.before = Some(point.clone());
+                return Some(point);
+            }
+            if let Some(interval) = intervals.next_back() {
+                self.back = Interval::from(interval.normalized())
+                    .intersect(&self.window)
+                    .iter();
+            } else {
+                let point = self.front.next_back()?;
+                self.before = Some(point.clone());
+                return Some(point);
+            }
+        }
+    }
+
+    /// Returns the progress of the iteration.
+    fn state(&self) -> IterState<T> {
+        let mut remaining = self.window.clone();
+        if let Some(after) = &self.after {
+            remaining = remaining.intersect(&Interval::above(after.clone()));
+        }
+        if let Some(before) = &self.before {
+            remaining = remaining.intersect(&Interval::below(before.clone()));
+        }
+        IterState { remaining }
+    }
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIter
 ////////////////////////////////////////////////////////////////////////////////
@@ -1056,8 +4197,30 @@ pub struct IntoIter<T>
 {
     /// The interval iterator of the `TineTree`.
     intervals: crate::tine_tree::IntoIter<T>,
-    /// The current interval.
-    current: crate::interval::Iter<T>,
+    /// The points at either end of the iteration.
+    cursor: PointCursor<T>,
+}
+
+impl<T> IntoIter<T> where T: Ord + Clone + Finite {
+    /// Returns the progress of the iteration, which can be passed to
+    /// [`IntoIter::resume`] to continue it.
+    ///
+    /// [`IntoIter::resume`]: #method.resume
+    #[must_use]
+    pub fn state(&self) -> IterState<T> {
+        self.cursor.state()
+    }
+
+    /// Resumes an iteration over the points of the given `Selection` from
+    /// the given progress.
+    #[must_use]
+    pub fn resume(mut selection: Selection<T>, state: &IterState<T>) -> Self {
+        selection.intersect_in_place(state.remaining.clone());
+        Self {
+            intervals: selection.0.into_iter(),
+            cursor: PointCursor::new(state.remaining.clone()),
+        }
+    }
 }
 
 impl<T> Iterator for IntoIter<T>
@@ -1066,20 +4229,7 @@ impl<T> Iterator for IntoIter<T>
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
-        }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next()
+        self.cursor.next(&mut self.intervals)
     }
 }
 
@@ -1087,20 +4237,7 @@ impl<T> DoubleEndedIterator for IntoIter<T>
     where T: Ord + Clone + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
-        }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next_back()
+        self.cursor.next_back(&mut self.intervals)
     }
 }
 
@@ -1118,54 +4255,52 @@ pub struct Iter<'t, T>
 {
     /// The interval iterator of the `TineTree`.
     intervals: crate::tine_tree::Iter<'t, T>,
-    /// The current interval.
-    current: crate::interval::Iter<T>,
+    /// The points at either end of the iteration.
+    cursor: PointCursor<T>,
+}
+
+impl<'t, T> Iter<'t, T> where T: Ord + Clone + Finite {
+    /// Returns the progress of the iteration, which can be passed to
+    /// [`Iter::resume`] to continue it.
+    ///
+    /// [`Iter::resume`]: #method.resume
+    #[must_use]
+    pub fn state(&self) -> IterState<T> {
+        self.cursor.state()
+    }
+
+    /// Resumes an iteration over the points of the given `Selection` from
+    /// the given progress.
+    #[must_use]
+    pub fn resume(selection: &'t Selection<T>, state: &IterState<T>)
+        -> Self
+    {
+        let window = state.remaining.0.clone().denormalized();
+        Self {
+            intervals: selection.0.interval_iter_from(&window),
+            cursor: PointCursor::new(state.remaining.clone()),
+        }
+    }
 }
 
-impl<'t, T> Iterator for Iter<'t, T>
+impl<T> Iterator for Iter<'_, T>
     where T: Ord + Clone + Finite,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
-        }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next()
+        self.cursor.next(&mut self.intervals)
     }
 }
 
-impl<'t, T> DoubleEndedIterator for Iter<'t, T>
+impl<T> DoubleEndedIterator for Iter<'_, T>
     where T: Ord + Clone + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
-        }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
-
-        self.current.next_back()
+        self.cursor.next_back(&mut self.intervals)
     }
 }
 
-impl<'t, T> FusedIterator for Iter<'t, T>
+impl<T> FusedIterator for Iter<'_, T>
     where T: Ord + Clone + Finite,
 {}
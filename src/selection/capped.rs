@@ -0,0 +1,318 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a `Selection` with a cap on its number of disjoint `Interval`s.
+//!
+//! A [`CappedSelection`] tracks its number of fragments, the maximal runs of
+//! adjacent points, as it is edited. An edit which would exceed the cap is
+//! handled by its [`FragmentPolicy`], either by merging the `Interval`s
+//! around the narrowest gap, or by rejecting the edit. Since the cap is
+//! enforced by each edit, a long-running tracker cannot fragment without
+//! bound.
+//!
+//! [`CappedSelection`]: struct.CappedSelection.html
+//! [`FragmentPolicy`]: enum.FragmentPolicy.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FragmentPolicy
+////////////////////////////////////////////////////////////////////////////////
+/// Determines how a [`CappedSelection`] handles an edit which would exceed
+/// its cap on fragments.
+///
+/// [`CappedSelection`]: struct.CappedSelection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FragmentPolicy {
+    /// Apply the edit, then fill the narrowest gap between fragments. The
+    /// width of a gap is the [`Measurable`] span between the bounds around
+    /// it, and the lowest of several narrowest gaps is filled.
+    ///
+    /// [`Measurable`]: ../../measure/trait.Measurable.html
+    MergeSmallestGap,
+    /// Reject the edit with a [`FragmentLimitError`], leaving the selection
+    /// unmodified.
+    ///
+    /// [`FragmentLimitError`]: struct.FragmentLimitError.html
+    Reject,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CappedSelection
+////////////////////////////////////////////////////////////////////////////////
+/// A `Selection` of at most a fixed number of fragments, the maximal runs of
+/// adjacent points. Constructed by [`Selection::with_max_fragments`].
+///
+/// The number of fragments is updated by each union or difference from the
+/// fragments near the edited `Interval`, without iterating over the whole
+/// `Selection`. Merging the narrowest gap visits every fragment, but only
+/// happens when an edit would exceed the cap.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::selection::capped::FragmentPolicy;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut seen = Selection::<u32>::with_max_fragments(
+///     2,
+///     FragmentPolicy::MergeSmallestGap);
+/// seen.union_in_place(Interval::closed(0, 10))?;
+/// seen.union_in_place(Interval::closed(50, 60))?;
+/// seen.union_in_place(Interval::closed(14, 20))?;
+///
+/// assert_eq!(seen.fragment_count(), 2);
+/// assert_eq!(seen.selection(), &Selection::from([
+///     Interval::closed(0, 20),
+///     Interval::closed(50, 60),
+/// ]));
+///
+/// let mut strict = Selection::<u32>::with_max_fragments(
+///     1,
+///     FragmentPolicy::Reject);
+/// strict.union_in_place(Interval::closed(0, 10))?;
+/// assert!(strict.minus_in_place(Interval::point(5)).is_err());
+/// assert_eq!(strict.selection(), &Selection::from(Interval::closed(0, 10)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Selection::with_max_fragments`]: ../struct.Selection.html#method.with_max_fragments
+#[derive(Debug, Clone)]
+pub struct CappedSelection<T> {
+    /// The selected points.
+    selection: Selection<T>,
+    /// The number of fragments of the `Selection`.
+    fragments: usize,
+    /// The maximum number of fragments.
+    max_fragments: usize,
+    /// The handling of edits which would exceed the maximum.
+    policy: FragmentPolicy,
+}
+
+impl<T> CappedSelection<T>
+    where
+        T: Finite + Ord + Clone + Measurable,
+        T::Length: PartialOrd,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs an empty `CappedSelection` of at most `max_fragments`
+    /// fragments. A single fragment is always allowed, even if
+    /// `max_fragments` is zero.
+    #[must_use]
+    pub fn new(max_fragments: usize, policy: FragmentPolicy) -> Self {
+        Self {
+            selection: Selection::new(),
+            fragments: 0,
+            max_fragments: max_fragments.max(1),
+            policy,
+        }
+    }
+
+    /// Returns the selected points.
+    #[must_use]
+    pub const fn selection(&self) -> &Selection<T> {
+        &self.selection
+    }
+
+    /// Returns the selected points, consuming the `CappedSelection`.
+    #[must_use]
+    pub fn into_selection(self) -> Selection<T> {
+        self.selection
+    }
+
+    /// Returns the number of fragments, the maximal runs of adjacent points.
+    #[must_use]
+    pub const fn fragment_count(&self) -> usize {
+        self.fragments
+    }
+
+    /// Returns the maximum number of fragments.
+    #[must_use]
+    pub const fn max_fragments(&self) -> usize {
+        self.max_fragments
+    }
+
+    /// Returns the handling of edits which would exceed the maximum number of
+    /// fragments.
+    #[must_use]
+    pub const fn policy(&self) -> FragmentPolicy {
+        self.policy
+    }
+
+    /// Removes all points.
+    pub fn clear(&mut self) {
+        self.selection.clear();
+        self.fragments = 0;
+    }
+
+    /// Adds all of the points in the given `Interval`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FragmentLimitError`] without modifying the selection if
+    /// the policy is [`FragmentPolicy::Reject`] and the result would exceed
+    /// the maximum number of fragments.
+    ///
+    /// [`FragmentLimitError`]: struct.FragmentLimitError.html
+    /// [`FragmentPolicy::Reject`]: enum.FragmentPolicy.html#variant.Reject
+    pub fn union_in_place(&mut self, interval: Interval<T>)
+        -> Result<(), FragmentLimitError>
+    {
+        self.edit(interval, Selection::union_in_place)
+    }
+
+    /// Removes all of the points in the given `Interval`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FragmentLimitError`] without modifying the selection if
+    /// the policy is [`FragmentPolicy::Reject`] and the result would exceed
+    /// the maximum number of fragments.
+    ///
+    /// [`FragmentLimitError`]: struct.FragmentLimitError.html
+    /// [`FragmentPolicy::Reject`]: enum.FragmentPolicy.html#variant.Reject
+    pub fn minus_in_place(&mut self, interval: Interval<T>)
+        -> Result<(), FragmentLimitError>
+    {
+        self.edit(interval, Selection::minus_in_place)
+    }
+
+    /// Removes all of the points not in the given `Interval`. This never adds
+    /// fragments, so it cannot fail.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        self.selection.intersect_in_place(interval);
+        self.fragments = fragment_count(&self.selection);
+    }
+
+    /// Applies the given edit of the points in the `Interval`, enforcing the
+    /// maximum number of fragments.
+    fn edit<F>(&mut self, interval: Interval<T>, op: F)
+        -> Result<(), FragmentLimitError>
+        where F: Fn(&mut Selection<T>, Interval<T>)
+    {
+        let (Some(lower), Some(upper)) = (
+            interval.infimum(),
+            interval.supremum()) else { return Ok(()) };
+
+        // Only the fragments overlapping or adjacent to the `Interval` can
+        // be changed by the edit, so the edit is first applied to those.
+        let window = Interval::closed(
+            lower.pred().unwrap_or(lower),
+            upper.succ().unwrap_or(upper));
+        let mut local: Selection<T> = self.selection
+            .intersections_with(&window)
+            .collect();
+        let before = fragment_count(&local);
+        op(&mut local, interval.clone());
+        let fragments = self.fragments - before + fragment_count(&local);
+
+        if fragments > self.max_fragments
+            && self.policy == FragmentPolicy::Reject
+        {
+            return Err(FragmentLimitError);
+        }
+        op(&mut self.selection, interval);
+        self.fragments = fragments;
+        // An edit adds at most one fragment, so a single merge is enough.
+        if self.fragments > self.max_fragments {
+            self.merge_smallest_gap();
+        }
+        Ok(())
+    }
+
+    /// Fills the narrowest gap between fragments.
+    fn merge_smallest_gap(&mut self) {
+        let runs = runs(&self.selection);
+        let smallest = runs
+            .iter()
+            .zip(runs.iter().skip(1))
+            .map(|((_, end), (start, _))| (end, start))
+            .reduce(|narrowest, (end, start)| {
+                let width = T::span(end, start);
+                if width < T::span(narrowest.0, narrowest.1) {
+                    (end, start)
+                } else {
+                    narrowest
+                }
+            });
+        if let Some((end, start)) = smallest {
+            let gap = Interval::closed(end.clone(), start.clone());
+            self.selection.union_in_place(gap);
+            self.fragments -= 1;
+        }
+    }
+}
+
+/// Returns the lower and upper points of the maximal runs of adjacent points
+/// in the `Selection`. Every run is bounded, since the points are `Finite`.
+fn runs<T>(selection: &Selection<T>) -> Vec<(T, T)>
+    where
+        T: Finite + Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    let mut runs: Vec<(T, T)> = Vec::new();
+    for interval in selection.interval_iter() {
+        let (Some(lower), Some(upper)) = (
+            interval.infimum(),
+            interval.supremum()) else { continue };
+        match runs.last_mut() {
+            Some((_, last)) if last.succ().as_ref() == Some(&lower) => {
+                *last = upper;
+            },
+            _ => runs.push((lower, upper)),
+        }
+    }
+    runs
+}
+
+/// Returns the number of maximal runs of adjacent points in the `Selection`.
+fn fragment_count<T>(selection: &Selection<T>) -> usize
+    where
+        T: Finite + Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    runs(selection).len()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FragmentLimitError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned when an edit of a [`CappedSelection`] would exceed its
+/// maximum number of fragments.
+///
+/// [`CappedSelection`]: struct.CappedSelection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FragmentLimitError;
+
+impl fmt::Display for FragmentLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "selection fragment limit exceeded")
+    }
+}
+
+impl std::error::Error for FragmentLimitError {}
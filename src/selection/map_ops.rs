@@ -0,0 +1,99 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides operations on ordered maps whose keys are selected by a
+//! `Selection`.
+//!
+//! Each operation runs one range query per `Interval` of the `Selection`, so
+//! its cost depends on the number of `Interval`s and of affected entries,
+//! rather than on the size of the map.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Key removal
+////////////////////////////////////////////////////////////////////////////////
+
+/// Removes the entries of the map whose keys are in the `Selection`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::selection::map_ops::remove_keys_in;
+/// # use std::collections::BTreeMap;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut cache: BTreeMap<u32, &str> = BTreeMap::new();
+/// let _ = cache.insert(10, "a");
+/// let _ = cache.insert(20, "b");
+/// let _ = cache.insert(30, "c");
+///
+/// // Invalidate the entries for a modified range of keys.
+/// remove_keys_in(&mut cache, &Selection::from(Interval::closed(15, 25)));
+/// assert_eq!(cache.keys().collect::<Vec<_>>(), [&10, &30]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn remove_keys_in<T, V>(map: &mut BTreeMap<T, V>, selection: &Selection<T>)
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    for interval in selection.interval_iter() {
+        if let Some(bounds) = interval.try_into_range_bounds() {
+            map.extract_if(bounds, |_, _| true).for_each(drop);
+        }
+    }
+}
+
+/// Removes the entries of the map whose keys are not in the `Selection`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::selection::map_ops::retain_keys_in;
+/// # use std::collections::BTreeMap;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut cache: BTreeMap<u32, &str> = BTreeMap::new();
+/// let _ = cache.insert(10, "a");
+/// let _ = cache.insert(20, "b");
+/// let _ = cache.insert(30, "c");
+///
+/// let mut live = Selection::from(Interval::closed(0, 12));
+/// live.union_in_place(Interval::unbounded_from(28));
+/// retain_keys_in(&mut cache, &live);
+/// assert_eq!(cache.keys().collect::<Vec<_>>(), [&10, &30]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn retain_keys_in<T, V>(map: &mut BTreeMap<T, V>, selection: &Selection<T>)
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    remove_keys_in(map, &selection.complement());
+}
@@ -0,0 +1,211 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the [`SortedDisjointIter`] marker trait for iterators which yield
+//! disjoint `Interval`s in ascending order.
+//!
+//! Every `Interval` iterator in this crate yields disjoint `Interval`s in
+//! ascending order, and implements `SortedDisjointIter` to guarantee it. A
+//! `Selection` can be built from such an iterator in linear time by
+//! [`Selection::from_sorted_disjoint`], where collecting arbitrary
+//! `Interval`s requires a search for each of them.
+//!
+//! [`SortedDisjointIter`]: trait.SortedDisjointIter.html
+//! [`Selection::from_sorted_disjoint`]: ../selection/struct.Selection.html#method.from_sorted_disjoint
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::array_selection;
+use crate::flat;
+use crate::flat::FlatPoint;
+use crate::interval::Interval;
+use crate::interval::algorithms::IntersectSortedStreams;
+use crate::interval::algorithms::UnionSortedStreams;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection;
+
+// Standard library imports.
+use std::iter;
+use std::option;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SortedDisjointIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over disjoint `Interval`s in ascending order.
+///
+/// Implementations must yield `Interval`s which do not overlap, each below
+/// the next. The `Interval`s may be adjacent or empty. An implementation
+/// which breaks this contract is not unsafe, but the `Selection`s built from
+/// it are unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::sorted_iter::SortedDisjointIter;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// /// Returns the `Selection` of the `Interval`s with at least two points.
+/// fn without_points<I>(intervals: I) -> Selection<i32>
+///     where I: SortedDisjointIter<i32>
+/// {
+///     Selection::from_sorted_disjoint(intervals
+///         .filter(|i| i.infimum() != i.supremum()))
+/// }
+///
+/// let sel: Selection<i32> = Selection::from([
+///     Interval::closed(0, 4),
+///     Interval::point(6),
+///     Interval::closed(8, 12),
+/// ]);
+/// assert_eq!(without_points(sel.interval_iter()), Selection::from([
+///     Interval::closed(0, 4),
+///     Interval::closed(8, 12),
+/// ]));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait SortedDisjointIter<T>: Iterator<Item=Interval<T>> {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SortedDisjointIter implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> SortedDisjointIter<T> for selection::IntervalIter<'_, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<T> SortedDisjointIter<T> for selection::IntoIntervalIter<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<T> SortedDisjointIter<T> for array_selection::IntervalIter<'_, T>
+    where T: Finite + Ord + Clone
+{}
+
+impl<T> SortedDisjointIter<T> for flat::IntervalIter<'_, T>
+    where T: FlatPoint + Finite + Ord + Clone
+{}
+
+// Streams combined from sorted inputs are sorted.
+impl<T, I> SortedDisjointIter<T> for UnionSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{}
+
+impl<T, I> SortedDisjointIter<T> for IntersectSortedStreams<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{}
+
+impl<T> SortedDisjointIter<T> for iter::Empty<Interval<T>> {}
+
+impl<T> SortedDisjointIter<T> for iter::Once<Interval<T>> {}
+
+impl<T> SortedDisjointIter<T> for option::IntoIter<Interval<T>> {}
+
+// Adapters which only remove `Interval`s preserve the order.
+impl<T, I, P> SortedDisjointIter<T> for iter::Filter<I, P>
+    where
+        I: SortedDisjointIter<T>,
+        P: FnMut(&Interval<T>) -> bool,
+{}
+
+impl<T, I> SortedDisjointIter<T> for iter::Fuse<I>
+    where I: SortedDisjointIter<T>
+{}
+
+impl<T, I> SortedDisjointIter<T> for iter::Peekable<I>
+    where I: SortedDisjointIter<T>
+{}
+
+impl<T, I> SortedDisjointIter<T> for iter::Skip<I>
+    where I: SortedDisjointIter<T>
+{}
+
+impl<T, I> SortedDisjointIter<T> for iter::Take<I>
+    where I: SortedDisjointIter<T>
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// AssumeSorted
+////////////////////////////////////////////////////////////////////////////////
+/// Wraps an `Iterator` which the caller guarantees yields disjoint
+/// `Interval`s in ascending order, such as one reading from a sorted index.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::sorted_iter::AssumeSorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let rows = vec![(0, 4), (6, 6), (8, 12)];
+/// let intervals = rows
+///     .into_iter()
+///     .map(|(lower, upper)| Interval::closed(lower, upper));
+///
+/// let sel = Selection::from_sorted_disjoint(AssumeSorted::new(intervals));
+/// assert_eq!(sel.interval_iter().count(), 3);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssumeSorted<I>(I);
+
+impl<I> AssumeSorted<I> {
+    /// Wraps the given `Iterator`, which must yield disjoint `Interval`s in
+    /// ascending order.
+    #[must_use]
+    pub const fn new(iter: I) -> Self {
+        Self(iter)
+    }
+
+    /// Returns the wrapped `Iterator`.
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<T, I> Iterator for AssumeSorted<I> where I: Iterator<Item=Interval<T>> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, I> SortedDisjointIter<T> for AssumeSorted<I>
+    where I: Iterator<Item=Interval<T>>
+{}
@@ -0,0 +1,245 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides piecewise-constant functions over the points of an `Interval`
+//! domain.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// StepFn
+////////////////////////////////////////////////////////////////////////////////
+/// A piecewise-constant function, mapping each `Interval` of a partition of
+/// all points to a value.
+///
+/// The pieces are stored in order, and adjacent pieces always have different
+/// values, so that each piece is the largest `Interval` on which the function
+/// is constant.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::step_fn::StepFn;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// // A rate table in cents per unit, by quantity.
+/// let mut rate: StepFn<u32, u32> = StepFn::constant(100);
+/// rate.set(Interval::unbounded_from(10), 90);
+/// rate.set(Interval::unbounded_from(100), 75);
+///
+/// assert_eq!(*rate.eval(&5), 100);
+/// assert_eq!(*rate.eval(&10), 90);
+/// assert_eq!(*rate.eval(&250), 75);
+///
+/// // A discount of 5 cents applies to quantities below 50.
+/// let mut discount: StepFn<u32, u32> = StepFn::constant(0);
+/// discount.set(Interval::right_open(0, 50), 5);
+///
+/// let price = rate.zip_with(&discount, |r, d| r - d);
+/// assert_eq!(price.into_pieces(), [
+///     (Interval::closed(0, 9), 95),
+///     (Interval::closed(10, 49), 85),
+///     (Interval::closed(50, 99), 90),
+///     (Interval::unbounded_from(100), 75),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepFn<T, V> {
+    /// The pieces of the function, in order.
+    pieces: Vec<(Interval<T>, V)>,
+}
+
+impl<T, V> StepFn<T, V>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `StepFn` with the given value at every point.
+    #[must_use]
+    pub fn constant(value: V) -> Self {
+        Self { pieces: vec![(Interval::full(), value)] }
+    }
+
+    /// Constructs a new `StepFn` with the given default value, then sets the
+    /// value of each of the given `Interval`s in order, so that later
+    /// `Interval`s take precedence over earlier ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::step_fn::StepFn;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let f: StepFn<i32, char> = StepFn::from_pieces('-', [
+    ///     (Interval::closed(0, 10), 'a'),
+    ///     (Interval::closed(5, 15), 'b'),
+    /// ]);
+    ///
+    /// assert_eq!(*f.eval(&-1), '-');
+    /// assert_eq!(*f.eval(&4), 'a');
+    /// assert_eq!(*f.eval(&5), 'b');
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_pieces<I>(default: V, pieces: I) -> Self
+        where
+            I: IntoIterator<Item=(Interval<T>, V)>,
+            V: Clone + PartialEq,
+    {
+        let mut step_fn = Self::constant(default);
+        for (interval, value) in pieces {
+            step_fn.set(interval, value);
+        }
+        step_fn
+    }
+
+    /// Returns the number of pieces of the `StepFn`.
+    #[must_use]
+    pub fn piece_count(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Returns `true` if the `StepFn` has the same value at every point.
+    #[must_use]
+    pub fn is_constant(&self) -> bool {
+        self.pieces.len() == 1
+    }
+
+    /// Returns the value of the `StepFn` at the given point.
+    #[must_use]
+    pub fn eval(&self, point: &T) -> &V {
+        // Find the first piece which does not end before the point.
+        let idx = self.pieces.partition_point(|(interval, _)| interval
+            .supremum()
+            .is_some_and(|sup| sup < *point
+                || (sup == *point && !interval.contains(point))));
+        &self.pieces[idx].1
+    }
+
+    /// Returns an iterator over the pieces of the `StepFn` and their values,
+    /// in order.
+    pub fn iter(&self) -> impl Iterator<Item=(&Interval<T>, &V)> + '_ {
+        self.pieces.iter().map(|(interval, value)| (interval, value))
+    }
+
+    /// Returns the pieces of the `StepFn` and their values, in order.
+    #[must_use]
+    pub fn into_pieces(self) -> Vec<(Interval<T>, V)> {
+        self.pieces
+    }
+
+    /// Sets the value of the `StepFn` at every point of the given `Interval`.
+    pub fn set(&mut self, interval: Interval<T>, value: V)
+        where V: Clone + PartialEq
+    {
+        if interval.is_empty() { return; }
+
+        let mut pieces = Vec::with_capacity(self.pieces.len() + 2);
+        for (piece, old) in self.pieces.drain(..) {
+            if piece.intersects(&interval) {
+                pieces.extend(piece
+                    .minus(&interval)
+                    .filter(|part| !part.is_empty())
+                    .map(|part| (part, old.clone())));
+            } else {
+                pieces.push((piece, old));
+            }
+        }
+        pieces.push((interval, value));
+        pieces.sort_by(|(a, _), (b, _)| cmp_pieces(a, b));
+        self.pieces = coalesced(pieces);
+    }
+
+    /// Returns the `StepFn` whose value at each point is the given function
+    /// of the values of the `StepFn` and the given `StepFn` at that point.
+    #[must_use]
+    pub fn zip_with<U, W, F>(&self, other: &StepFn<T, U>, mut f: F)
+        -> StepFn<T, W>
+        where
+            W: PartialEq,
+            F: FnMut(&V, &U) -> W,
+    {
+        let mut pieces = Vec::with_capacity(
+            self.piece_count() + other.piece_count());
+        let mut a = self.pieces.iter().peekable();
+        let mut b = other.pieces.iter().peekable();
+        while let (Some((x, v)), Some((y, u))) = (a.peek(), b.peek()) {
+            let overlap = x.intersect(y);
+            if !overlap.is_empty() {
+                pieces.push((overlap, f(v, u)));
+            }
+            // Advance past whichever piece ends first.
+            match (x.upper_bound(), y.upper_bound()) {
+                (Some(xu), Some(yu)) => match xu.cmp_upper(&yu) {
+                    Ordering::Less    => { let _ = a.next(); },
+                    Ordering::Greater => { let _ = b.next(); },
+                    Ordering::Equal   => {
+                        let _ = a.next();
+                        let _ = b.next();
+                    },
+                },
+                _ => break,
+            }
+        }
+        StepFn { pieces: coalesced(pieces) }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Piece helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compares disjoint nonempty pieces by their lower bounds.
+fn cmp_pieces<T>(a: &Interval<T>, b: &Interval<T>) -> Ordering
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    match (a.lower_bound(), b.lower_bound()) {
+        (Some(a), Some(b)) => a.cmp_lower(&b),
+        (a, b)             => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+/// Merges each run of ordered adjacent pieces with equal values.
+fn coalesced<T, V>(pieces: Vec<(Interval<T>, V)>) -> Vec<(Interval<T>, V)>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        V: PartialEq,
+{
+    let mut merged: Vec<(Interval<T>, V)> = Vec::with_capacity(pieces.len());
+    for (interval, value) in pieces {
+        match merged.last_mut() {
+            Some((last, last_value)) if *last_value == value => {
+                *last = last.enclose(&interval);
+            },
+            _ => merged.push((interval, value)),
+        }
+    }
+    merged
+}
@@ -13,7 +13,7 @@
 
 // Needs to be defined before submodule declarations.
 
-/// Variant of `assert_eq` that collects items into a HashSet before comparing.
+/// Variant of `assert_eq` that collects items into a `HashSet` before comparing.
 macro_rules! assert_eq_u {
     ($left:expr, $right:expr) => ({
         use std::collections::HashSet;
@@ -26,7 +26,7 @@ macro_rules! assert_eq_u {
 }
 
 /// Variant of `assert_eq` that converts to an iterator and collects items into
-/// a HashSet before comparing.
+/// a `HashSet` before comparing.
 macro_rules! assert_eq_i {
     ($left:expr, $right:expr) => ({
         use std::collections::HashSet;
@@ -39,5 +39,34 @@ macro_rules! assert_eq_i {
 }
 
 // Module declarations.
+#[cfg(feature="algorithms")]
+mod algorithms;
+#[cfg(feature="arrow")]
+mod arrow;
+mod array_selection;
+#[cfg(feature="num")]
+mod bignum;
+mod booking;
+mod bound;
+mod cidr;
+mod corpus;
+mod cpu_set;
+mod flat;
+mod interval;
+mod interval_set;
+mod lattice;
+mod partial_interval;
+mod predicate;
+mod prefix;
+#[cfg(feature="uom")]
+mod quantity;
+mod range_list;
 mod raw_interval;
+mod selection;
+mod sorted_iter;
+mod step_fn;
+mod text;
 mod tine_tree;
+mod units;
+#[cfg(feature="semver")]
+mod version;
@@ -0,0 +1,153 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for interval algorithms.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::algorithms::max_disjoint;
+use crate::interval::algorithms::minimum_cover;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+/// Returns the size of the smallest cover of the target by the candidates,
+/// found by trying every subset.
+fn exhaustive_cover_size(target: Interval<u8>, candidates: &[Interval<u8>])
+    -> Option<u32>
+{
+    let target = Selection::from(target);
+    (0_u32..1 << candidates.len())
+        .filter(|subset| {
+            let union: Selection<u8> = candidates
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| subset & (1 << idx) != 0)
+                .map(|(_, c)| *c)
+                .collect();
+            target.minus(&union).is_empty()
+        })
+        .map(u32::count_ones)
+        .min()
+}
+
+/// Returns the size of the largest disjoint subset of the nonempty intervals,
+/// found by trying every subset.
+fn exhaustive_disjoint_size(intervals: &[Interval<u8>]) -> u32 {
+    (0_u32..1 << intervals.len())
+        .filter(|subset| {
+            let chosen: Vec<_> = intervals
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| subset & (1 << idx) != 0)
+                .map(|(_, i)| *i)
+                .collect();
+            chosen.iter().enumerate().all(|(idx, a)|
+                chosen[idx + 1..].iter().all(|b| !a.intersects(b)))
+        })
+        .map(u32::count_ones)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns a sequence of pseudo-random intervals of `u8` points.
+fn intervals(state: &mut u32, count: usize) -> Vec<Interval<u8>> {
+    (0..count)
+        .map(|_| {
+            *state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let [a, b, ..] = (*state >> 8).to_le_bytes();
+            Interval::closed(a.min(b) / 4, a.max(b) / 4)
+        })
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// minimum_cover tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn minimum_cover_matches_exhaustive_search() {
+    let mut state: u32 = 0x1234_5678;
+    for _ in 0..200 {
+        let candidates = intervals(&mut state, 8);
+        let target = Interval::closed(10, 50);
+
+        let cover = minimum_cover(&target, &candidates);
+        let expected = exhaustive_cover_size(target, &candidates);
+        assert_eq!(
+            cover.as_ref().map(|c| u32::try_from(c.len()).unwrap()),
+            expected);
+
+        // The cover is ordered by the points covered, and covers the target.
+        if let Some(cover) = cover {
+            let union: Selection<u8> = cover
+                .iter()
+                .map(|&idx| candidates[idx])
+                .collect();
+            assert!(Selection::from(target).minus(&union).is_empty());
+            assert!(cover.windows(2).all(|pair|
+                candidates[pair[0]].infimum() < candidates[pair[1]].infimum()));
+        }
+    }
+}
+
+#[test]
+fn minimum_cover_edge_cases() {
+    let candidates = [
+        Interval::empty(),
+        Interval::closed(0, 5),
+        Interval::closed(6, 9),
+    ];
+    // Adjacent intervals cover the points between them.
+    assert_eq!(
+        minimum_cover(&Interval::closed(2, 8), &candidates),
+        Some(vec![1, 2]));
+    // An empty target needs no candidates.
+    assert_eq!(minimum_cover(&Interval::empty(), &candidates), Some(vec![]));
+    assert_eq!(minimum_cover(&Interval::closed(2, 8), &[]), None);
+    assert_eq!(
+        minimum_cover(&Interval::closed(0, 5), &candidates[1..1]),
+        None);
+    // A full candidate covers a full target by itself.
+    assert_eq!(
+        minimum_cover(&Interval::<u8>::full(), &[
+            Interval::closed(0, 100),
+            Interval::full(),
+        ]),
+        Some(vec![1]));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// max_disjoint tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn max_disjoint_matches_exhaustive_search() {
+    let mut state: u32 = 0x0bad_cafe;
+    for _ in 0..200 {
+        let mut candidates = intervals(&mut state, 9);
+        candidates[0] = Interval::empty();
+
+        let selected = max_disjoint(candidates.iter().copied());
+        let nonempty = &candidates[1..];
+        assert_eq!(
+            u32::try_from(selected.len()).unwrap(),
+            exhaustive_disjoint_size(nonempty));
+
+        // The selection is ordered by the points contained, and disjoint.
+        assert!(selected.windows(2).all(|pair|
+            candidates[pair[0]].supremum() < candidates[pair[1]].infimum()));
+    }
+}
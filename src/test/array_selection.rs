@@ -0,0 +1,154 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for fixed-capacity selections.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::array_selection::ArraySelection;
+use crate::array_selection::CapacityError;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+/// Returns a sequence of pseudo-random intervals of `u8` points.
+fn intervals(count: usize) -> Vec<Interval<u8>> {
+    let mut state: u32 = 0x2545_f491;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let [a, b, ..] = (state >> 8).to_le_bytes();
+            let (lower, upper) = (a.min(b), a.max(b));
+            Interval::closed(lower, lower + (upper - lower) / 8)
+        })
+        .collect()
+}
+
+/// Returns `true` if every point of `inner` is in `outer`.
+fn is_subset(inner: &Selection<u8>, outer: &Selection<u8>) -> bool {
+    inner.minus(outer).is_empty()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ArraySelection tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn checked_operations_match_selection() {
+    let mut array: ArraySelection<u8, 4> = ArraySelection::new();
+    let mut model: Selection<u8> = Selection::new();
+
+    for (idx, interval) in intervals(400).into_iter().enumerate() {
+        let mut expected = model.clone();
+        let result = match idx % 3 {
+            0 => {
+                expected.union_in_place(interval);
+                array.try_union_in_place(interval)
+            },
+            1 => {
+                expected.minus_in_place(interval);
+                array.try_minus_in_place(interval)
+            },
+            _ => {
+                // Keep most of the points, so that the selection stays busy.
+                let keep = interval.enclose(&Interval::closed(16, 240));
+                expected.intersect_in_place(keep);
+                array.intersect_in_place(keep);
+                Ok(())
+            },
+        };
+        let fits = ArraySelection::<u8, 4>::try_from(&expected).is_ok();
+        match result {
+            Ok(()) => {
+                model = expected;
+                assert!(fits);
+            },
+            Err(CapacityError) => assert!(!fits),
+        }
+        assert_eq!(array.to_selection(), model);
+        assert!(array.len() <= array.capacity());
+    }
+}
+
+#[test]
+fn saturating_operations_contain_selection() {
+    let mut array: ArraySelection<u8, 3> = ArraySelection::new();
+    let mut model: Selection<u8> = Selection::new();
+
+    for (idx, interval) in intervals(400).into_iter().enumerate() {
+        if idx % 2 == 0 {
+            model.union_in_place(interval);
+            array.union_in_place_saturating(interval);
+        } else {
+            model.minus_in_place(interval);
+            array.minus_in_place_saturating(interval);
+        }
+        let selected = array.to_selection();
+        assert!(is_subset(&model, &selected));
+        assert!(array.len() <= 3);
+        // The model tracks the saturated points from here on.
+        model = selected;
+    }
+}
+
+#[test]
+fn saturating_union_merges_narrowest_gap() {
+    let mut array: ArraySelection<u8, 2> = ArraySelection::new();
+    array.union_in_place_saturating(Interval::closed(0, 9));
+    array.union_in_place_saturating(Interval::closed(100, 109));
+
+    // Closer to the upper `Interval` than the gap below it.
+    array.union_in_place_saturating(Interval::closed(90, 95));
+    assert_eq!(array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 9),
+        Interval::closed(90, 109),
+    ]);
+
+    // An existing gap narrower than the gap below the new `Interval`.
+    array.union_in_place_saturating(Interval::closed(200, 200));
+    assert_eq!(array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 109),
+        Interval::closed(200, 200),
+    ]);
+    array.union_in_place_saturating(Interval::closed(150, 150));
+    assert_eq!(array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 150),
+        Interval::closed(200, 200),
+    ]);
+
+    // A split whose gap would be wider than the existing gap.
+    array.minus_in_place_saturating(Interval::closed(20, 130));
+    assert_eq!(array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 19),
+        Interval::closed(131, 200),
+    ]);
+
+    // A split whose gap would be narrower than the existing gap.
+    array.minus_in_place_saturating(Interval::closed(150, 151));
+    assert_eq!(array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 19),
+        Interval::closed(131, 200),
+    ]);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut array: ArraySelection<u8, 0> = ArraySelection::default();
+    assert_eq!(
+        array.try_union_in_place(Interval::point(3)),
+        Err(CapacityError));
+    assert_eq!(array.try_union_in_place(Interval::empty()), Ok(()));
+    assert_eq!(array.try_minus_in_place(Interval::point(3)), Ok(()));
+    assert!(array.is_empty());
+}
@@ -0,0 +1,78 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for Arrow array conversions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::arrow::SpanArrays;
+use crate::arrow::SpanArraysError;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// External library imports.
+use arrow_array::Array;
+use arrow_array::BooleanArray;
+use arrow_array::Int64Array;
+use arrow_array::UInt64Array;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SpanArrays tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn span_arrays_round_trip() {
+    let mut sel: Selection<i64> = (0..20)
+        .map(|i| Interval::closed(i * 10 - 100, i * 10 - 96))
+        .collect();
+    sel.union_in_place(Interval::unbounded_from(1000));
+    sel.union_in_place(Interval::point(i64::MIN));
+    let arrays = SpanArrays::from_selection(&sel);
+    assert_eq!(arrays.len(), 22);
+    assert_eq!(arrays.starts.null_count(), 0);
+    assert_eq!(arrays.ends.value(21), i64::MAX);
+    assert_eq!(arrays.to_selection(), Ok(sel));
+
+    let sel: Selection<u64> = Selection::from(Interval::full());
+    let arrays = SpanArrays::from_selection(&sel);
+    assert_eq!(arrays.starts, UInt64Array::from(vec![0]));
+    assert_eq!(arrays.ends, UInt64Array::from(vec![u64::MAX]));
+    assert_eq!(arrays.to_selection(), Ok(sel));
+
+    let arrays = SpanArrays::<u64>::from_selection(&Selection::new());
+    assert!(arrays.is_empty());
+    assert_eq!(arrays.to_selection(), Ok(Selection::new()));
+}
+
+#[test]
+fn span_arrays_import_nulls_and_openness() {
+    let arrays: SpanArrays<i64> = SpanArrays {
+        starts: Int64Array::from(vec![None, Some(10), Some(20), Some(9)]),
+        ends: Int64Array::from(vec![Some(-5), Some(15), None, Some(10)]),
+        start_closed: BooleanArray::from(vec![Some(true), Some(false), None,
+            Some(false)]),
+        end_closed: BooleanArray::from(vec![Some(false), None, Some(true),
+            Some(false)]),
+    };
+    // The last row is open and contains no points.
+    assert!(arrays.interval(3).is_empty());
+
+    let mut expected = Selection::from(Interval::closed(i64::MIN, -6));
+    expected.union_in_place(Interval::closed(11, 15));
+    expected.union_in_place(Interval::unbounded_from(20));
+    assert_eq!(arrays.to_selection(), Ok(expected));
+
+    let mismatched: SpanArrays<i64> = SpanArrays {
+        ends: Int64Array::from(vec![1]),
+        ..arrays
+    };
+    assert_eq!(mismatched.to_selection(), Err(SpanArraysError));
+}
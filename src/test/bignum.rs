@@ -0,0 +1,103 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `BigInt` and `Decimal` intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::selection::Selection;
+
+// External library imports.
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+
+/// Returns the `BigInt` with the given value.
+fn big(value: i64) -> BigInt {
+    BigInt::from(value)
+}
+
+/// Returns the `Decimal` with the given number of cents.
+fn cents(value: i64) -> Decimal {
+    Decimal::new(value, 2)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalization tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn big_integer_bounds_are_not_normalized() {
+    let interval = Interval::open(big(3), big(7));
+    assert_eq!(interval.lower_bound(), Some(Bound::Exclude(big(3))));
+    assert_eq!(interval.upper_bound(), Some(Bound::Exclude(big(7))));
+    assert!(!Interval::open(big(3), big(4)).is_empty());
+
+    let huge = big(i64::MAX) * big(i64::MAX);
+    let interval = Interval::closed(-huge.clone(), huge.clone());
+    assert!(interval.contains(&big(i64::MIN)));
+    assert_eq!(interval.size(), Some(huge * 2));
+}
+
+#[test]
+fn decimal_buckets_keep_exact_bounds() {
+    let mut buckets = Selection::from(
+        Interval::right_open(cents(0), cents(1000)));
+    buckets.minus_in_place(Interval::point(cents(500)));
+    assert_eq!(buckets.interval_iter().collect::<Vec<_>>(), [
+        Interval::right_open(cents(0), cents(500)),
+        Interval::open(cents(500), cents(1000)),
+    ]);
+    assert!(buckets.contains(&cents(999)));
+    assert!(!buckets.contains(&cents(1000)));
+    // Equal decimals with different scales are the same point.
+    assert!(buckets.contains(&Decimal::new(1, 0)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measure tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn big_integer_measures() {
+    let sel = Selection::from([
+        Interval::closed(big(0), big(10)),
+        Interval::closed(big(20), big(25)),
+    ]);
+    assert_eq!(sel.measure(), Some(big(15)));
+    let other = Selection::from(Interval::closed(big(5), big(20)));
+    assert_eq!(sel.overlap_fraction(&other), Some(1.0 / 3.0));
+    assert_eq!(Selection::<BigInt>::full().measure(), None);
+}
+
+#[test]
+fn decimal_measures() {
+    assert_eq!(Decimal::span(&cents(150), &cents(275)), cents(125));
+    assert_eq!(Decimal::span(&Decimal::MIN, &Decimal::MAX), Decimal::MAX);
+
+    let sel = Selection::from([
+        Interval::right_open(cents(0), cents(250)),
+        Interval::right_open(cents(500), cents(750)),
+    ]);
+    assert_eq!(sel.measure(), Some(cents(500)));
+    assert_eq!(sel.coverage_of(&Interval::closed(cents(0), cents(1000))),
+        Some(0.5));
+
+    let wide = Selection::from([
+        Interval::closed(Decimal::MIN, cents(0)),
+        Interval::closed(cents(1), Decimal::MAX),
+    ]);
+    assert_eq!(wide.measure(), None);
+}
@@ -0,0 +1,72 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for reservation tables.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::booking::Bookings;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Reservation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn reserve_adjacent_and_conflicting() {
+    let mut rooms: Bookings<u8, i32> = Bookings::new();
+    assert!(rooms.try_reserve(1, Interval::right_open(0, 10)).is_ok());
+    assert!(rooms.try_reserve(1, Interval::right_open(10, 20)).is_ok());
+    assert!(rooms.try_reserve(1, Interval::right_open(30, 40)).is_ok());
+
+    let before = rooms.clone();
+    let conflict = rooms
+        .try_reserve(1, Interval::closed(15, 35))
+        .expect_err("overlapping reservation");
+    assert_eq!(conflict.into_conflicts(), Selection::from([
+        Interval::closed(15, 19),
+        Interval::closed(30, 35),
+    ]));
+    assert_eq!(rooms, before);
+
+    assert_eq!(rooms.busy(&1), Some(&Selection::from([
+        Interval::closed(0, 19),
+        Interval::closed(30, 39),
+    ])));
+}
+
+#[test]
+fn reserve_empty_interval() {
+    let mut rooms: Bookings<u8, i32> = Bookings::new();
+    assert!(rooms.try_reserve(1, Interval::empty()).is_ok());
+    assert!(rooms.is_empty());
+    assert!(rooms.busy(&1).is_none());
+}
+
+#[test]
+fn release_and_free_for() {
+    let mut rooms: Bookings<&str, i32> = Bookings::new();
+    assert!(rooms.try_reserve("a", Interval::closed(0, 9)).is_ok());
+    assert!(rooms.try_reserve("b", Interval::closed(5, 14)).is_ok());
+    assert!(rooms.try_reserve("c", Interval::closed(20, 29)).is_ok());
+
+    let morning = Interval::closed(10, 19);
+    assert_eq!(rooms.free_for(&morning).collect::<Vec<_>>(), [&"a", &"c"]);
+
+    assert!(rooms.release(&"b", Interval::closed(10, 14)));
+    assert!(!rooms.release(&"d", Interval::closed(10, 14)));
+    assert_eq!(rooms.free_for(&morning).count(), 3);
+
+    assert!(rooms.release(&"c", Interval::full()));
+    assert!(rooms.busy(&"c").is_none());
+    assert_eq!(rooms.iter().count(), 2);
+}
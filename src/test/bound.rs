@@ -0,0 +1,70 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Bound`].
+//!
+//! [`Bound`]: ../../bound/enum.Bound.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::bound::UpperBound;
+
+
+/// Returns every `Bound` on the points from -3 to 3.
+fn bounds() -> Vec<Bound<i8>> {
+    (-3..=3)
+        .flat_map(|p| [Bound::Include(p), Bound::Exclude(p)])
+        .chain(std::iter::once(Bound::Infinite))
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Ordering tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn bound_orderings_match_contained_points() {
+    // A lower bound is less than another if it contains more points, which
+    // for `i8` is measured by the least contained point.
+    let least = |b: &Bound<i8>| b.next_up().map_or(i16::MIN, i16::from);
+    let greatest = |b: &Bound<i8>| b.next_down().map_or(i16::MAX, i16::from);
+
+    for a in bounds() {
+        for b in bounds() {
+            let (la, lb) = (LowerBound(a), LowerBound(b));
+            let (ua, ub) = (UpperBound(a), UpperBound(b));
+            // `Exclude` and `Include` bounds of adjacent points contain the
+            // same points, but are ordered by their points.
+            if least(&a) != least(&b) {
+                assert_eq!(la.cmp(&lb), least(&a).cmp(&least(&b)));
+            }
+            if greatest(&a) != greatest(&b) {
+                assert_eq!(ua.cmp(&ub), greatest(&a).cmp(&greatest(&b)));
+            }
+            assert_eq!(la == lb, a == b);
+            assert_eq!(ua == ub, a == b);
+        }
+    }
+
+    let mut lower: Vec<_> = bounds().into_iter().map(LowerBound).collect();
+    lower.sort();
+    assert_eq!(lower[0], LowerBound(Bound::Infinite));
+    assert_eq!(lower[1], LowerBound(Bound::Include(-3)));
+    assert_eq!(lower[2], LowerBound(Bound::Exclude(-3)));
+
+    let mut upper: Vec<_> = bounds().into_iter().map(UpperBound).collect();
+    upper.sort();
+    assert_eq!(upper[upper.len() - 1], UpperBound(Bound::Infinite));
+    assert_eq!(upper[upper.len() - 2], UpperBound(Bound::Include(3)));
+    assert_eq!(upper[0], UpperBound(Bound::Exclude(-3)));
+}
@@ -0,0 +1,112 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for CIDR block conversions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::cidr::Ipv4Cidr;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::net::Ipv4Addr;
+
+
+fn cidr(a: u8, b: u8, c: u8, d: u8, len: u8) -> Ipv4Cidr {
+    Ipv4Cidr::new(Ipv4Addr::new(a, b, c, d), len).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Ipv4Cidr tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn cidr_construction() {
+    assert_eq!(cidr(10, 1, 2, 3, 8).address(), Ipv4Addr::new(10, 0, 0, 0));
+    assert_eq!(cidr(10, 1, 2, 3, 0).address(), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(cidr(10, 1, 2, 3, 32).address(), Ipv4Addr::new(10, 1, 2, 3));
+    assert_eq!(Ipv4Cidr::new(Ipv4Addr::LOCALHOST, 33), None);
+
+    assert_eq!(cidr(0, 0, 0, 0, 0).interval(), Interval::full());
+    assert_eq!(cidr(10, 1, 2, 3, 32).interval(),
+        Interval::point(Ipv4Addr::new(10, 1, 2, 3)));
+    assert_eq!(Ipv4Cidr::from(Ipv4Addr::LOCALHOST), cidr(127, 0, 0, 1, 32));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn to_cidrs_edge_cases() {
+    assert!(Selection::<Ipv4Addr>::empty().to_cidrs().is_empty());
+    assert_eq!(Selection::<Ipv4Addr>::full().to_cidrs(),
+        [cidr(0, 0, 0, 0, 0)]);
+
+    let everything_but_broadcast = Selection::from(
+        Interval::right_open(Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST));
+    let cidrs = everything_but_broadcast.to_cidrs();
+    assert_eq!(cidrs.len(), 32);
+    assert_eq!(cidrs.first(), Some(&cidr(0, 0, 0, 0, 1)));
+    assert_eq!(cidrs.last(), Some(&cidr(255, 255, 255, 254, 32)));
+}
+
+#[test]
+fn cidrs_round_trip() {
+    let mut sel = Selection::from_cidrs([
+        cidr(10, 0, 0, 0, 8),
+        cidr(172, 16, 0, 0, 12),
+        cidr(192, 168, 0, 0, 16),
+    ]);
+    sel.minus_in_place(cidr(10, 20, 0, 0, 14).interval());
+    sel.minus_in_place(Interval::closed(
+        Ipv4Addr::new(172, 16, 3, 7),
+        Ipv4Addr::new(172, 17, 0, 200)));
+
+    let cidrs = sel.to_cidrs();
+    assert_eq!(Selection::from_cidrs(cidrs.iter().copied()), sel);
+    assert!(cidrs.windows(2).all(|w|
+        w[0].interval().supremum() < w[1].interval().infimum()));
+    // No two blocks can be merged into their parent block.
+    assert!(cidrs.windows(2).all(|w| w[0].prefix_len() != w[1].prefix_len()
+        || cidr_parent(w[0]) != cidr_parent(w[1])));
+}
+
+fn cidr_parent(block: Ipv4Cidr) -> Option<Ipv4Cidr> {
+    block.prefix_len()
+        .checked_sub(1)
+        .and_then(|len| Ipv4Cidr::new(block.address(), len))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Serialization tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[cfg(feature="serde")]
+fn cidr_deserialize_validates() {
+    let block = cidr(10, 1, 0, 0, 16);
+    let json = serde_json::to_string(&block).unwrap();
+    assert_eq!(json, r#"{"address":"10.1.0.0","prefix_len":16}"#);
+    assert_eq!(serde_json::from_str::<Ipv4Cidr>(&json).unwrap(), block);
+
+    // Host bits are cleared, as by `Ipv4Cidr::new`.
+    let block: Ipv4Cidr = serde_json::from_str(
+        r#"{"address":"10.1.2.3","prefix_len":16}"#).unwrap();
+    assert_eq!(block, cidr(10, 1, 0, 0, 16));
+    assert_eq!(block.address(), Ipv4Addr::new(10, 1, 0, 0));
+
+    let error = serde_json::from_str::<Ipv4Cidr>(
+        r#"{"address":"10.1.2.3","prefix_len":33}"#).unwrap_err();
+    assert_eq!(error.to_string(), "CIDR prefix length greater than 32");
+}
@@ -0,0 +1,169 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the `Interval` operation corpus.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval::corpus;
+use crate::interval::corpus::CORPUS;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::fmt::Write;
+use std::net::Ipv4Addr;
+
+
+/// Set this environment variable to rewrite the corpus file from the current
+/// results.
+const BLESS_VAR: &str = "NORMALIZE_INTERVAL_BLESS";
+
+/// Returns every distinct interval bounded at the corpus operand points.
+fn operands() -> Vec<RawInterval<i32>> {
+    let mut bounds = vec![Bound::Infinite];
+    for p in [1, 3] {
+        bounds.push(Bound::Include(p));
+        bounds.push(Bound::Exclude(p));
+    }
+    let mut operands: Vec<RawInterval<i32>> = Vec::new();
+    for lower in &bounds {
+        for upper in &bounds {
+            let interval = RawInterval::new(*lower, *upper);
+            if !operands.contains(&interval) {
+                operands.push(interval);
+            }
+        }
+    }
+    operands
+}
+
+/// Writes a normalized interval in the corpus notation, with the extreme
+/// points written as infinite bounds.
+fn render(interval: &Interval<i32>) -> String {
+    let (Some(lower), Some(upper)) = (
+        interval.lower_bound(),
+        interval.upper_bound()) else {
+            return RawInterval::<i32>::Empty.to_string()
+        };
+    let lower = match lower {
+        Bound::Include(i32::MIN) => Bound::Infinite,
+        bound => bound,
+    };
+    let upper = match upper {
+        Bound::Include(i32::MAX) => Bound::Infinite,
+        bound => bound,
+    };
+    RawInterval::new(lower, upper).to_string()
+}
+
+/// Appends a corpus case with the given results.
+fn case<I>(text: &mut String, operands: &str, results: I)
+    where I: IntoIterator<Item=String>
+{
+    text.push_str(operands);
+    text.push_str(" =");
+    for result in results {
+        text.push(' ');
+        text.push_str(&result);
+    }
+    text.push('\n');
+}
+
+/// Generates the corpus from the results for `i32`.
+fn generate() -> String {
+    let mut text = String::from("\
+        # Results of the `Interval` operations for `i32`, regenerated and \
+        checked by the crate's tests.\n\
+        # See the `normalize_interval::interval::corpus` module for the \
+        format.\n");
+    let operands = operands();
+    for a in &operands {
+        let ia = Interval::from(*a);
+        text.push('\n');
+        case(&mut text,
+            &format!("complement {}", a),
+            ia.complement().map(|i| render(&i)));
+        for b in &operands {
+            let ib = Interval::from(*b);
+            let ops = format!("{} {}", a, b);
+            case(&mut text,
+                &format!("intersect {}", ops),
+                Some(render(&ia.intersect(&ib))));
+            case(&mut text,
+                &format!("union {}", ops),
+                ia.union(&ib).map(|i| render(&i)));
+            case(&mut text,
+                &format!("minus {}", ops),
+                ia.minus(&ib).map(|i| render(&i)));
+            case(&mut text,
+                &format!("enclose {}", ops),
+                Some(render(&ia.enclose(&ib))));
+            let _ = writeln!(text, "intersects {} = {}",
+                ops, ia.intersects(&ib));
+            let _ = writeln!(text, "is_adjacent_to {} = {}",
+                ops, ia.is_adjacent_to(&ib));
+        }
+    }
+    text
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Corpus tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn corpus_matches_generated_results() {
+    let generated = generate();
+    if std::env::var_os(BLESS_VAR).is_some() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"),
+            "/src/interval/corpus.txt");
+        std::fs::write(path, &generated).expect("write corpus");
+        return;
+    }
+    for (line, (expected, actual)) in CORPUS.lines()
+        .zip(generated.lines())
+        .enumerate()
+    {
+        assert_eq!(expected, actual,
+            "corpus line {} changed; rerun with {} set to regenerate",
+            line + 1, BLESS_VAR);
+    }
+    assert_eq!(CORPUS.lines().count(), generated.lines().count());
+}
+
+#[test]
+fn corpus_points_are_in_range() {
+    let points = || CORPUS.lines()
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(|line| line
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse::<u8>().unwrap()));
+    assert!(points().all(|p| corpus::POINTS.contains(&p)));
+    assert_eq!(points().min(), Some(*corpus::POINTS.start()));
+    assert_eq!(points().max(), Some(*corpus::POINTS.end()));
+}
+
+#[test]
+fn corpus_checks_finite_types() {
+    assert_eq!(corpus::check(i32::from), Ok(()));
+    assert_eq!(corpus::check(|p| i64::from(p) - 3), Ok(()));
+    assert_eq!(corpus::check(|p| p + 1), Ok(()));
+    assert_eq!(corpus::check(|p| Ipv4Addr::new(10, 0, 0, p)), Ok(()));
+
+    // The minimum point must lie above `T::MINIMUM`.
+    let mismatch = corpus::check(|p| p).unwrap_err();
+    assert!(mismatch.case.contains("-∞"));
+    // Consecutive points must be consecutive.
+    assert!(corpus::check(|p| i32::from(p) * 2).is_err());
+}
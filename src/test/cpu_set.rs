@@ -0,0 +1,121 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for CPU mask and CPU list conversions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::cpu_set::CpuSetParseError;
+use crate::cpu_set::MAX_CPUS;
+use crate::cpu_set::cpu_list;
+use crate::cpu_set::cpu_mask;
+use crate::cpu_set::parse_cpu_list;
+use crate::cpu_set::parse_cpu_mask;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CPU mask tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn mask_word_boundaries() {
+    let cpus = parse_cpu_mask("0x1,80000000,ffffffff\n")
+        .expect("successful parse");
+
+    assert_eq!(cpus.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 31),
+        Interval::closed(63, 64),
+    ]);
+    assert_eq!(cpu_mask(&cpus).as_deref(), Some("1,80000000,ffffffff"));
+}
+
+#[test]
+fn mask_round_trip() {
+    for mask in ["0", "1", "f0", "80000000", "3,00000000", "ff,0000000f"] {
+        let cpus = parse_cpu_mask(mask).expect("successful parse");
+        assert_eq!(cpu_mask(&cpus).as_deref(), Some(mask));
+    }
+
+    let cpus = parse_cpu_mask("00000000,0000000f").expect("successful parse");
+    assert_eq!(cpu_mask(&cpus).as_deref(), Some("f"));
+    assert_eq!(cpu_mask(&Selection::new()).as_deref(), Some("0"));
+}
+
+#[test]
+fn mask_errors() {
+    assert_eq!(parse_cpu_mask(""), Err(CpuSetParseError::InvalidMask));
+    assert_eq!(parse_cpu_mask("f,,f"), Err(CpuSetParseError::InvalidMask));
+    assert_eq!(parse_cpu_mask("fg"), Err(CpuSetParseError::InvalidMask));
+    assert_eq!(parse_cpu_mask("100000000"),
+        Err(CpuSetParseError::InvalidMask));
+}
+
+#[test]
+fn mask_limit() {
+    let last = Selection::from(Interval::point(MAX_CPUS - 1));
+    let mask = cpu_mask(&last).expect("mask within limit");
+    assert_eq!(mask.len(), 8 + 9 * (MAX_CPUS / 32 - 1) as usize);
+    assert_eq!(parse_cpu_mask(&mask), Ok(last));
+
+    assert_eq!(cpu_mask(&Selection::from(Interval::point(MAX_CPUS))), None);
+    assert_eq!(cpu_mask(&Selection::full()), None);
+}
+
+#[test]
+fn mask_matches_list() {
+    let cpus = parse_cpu_list("0-2,5,31-33,40-47").expect("successful parse");
+    let mask = cpu_mask(&cpus).expect("mask within limit");
+
+    assert_eq!(mask, "ff03,80000027");
+    assert_eq!(parse_cpu_mask(&mask), Ok(cpus));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CPU list tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn list_strides() {
+    let cpus = parse_cpu_list("0-9:3/4, 20").expect("successful parse");
+
+    assert_eq!(cpus.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 2),
+        Interval::closed(4, 6),
+        Interval::closed(8, 9),
+        Interval::point(20),
+    ]);
+    assert_eq!(cpu_list(&cpus), "0-2,4-6,8-9,20");
+
+    let cpus = parse_cpu_list("4294967290-4294967295:1/4")
+        .expect("successful parse");
+    assert_eq!(cpu_list(&cpus), "4294967290,4294967294");
+}
+
+#[test]
+fn list_round_trip() {
+    for list in ["", "0", "0-3,8", "1,3,5-7", "0-4294967295"] {
+        let cpus = parse_cpu_list(list).expect("successful parse");
+        assert_eq!(cpu_list(&cpus), list);
+    }
+}
+
+#[test]
+fn list_errors() {
+    assert_eq!(parse_cpu_list("0,,1"), Err(CpuSetParseError::InvalidItem));
+    assert_eq!(parse_cpu_list("3-1"), Err(CpuSetParseError::InvalidItem));
+    assert_eq!(parse_cpu_list("0-7:4/2"), Err(CpuSetParseError::InvalidItem));
+    assert_eq!(parse_cpu_list("0-7:0/2"), Err(CpuSetParseError::InvalidItem));
+    assert_eq!(parse_cpu_list("0-7:2"), Err(CpuSetParseError::InvalidItem));
+    assert!(matches!(parse_cpu_list("0-x"),
+        Err(CpuSetParseError::InvalidValue(_))));
+}
@@ -0,0 +1,74 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for flat selection encoding.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::flat::FlatSelectionError;
+use crate::flat::SelectionView;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionView tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn view_matches_selection() {
+    let mut sel: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10 - 200, i * 10 - 196))
+        .collect();
+    sel.union_in_place(Interval::point(i32::MAX));
+    sel.minus_in_place(Interval::point(-198));
+    let bytes = sel.serialize_flat();
+
+    let view: SelectionView<'_, i32> = SelectionView::new(&bytes).unwrap();
+    assert_eq!(view.len(), 42);
+    assert_eq!(view.to_selection(), sel);
+    for point in -210..210 {
+        assert_eq!(view.contains(&point), sel.contains(&point));
+        assert_eq!(
+            view.interval_containing(&point),
+            sel.interval_iter().find(|i| i.contains(&point)));
+    }
+    assert!(view.contains(&i32::MAX));
+    assert!(!view.contains(&i32::MIN));
+}
+
+#[test]
+fn view_of_empty_and_full_selections() {
+    let bytes = Selection::<u8>::empty().serialize_flat();
+    let view: SelectionView<'_, u8> = SelectionView::new(&bytes).unwrap();
+    assert!(view.is_empty());
+    assert!(!view.contains(&0));
+
+    let bytes = Selection::<u8>::full().serialize_flat();
+    assert_eq!(bytes, [0, 255]);
+    let view: SelectionView<'_, u8> = SelectionView::new(&bytes).unwrap();
+    assert_eq!(view.interval_containing(&7), Some(Interval::full()));
+}
+
+#[test]
+fn view_rejects_invalid_encodings() {
+    assert_eq!(
+        SelectionView::<u16>::new(&[1, 0, 2]).unwrap_err(),
+        FlatSelectionError::InvalidLength);
+    // An upper point below its lower point.
+    assert_eq!(
+        SelectionView::<u8>::new(&[5, 4]).unwrap_err(),
+        FlatSelectionError::Unordered);
+    // Intervals sharing a point.
+    assert_eq!(
+        SelectionView::<u8>::new(&[1, 4, 4, 6]).unwrap_err(),
+        FlatSelectionError::Unordered);
+    assert!(SelectionView::<u8>::new(&[1, 4, 5, 6]).is_ok());
+}
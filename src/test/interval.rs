@@ -0,0 +1,1014 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Interval`].
+//!
+//! [`Interval`] struct.Interval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::algorithms::Origin;
+use crate::interval::algorithms::intersect_sorted_streams;
+use crate::interval::algorithms::pairwise_intersections;
+use crate::interval::algorithms::union_sorted_streams;
+use crate::interval::Interval;
+use crate::interval::NormalizationError;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iteration tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn iter_unnormalized_forward() {
+    let interval: Interval<i32> = Interval(RawInterval::Open(3, 7));
+    assert_eq!(interval.iter().collect::<Vec<_>>(), [4, 5, 6]);
+}
+
+#[test]
+fn iter_unnormalized_backward() {
+    let interval: Interval<i32> = Interval(RawInterval::LeftOpen(3, 7));
+    assert_eq!(interval.iter().rev().collect::<Vec<_>>(), [7, 6, 5, 4]);
+}
+
+#[test]
+fn iter_unnormalized_empty() {
+    let interval: Interval<i32> = Interval(RawInterval::Open(3, 4));
+    assert_eq!(interval.iter().next(), None);
+    assert_eq!(interval.iter().next_back(), None);
+}
+
+#[test]
+fn points_unnormalized() {
+    let interval: Interval<i32> = Interval(RawInterval::Open(3, 7));
+    assert_eq!(interval.points().err(), Some(NormalizationError));
+}
+
+#[test]
+fn iter_from_matches_skipped_iter() {
+    let intervals: Vec<Interval<i8>> = vec![
+        Interval::empty(),
+        Interval::full(),
+        Interval::point(3),
+        Interval::open(-5, 9),
+        Interval::closed(i8::MIN, -100),
+        Interval::unbounded_from(120),
+    ];
+    for interval in &intervals {
+        for point in [i8::MIN, -101, -5, -4, 0, 3, 8, 9, 120, i8::MAX] {
+            assert_eq!(
+                interval.iter_from(point).collect::<Vec<_>>(),
+                interval.iter().filter(|p| *p >= point).collect::<Vec<_>>());
+            assert_eq!(
+                interval.iter_rev_from(point).collect::<Vec<_>>(),
+                interval.iter().rev().filter(|p| *p <= point)
+                    .collect::<Vec<_>>());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalization tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn normalize_meeting_bounds() {
+    assert_eq!(Interval::open(3, 5), Interval::point(4));
+    assert_eq!(Interval::left_open(3, 4), Interval::point(4));
+    assert_eq!(Interval::right_open(3, 4), Interval::point(3));
+    assert_eq!(Interval::unbounded_up_to(i32::MIN + 1),
+        Interval::point(i32::MIN));
+}
+
+#[test]
+fn normalize_crossing_bounds() {
+    assert_eq!(Interval::<i32>::open(3, 4), Interval::empty());
+    assert_eq!(Interval::open(3, 4).iter().next(), None);
+}
+
+#[test]
+fn dense_points_are_not_normalized() {
+    use crate::normalize::Dense;
+    let interval = Interval::open(Dense(3), Dense(7));
+    assert_eq!(interval.0, RawInterval::Open(Dense(3), Dense(7)));
+    assert_eq!(Interval::open(Dense(3), Dense(4)).0,
+        RawInterval::Open(Dense(3), Dense(4)));
+    assert_eq!(Interval::open(Dense(3), Dense(3)), Interval::empty());
+    assert_eq!("(3,7)".parse::<RawInterval<Dense<i32>>>(), Ok(interval.0));
+
+    let below_min = Interval::unbounded_up_to(Dense(i32::MIN));
+    assert!(!below_min.is_empty());
+    assert!(!below_min.contains(&Dense(i32::MIN)));
+    assert_eq!(below_min.lower_bound(), Some(Bound::Infinite));
+    assert!(Interval::unbounded_from(Dense(i32::MAX))
+        .contains(&Dense(i32::MAX)));
+
+    let mut sel = Selection::from(Interval::closed(Dense(0), Dense(10)));
+    sel.minus_in_place(Interval::open(Dense(3), Dense(4)));
+    assert!(sel.contains(&Dense(3)));
+    assert!(sel.contains(&Dense(4)));
+    sel.minus_in_place(Interval::point(Dense(3)));
+    assert!(!sel.contains(&Dense(3)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), vec![
+        Interval::right_open(Dense(0), Dense(3)),
+        Interval::closed(Dense(4), Dense(10)),
+    ]);
+    assert_eq!(sel.complement().interval_iter().collect::<Vec<_>>(), vec![
+        Interval::unbounded_up_to(Dense(0)),
+        Interval::right_open(Dense(3), Dense(4)),
+        Interval::unbounded_up_from(Dense(10)),
+    ]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Constructor tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn above_below_extremes() {
+    assert_eq!(Interval::above(i8::MAX - 1), Interval::point(i8::MAX));
+    assert_eq!(Interval::below(i8::MIN + 1), Interval::point(i8::MIN));
+    assert_eq!(Interval::<i8>::above(i8::MAX), Interval::empty());
+    assert_eq!(Interval::<i8>::below(i8::MIN), Interval::empty());
+}
+
+#[test]
+fn constants_match_constructors() {
+    assert_eq!(Interval::<i8>::EMPTY, Interval::empty());
+    assert_eq!(Interval::<i8>::FULL_CLOSED, Interval::full());
+    assert_eq!(Interval::<u64>::FULL_CLOSED, Interval::full());
+    assert!(matches!(Interval::closed(3_i8, 2), Interval::EMPTY));
+    assert_eq!(Selection::<i8>::EMPTY, Selection::new());
+    assert_eq!(Selection::from(Interval::<i8>::FULL_CLOSED), Selection::full());
+}
+
+#[test]
+fn is_full_matches_selection() {
+    for interval in [
+        Interval::<i8>::full(),
+        Interval::closed(i8::MIN, i8::MAX),
+        Interval::unbounded_from(i8::MIN),
+        Interval::closed(i8::MIN, i8::MAX - 1),
+        Interval::open(i8::MIN, i8::MAX),
+        Interval::point(0),
+        Interval::empty(),
+    ] {
+        assert_eq!(interval.is_full(), Selection::from(interval).is_full());
+    }
+    assert!(Interval::<i32>::full().is_full());
+    assert!(!Interval::<i32>::unbounded_from(0).is_full());
+}
+
+#[test]
+fn from_bounds_lenient_resolves_conflicts() {
+    use crate::interval::BoundConflict::*;
+    use Bound::*;
+    let conflicts = [
+        (Include(3), Exclude(3)),
+        (Exclude(3), Include(3)),
+        (Exclude(3), Exclude(3)),
+    ];
+    for (lower, upper) in conflicts {
+        assert_eq!(Interval::from_bounds_lenient(lower, upper, PreferPoint),
+            Interval::point(3));
+        assert_eq!(Interval::from_bounds_lenient(lower, upper, PreferEmpty),
+            Interval::empty());
+    }
+    assert_eq!(Interval::new(Include(3), Exclude(3)), Interval::point(3));
+    assert_eq!(Interval::new(Exclude(3), Exclude(3)), Interval::empty());
+
+    for policy in [PreferPoint, PreferEmpty] {
+        assert_eq!(Interval::from_bounds_lenient(
+                Include(3), Include(3), policy),
+            Interval::point(3));
+        assert_eq!(Interval::from_bounds_lenient(
+                Exclude(3), Exclude(7), policy),
+            Interval::closed(4, 6));
+        assert_eq!(Interval::from_bounds_lenient(
+                Include(7), Include(3), policy),
+            Interval::empty());
+        assert_eq!(Interval::<i32>::from_bounds_lenient(
+                Infinite, Infinite, policy),
+            Interval::full());
+    }
+}
+
+#[test]
+fn enclose_points_and_intervals() {
+    let points = [5_i8, -3, 9, i8::MIN, 0];
+    assert_eq!(Interval::enclose_points(points.iter().copied()),
+        Interval::closed(i8::MIN, 9));
+    assert_eq!(Interval::enclose_points(Some(4_i8)), Interval::point(4));
+    assert_eq!(Interval::<i8>::enclose_points(None), Interval::empty());
+
+    let intervals: Vec<Interval<i8>> = points
+        .iter()
+        .map(|&p| Interval::open(p, p.saturating_add(2)))
+        .collect();
+    assert_eq!(Interval::enclose_intervals(intervals),
+        Interval::closed(i8::MIN + 1, 10));
+    assert_eq!(Interval::enclose_intervals(vec![Interval::<i8>::empty()]),
+        Interval::empty());
+    assert_eq!(Interval::<i8>::enclose_intervals(None), Interval::empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Measure tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn size_measured_length() {
+    use crate::measure::Measurable;
+    use std::time::Duration;
+    use std::time::Instant;
+    let start = Instant::now();
+    let end = start + Duration::from_secs(3);
+    assert_eq!(Instant::span(&start, &end), Duration::from_secs(3));
+    assert_eq!(Duration::span(&Duration::from_secs(2),
+        &Duration::from_secs(5)), Duration::from_secs(3));
+    assert_eq!(Interval::<i32>::closed(-3, 7).size(), Some(10));
+    assert_eq!(Interval::<i8>::full().size(), Some(u8::MAX));
+    assert_eq!(Interval::<i32>::empty().size(), None);
+}
+
+#[test]
+fn extent_counts_finite_points() {
+    use crate::measure::Measurable;
+    use std::time::Duration;
+    use std::time::Instant;
+    assert_eq!(u32::extent(&3, &3), Some(1));
+    assert_eq!(i8::extent(&-128, &126), Some(u8::MAX));
+    assert_eq!(i8::extent(&-128, &127), None);
+    assert_eq!(Duration::extent(&Duration::ZERO, &Duration::from_secs(1)),
+        Some(Duration::from_nanos(1_000_000_001)));
+
+    // Points of continuous types have no extent.
+    let now = Instant::now();
+    assert_eq!(Instant::extent(&now, &now), Some(Duration::ZERO));
+}
+
+#[test]
+fn size_of_time_intervals() {
+    use std::time::Duration;
+    use std::time::Instant;
+    use std::time::SystemTime;
+    let start = Instant::now();
+    let end = start + Duration::from_secs(3);
+    let interval = Interval::open(start, end);
+    assert_eq!(interval.lower_bound(), Some(Bound::Exclude(start)));
+    assert_eq!(interval.size(), Some(Duration::from_secs(3)));
+
+    let epoch = SystemTime::UNIX_EPOCH;
+    let sel = Selection::from([
+        Interval::right_open(epoch, epoch + Duration::from_secs(2)),
+        Interval::closed(
+            epoch + Duration::from_secs(5),
+            epoch + Duration::from_secs(6)),
+    ]);
+    assert_eq!(sel.measure(), Some(Duration::from_secs(3)));
+}
+
+#[test]
+fn has_at_least_matches_point_count() {
+    let intervals: Vec<Interval<i8>> = vec![
+        Interval::empty(),
+        Interval::point(5),
+        Interval::closed(-3, 7),
+        Interval::closed(-100, 100),
+        Interval::unbounded_to(0),
+        Interval::full(),
+    ];
+    for interval in intervals {
+        let count = interval.iter().count();
+        for n in [0, 1, 2, 10, 11, 12, 127, 128, 129, 201, 256, 257] {
+            assert_eq!(interval.has_at_least(n), count >= n);
+        }
+        for width in [0, 1, 10, 11, 100, 127, 128, u8::MAX] {
+            assert_eq!(interval.width_at_least(&width),
+                count > usize::from(width));
+        }
+    }
+    assert!(Interval::<u128>::full().has_at_least(usize::MAX));
+    assert!(!Interval::<u128>::point(0).has_at_least(2));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Alignment tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn aligned_outward_extremes() {
+    assert_eq!(Interval::closed(-128i8, -120).aligned_outward(&100),
+        Interval::closed(i8::MIN, -101));
+    assert_eq!(Interval::closed(101u8, 250).aligned_outward(&100),
+        Interval::closed(100, u8::MAX));
+    assert_eq!(Interval::point(5i32).aligned_outward(&1), Interval::point(5));
+    assert_eq!(Interval::<i32>::empty().aligned_outward(&4), Interval::empty());
+}
+
+#[test]
+fn aligned_inward_extremes() {
+    assert_eq!(Interval::closed(0u8, u8::MAX).aligned_inward(&16),
+        Interval::closed(0, u8::MAX));
+    assert_eq!(Interval::closed(0u8, u8::MAX).aligned_inward(&100),
+        Interval::closed(0, 199));
+    assert_eq!(Interval::closed(-7i32, 7).aligned_inward(&4),
+        Interval::closed(-4, 7));
+    assert_eq!(Interval::closed(1i32, 2).aligned_inward(&4), Interval::empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reflection and negation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn reflected_matches_points() {
+    let intervals = [
+        Interval::closed(-128i8, -100),
+        Interval::closed(-20, 40),
+        Interval::closed(90, 127),
+        Interval::point(0),
+        Interval::full(),
+        Interval::empty(),
+    ];
+    for interval in &intervals {
+        for center in [-128i8, -64, -1, 0, 1, 63, 127] {
+            let reflected = interval.reflected(&center);
+            for point in i8::MIN..=i8::MAX {
+                let mirror = i16::from(center) * 2 - i16::from(point);
+                let expected = i8::try_from(mirror)
+                    .is_ok_and(|p| interval.contains(&p));
+                assert_eq!(reflected.contains(&point), expected);
+            }
+        }
+    }
+    assert_eq!(Interval::closed(250u8, 255).reflected(&0), Interval::empty());
+    assert_eq!(Interval::closed(0u8, 5).reflected(&255), Interval::empty());
+}
+
+#[test]
+fn neg_swaps_bounds() {
+    assert_eq!(-Interval::closed(-3i32, 7), Interval::closed(-7, 3));
+    assert_eq!(-Interval::right_open(0i32, 10), Interval::closed(-9, 0));
+    assert_eq!(-Interval::unbounded_from(5i8), Interval::closed(i8::MIN + 1, -5));
+    assert_eq!(-Interval::point(i8::MIN), Interval::empty());
+    assert_eq!(-Interval::<i64>::empty(), Interval::empty());
+}
+
+#[test]
+fn abs_matches_points() {
+    let intervals = [
+        Interval::closed(-128i8, -100),
+        Interval::closed(-20, 40),
+        Interval::closed(-40, 20),
+        Interval::closed(90, 127),
+        Interval::point(0),
+        Interval::full(),
+        Interval::empty(),
+    ];
+    for interval in &intervals {
+        let abs = interval.abs();
+        for point in i8::MIN..=i8::MAX {
+            let expected = point >= 0
+                && (interval.contains(&point) || interval.contains(&-point));
+            assert_eq!(abs.contains(&point), expected);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Modular reduction tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn rem_euclid_matches_points() {
+    let intervals = [
+        Interval::closed(-128i8, -100),
+        Interval::closed(-20, 40),
+        Interval::closed(-3, 2),
+        Interval::closed(90, 127),
+        Interval::point(-1),
+        Interval::full(),
+        Interval::empty(),
+    ];
+    for interval in &intervals {
+        for modulus in [1i8, 2, 7, 16, 100, 127] {
+            let rem = interval.rem_euclid(modulus);
+            for residue in i8::MIN..=i8::MAX {
+                let expected = (i8::MIN..=i8::MAX).any(|p|
+                    interval.contains(&p) && p.rem_euclid(modulus) == residue);
+                assert_eq!(rem.contains(&residue), expected);
+            }
+        }
+    }
+    assert_eq!(Interval::closed(0u8, u8::MAX).rem_euclid(u8::MAX),
+        Selection::from(Interval::closed(0, 254)));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Partition tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn split_by_edges() {
+    let a: Interval<i32> = Interval::closed(0, 10);
+    assert_eq!(a.split_by(&Interval::closed(-5, 3)),
+        (Interval::empty(), Interval::closed(0, 3), Interval::closed(4, 10)));
+    assert_eq!(a.split_by(&Interval::closed(20, 30)),
+        (a, Interval::empty(), Interval::empty()));
+    assert_eq!(a.split_by(&Interval::unbounded_from(5)),
+        (Interval::closed(0, 4), Interval::closed(5, 10), Interval::empty()));
+    assert_eq!(a.split_by(&Interval::empty()),
+        (a, Interval::empty(), Interval::empty()));
+    let low: Interval<i32> = Interval::closed(i32::MIN, 0);
+    assert_eq!(low.split_by(&Interval::point(i32::MIN)),
+        (Interval::empty(), Interval::point(i32::MIN),
+            Interval::closed(i32::MIN + 1, 0)));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Algorithm tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn pairwise_intersections_disjoint_inputs() {
+    let a = vec![Interval::closed(0, 3), Interval::closed(10, 12)];
+    let b = vec![Interval::empty(), Interval::closed(5, 6)];
+    assert_eq!(pairwise_intersections(a, b).collect::<Vec<_>>(), [
+        (Interval::closed(0, 3), Origin::A),
+        (Interval::closed(5, 6), Origin::B),
+        (Interval::closed(10, 12), Origin::A),
+    ]);
+}
+
+#[test]
+fn pairwise_intersections_nested_inputs() {
+    let a = vec![Interval::closed(0, 20)];
+    let b = vec![
+        Interval::closed(2, 4),
+        Interval::point(8),
+        Interval::closed(18, 30),
+    ];
+    assert_eq!(pairwise_intersections(a, b).collect::<Vec<_>>(), [
+        (Interval::closed(0, 1), Origin::A),
+        (Interval::closed(2, 4), Origin::Both),
+        (Interval::closed(5, 7), Origin::A),
+        (Interval::point(8), Origin::Both),
+        (Interval::closed(9, 17), Origin::A),
+        (Interval::closed(18, 20), Origin::Both),
+        (Interval::closed(21, 30), Origin::B),
+    ]);
+}
+
+#[test]
+fn pairwise_intersections_empty_inputs() {
+    let none: Vec<Interval<i32>> = Vec::new();
+    assert_eq!(pairwise_intersections(none.clone(), none.clone()).count(), 0);
+    assert_eq!(pairwise_intersections(vec![Interval::full()], none)
+            .collect::<Vec<_>>(),
+        [(Interval::full(), Origin::A)]);
+}
+
+#[test]
+fn sorted_streams_match_selection() {
+    let streams: Vec<Vec<Interval<i32>>> = vec![
+        vec![Interval::closed(0, 10), Interval::closed(14, 20),
+            Interval::unbounded_from(40)],
+        vec![Interval::closed(-5, 2), Interval::point(4),
+            Interval::closed(6, 15), Interval::closed(19, 45)],
+        vec![Interval::empty(), Interval::closed(1, 50)],
+    ];
+    let selections: Vec<Selection<i32>> = streams
+        .iter()
+        .map(|stream| stream.iter().copied().collect())
+        .collect();
+
+    let union = selections.iter()
+        .fold(Selection::new(), |acc, sel| acc.union(sel));
+    assert_eq!(union_sorted_streams(streams.clone()).collect::<Vec<_>>(),
+        union.interval_iter().collect::<Vec<_>>());
+
+    let intersection = selections.iter()
+        .fold(Selection::full(), |acc, sel| acc.intersect(sel));
+    assert_eq!(intersect_sorted_streams(streams).collect::<Vec<_>>(),
+        intersection.interval_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn sorted_streams_degenerate() {
+    let none: Vec<Vec<Interval<i32>>> = Vec::new();
+    assert_eq!(union_sorted_streams(none.clone()).count(), 0);
+    assert_eq!(intersect_sorted_streams(none).count(), 0);
+    let one_empty = vec![vec![Interval::closed(0, 4)], Vec::new()];
+    assert_eq!(intersect_sorted_streams(one_empty.clone()).count(), 0);
+    assert_eq!(union_sorted_streams(one_empty).collect::<Vec<_>>(),
+        [Interval::closed(0, 4)]);
+}
+
+#[test]
+fn sorted_streams_order_by_lower_bound() {
+    use crate::normalize::Dense;
+    // Included lower bounds precede excluded ones at the same point.
+    let streams = vec![
+        vec![Interval::open(Dense(3), Dense(5)),
+            Interval::open(Dense(7), Dense(9))],
+        vec![Interval::point(Dense(3)), Interval::unbounded_from(Dense(9))],
+        vec![Interval::unbounded_to(Dense(-1))],
+    ];
+    assert_eq!(union_sorted_streams(streams).collect::<Vec<_>>(), [
+        Interval::unbounded_to(Dense(-1)),
+        Interval::right_open(Dense(3), Dense(5)),
+        Interval::unbounded_up_from(Dense(7)),
+    ]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Macro tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn interval_macro_forms() {
+    let empty: Interval<i32> = crate::interval!();
+    assert_eq!(empty, Interval::empty());
+    assert_eq!(crate::interval!(=5), Interval::point(5));
+    assert_eq!(crate::interval!(5), Interval::point(5));
+    assert_eq!(crate::interval!(-3..7), Interval::right_open(-3, 7));
+    assert_eq!(crate::interval!(-3..=7), Interval::closed(-3, 7));
+    let mut exhausted = 3..=3;
+    assert_eq!(exhausted.next(), Some(3));
+    assert_eq!(crate::interval!(exhausted), Interval::empty());
+    assert_eq!(crate::interval!(3..), Interval::unbounded_from(3));
+    assert_eq!(crate::interval!(..9), Interval::unbounded_up_to(9));
+    assert_eq!(crate::interval!(..=9), Interval::unbounded_to(9));
+    let full: Interval<i32> = crate::interval!(..);
+    assert_eq!(full, Interval::full());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Start and length tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn start_len_edge_cases() {
+    assert_eq!(Interval::from_start_len(0, 1), Interval::point(0));
+    assert_eq!(Interval::from_start_len(7, 0), Interval::empty());
+    assert_eq!(Interval::from_start_len(usize::MAX, 1),
+        Interval::point(usize::MAX));
+    assert_eq!(Interval::from_start_len(usize::MAX - 1, 5),
+        Interval::closed(usize::MAX - 1, usize::MAX));
+
+    assert_eq!(Interval::point(7usize).to_start_len(), Some((7, 1)));
+    assert_eq!(Interval::open(2usize, 5).to_start_len(), Some((3, 2)));
+    assert_eq!(Interval::unbounded_from(usize::MAX - 1).to_start_len(),
+        Some((usize::MAX - 1, 2)));
+    assert_eq!(Interval::<usize>::full().to_start_len(),
+        Some((0, usize::MAX)));
+    assert_eq!(Interval::from_start_len(7, 0).to_start_len(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Image tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn image_of_monotone_functions() {
+    use crate::interval::Monotonicity;
+    let interval: Interval<i32> = Interval::open(0, 10);
+
+    assert_eq!(interval.image(|x| i64::from(*x) * 3, Monotonicity::Increasing),
+        Interval::closed(3, 27));
+    assert_eq!(interval.image(|x| -x, Monotonicity::Decreasing),
+        Interval::closed(-9, -1));
+    assert_eq!(interval.image(|x| x / 3, Monotonicity::NonDecreasing),
+        Interval::closed(0, 3));
+    assert_eq!(interval.image(|x| (10 - x) / 3, Monotonicity::NonIncreasing),
+        Interval::closed(0, 3));
+    assert_eq!(Interval::<i32>::empty().image(|x| *x, Monotonicity::Increasing),
+        Interval::empty());
+    assert_eq!(interval.image(|_| 5u8, Monotonicity::NonDecreasing),
+        Interval::point(5));
+}
+
+#[test]
+fn map_bounds_renormalizes() {
+    let interval: Interval<i32> = Interval::open(0, 10);
+
+    assert_eq!(interval.map_bounds(|b| b.map(|p| i64::from(p) * 10)),
+        Interval::closed(10, 90));
+    assert_eq!(interval.map_bounds(|b| Bound::Exclude(b.unwrap() * 10)),
+        Interval::closed(11, 89));
+    assert_eq!(interval.map_bounds(|b| b.map(|p| p / 4)),
+        Interval::closed(0, 2));
+    assert_eq!(Interval::<i32>::unbounded_from(3).map_bounds(|b| b),
+        Interval::unbounded_from(3));
+    assert_eq!(Interval::<i32>::empty().map_bounds(|b| b),
+        Interval::empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Widening and narrowing tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn widen_stabilizes_loop_counter() {
+    // Abstract iteration of `i = 0; while ... { i += 1 }`.
+    let mut state: Interval<i32> = Interval::point(0);
+    let mut steps = 0;
+    loop {
+        let upper = state.supremum().map_or(0, |s| s.saturating_add(1));
+        let next = Interval::closed(0, upper);
+        let widened = state.widen(&next);
+        if widened == state { break; }
+        state = widened;
+        steps += 1;
+    }
+    assert_eq!(state, Interval::unbounded_from(0));
+    assert_eq!(steps, 1);
+
+    assert_eq!(state.narrow(&Interval::closed(0, 100)),
+        Interval::closed(0, 100));
+}
+
+#[test]
+fn widen_and_narrow_edge_cases() {
+    let a: Interval<i32> = Interval::closed(0, 10);
+    let empty = Interval::empty();
+
+    assert_eq!(empty.widen(&a), a);
+    assert_eq!(a.widen(&empty), a);
+    assert_eq!(a.widen(&Interval::closed(-1, 11)), Interval::full());
+    assert_eq!(a.narrow(&empty), empty);
+    assert_eq!(empty.narrow(&a), empty);
+    assert_eq!(a.narrow(&Interval::closed(-5, 50)), a);
+    assert_eq!(Interval::full().narrow(&a), a);
+}
+
+#[cfg(feature="rand")]
+#[test]
+fn random_subinterval_within_interval() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+
+    let intervals: [Interval<u8>; 4] = [
+        Interval::closed(10, 20),
+        Interval::point(7),
+        Interval::unbounded_from(250),
+        Interval::full(),
+    ];
+    for interval in &intervals {
+        for _ in 0..100 {
+            let sub = interval.random_subinterval(&mut rng);
+            assert!(!sub.is_empty());
+            assert_eq!(interval.intersect(&sub), sub);
+        }
+    }
+    assert!(Interval::<u8>::empty().random_subinterval(&mut rng).is_empty());
+}
+
+#[test]
+fn overlap_matches_intersection_size() {
+    let mut intervals: Vec<Interval<i32>> = vec![
+        Interval::empty(),
+        Interval::full(),
+    ];
+    for l in -2..6 {
+        for u in l..6 {
+            intervals.push(Interval::closed(l, u));
+            intervals.push(Interval::open(l, u));
+        }
+        intervals.push(Interval::unbounded_from(l));
+        intervals.push(Interval::unbounded_up_to(l));
+    }
+
+    for a in &intervals {
+        for b in &intervals {
+            assert_eq!(a.overlap(b), a.intersect(b).size(), "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn integer_width_conversions() {
+    let interval: Interval<u8> = Interval::closed(3, 200);
+    assert_eq!(Interval::<u32>::from(interval), Interval::closed(3, 200));
+    assert_eq!(Interval::<i16>::from(interval), Interval::closed(3, 200));
+    assert_eq!(Interval::<u64>::from(Interval::<u8>::empty()),
+        Interval::empty());
+    assert_eq!(Interval::<i32>::from(Interval::<i8>::full()),
+        Interval::closed(-128, 127));
+
+    assert_eq!(Interval::<u8>::try_from(Interval::<i64>::closed(0, 255)),
+        Ok(Interval::closed(0, 255)));
+    assert!(Interval::<u8>::try_from(Interval::<i64>::closed(0, 256))
+        .is_err());
+    assert!(Interval::<u8>::try_from(Interval::<i8>::closed(-1, 5))
+        .is_err());
+    assert_eq!(Interval::<i8>::try_from(Interval::<u128>::empty()),
+        Ok(Interval::empty()));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Containment tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn within_matches_point_containment() {
+    let mut intervals: Vec<Interval<u8>> = vec![
+        Interval::empty(),
+        Interval::full(),
+    ];
+    for l in 0..5 {
+        for u in l..5 {
+            intervals.push(Interval::closed(l, u));
+            intervals.push(Interval::open(l, u));
+            intervals.push(Interval::left_open(l, u));
+        }
+        intervals.push(Interval::unbounded_from(l));
+        intervals.push(Interval::unbounded_up_to(l));
+    }
+
+    for a in &intervals {
+        for b in &intervals {
+            let within = a.iter().all(|p| b.contains(&p));
+            assert_eq!(a.within(b), within, "{:?} {:?}", a, b);
+            assert_eq!(b.properly_contains(a), within && a != b,
+                "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn interior_and_boundary_points() {
+    let interval: Interval<i32> = Interval::closed(0, 5);
+    assert_eq!(interval.interior(), Interval::closed(1, 4));
+    assert_eq!(interval.boundary_points().collect::<Vec<_>>(), [0, 5]);
+
+    // The boundary points are not in the interior.
+    for interval in [Interval::closed(0, 1), Interval::point(0)] {
+        assert!(interval.interior().is_empty());
+        assert!(interval.boundary_points().all(|p| interval.contains(&p)));
+    }
+    assert_eq!(Interval::<i32>::empty().boundary_points().count(), 0);
+    assert_eq!(Interval::<i32>::point(3).boundary_points().count(), 1);
+    assert_eq!(Interval::<u8>::full().boundary_points().collect::<Vec<_>>(),
+        [0, 255]);
+}
+
+#[test]
+fn entirely_before_matches_points() {
+    let intervals: Vec<Interval<i8>> = vec![
+        Interval::empty(),
+        Interval::point(-3),
+        Interval::closed(-3, 2),
+        Interval::open(2, 6),
+        Interval::closed(3, 5),
+        Interval::unbounded_to(-4),
+        Interval::unbounded_from(6),
+        Interval::full(),
+    ];
+    for a in &intervals {
+        for b in &intervals {
+            let expected = a.iter().all(|p| b.iter().all(|q| p < q));
+            assert_eq!(a.entirely_before(b), expected);
+            assert_eq!(b.entirely_after(a), expected);
+            assert_eq!(
+                Selection::from(*a).entirely_before(&Selection::from(*b)),
+                expected);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_bound_at_ends() {
+    use Bound::*;
+    let interval: Interval<i32> = Interval::closed(0, 20);
+    for p in [0, 10, 20] {
+        assert!(interval.contains_bound(&Include(p)));
+        assert!(interval.contains_bound(&Exclude(p)));
+    }
+    for p in [-1, 21] {
+        assert!(!interval.contains_bound(&Include(p)));
+        assert!(!interval.contains_bound(&Exclude(p)));
+    }
+    assert!(!interval.contains_bound(&Infinite));
+
+    // Finite `Interval`s are normalized to be bounded.
+    let unbounded: Interval<i32> = Interval::unbounded_from(5);
+    assert!(!unbounded.contains_bound(&Infinite));
+    assert!(unbounded.contains_bound(&Include(i32::MAX)));
+    assert!(unbounded.contains_bound(&Exclude(5)));
+    assert!(!unbounded.contains_bound(&Include(4)));
+
+    let empty: Interval<i32> = Interval::empty();
+    assert!(!empty.contains_bound(&Include(0)));
+    assert!(!empty.contains_bound(&Exclude(0)));
+    assert!(!empty.contains_bound(&Infinite));
+}
+
+#[test]
+fn try_into_range_bounds_edge_cases() {
+    use std::ops::Bound::*;
+    let full: Interval<i32> = Interval::full();
+    assert_eq!(
+        full.try_into_range_bounds(),
+        Some((Included(i32::MIN), Included(i32::MAX))));
+    assert_eq!(
+        Interval::<i32>::point(3).try_into_range_bounds(),
+        Some((Included(3), Included(3))));
+    assert_eq!(Interval::<i32>::open(3, 4).try_into_range_bounds(), None);
+
+    // The standard library conversions round trip.
+    for bound in [Bound::Include(1), Bound::Exclude(2), Bound::Infinite] {
+        let std_bound: std::ops::Bound<i32> = bound.into();
+        assert_eq!(Bound::from(std_bound), bound);
+    }
+}
+
+#[test]
+fn clamp_bound_edge_cases() {
+    use Bound::*;
+    let interval: Interval<i32> = Interval::closed(0, 20);
+    assert_eq!(interval.clamp_bound(Include(5)), Include(5));
+    assert_eq!(interval.clamp_bound(Exclude(20)), Exclude(20));
+    assert_eq!(interval.clamp_bound(Include(-3)), Include(0));
+    assert_eq!(interval.clamp_bound(Exclude(-1)), Include(0));
+    assert_eq!(interval.clamp_bound(Include(21)), Include(20));
+    assert_eq!(interval.clamp_bound(Infinite), Infinite);
+
+    let unbounded: Interval<i32> = Interval::unbounded_up_to(5);
+    assert_eq!(unbounded.clamp_bound(Include(9)), Include(4));
+    assert_eq!(unbounded.clamp_bound(Include(i32::MIN)), Include(i32::MIN));
+    assert_eq!(unbounded.clamp_bound(Infinite), Infinite);
+
+    let empty: Interval<i32> = Interval::empty();
+    assert_eq!(empty.clamp_bound(Include(3)), Include(3));
+
+    // Clamped bounds always lie within a nonempty `Interval`.
+    for p in -5..25 {
+        for bound in [Include(p), Exclude(p)] {
+            assert!(interval.contains_bound(&interval.clamp_bound(bound)));
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Serde tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature="serde")]
+#[test]
+fn serde_derived_form() {
+    use serde_test::Token;
+    use serde_test::assert_tokens;
+    use std::time::Duration;
+
+    let interval: Interval<i32> = Interval::right_open(3, 7);
+    assert_tokens(&interval, &[
+        Token::TupleVariant { name: "RawInterval", variant: "Closed", len: 2 },
+        Token::I32(3),
+        Token::I32(6),
+        Token::TupleVariantEnd,
+    ]);
+    assert_tokens(&Interval::<i32>::empty(), &[
+        Token::UnitVariant { name: "RawInterval", variant: "Empty" },
+    ]);
+
+    // Points need not implement `Display` or `FromStr`.
+    let interval = Interval::right_open(
+        Duration::from_millis(1500),
+        Duration::from_secs(3));
+    let json = serde_json::to_string(&interval).unwrap();
+    assert_eq!(serde_json::from_str::<Interval<Duration>>(&json).unwrap(),
+        interval);
+}
+
+#[cfg(feature="serde")]
+#[test]
+fn serde_notation_round_trip() {
+    use serde::Deserialize;
+    use serde::Serialize;
+    use crate::normalize::Dense;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Names {
+        #[serde(with="crate::interval::serde_notation")]
+        names: Interval<Dense<String>>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ports {
+        #[serde(with="crate::interval::serde_notation")]
+        ports: Interval<u16>,
+    }
+
+    let s = |s: &str| Dense(s.to_string());
+    let cases = [
+        (Interval::right_open(s("[c]"), s("a,b")), r"[\[c\],a\,b)"),
+        (Interval::closed(s(" x"), s("y ")),       r"[\ x,y\ ]"),
+        (Interval::unbounded_to(s("∞)")),          r"(-∞,\∞\)]"),
+        (Interval::unbounded_from(s("-∞")),        r"[-\∞,∞)"),
+        (Interval::point(s("Ø")),                  r"\Ø"),
+        (Interval::point(s(r"\")),                 r"\\"),
+        (Interval::open(s(""), s("(")),            r"(,\()"),
+        (Interval::empty(),                        "Ø"),
+        (Interval::full(),                         "(-∞,∞)"),
+    ];
+    for (interval, notation) in cases {
+        let names = Names { names: interval };
+        let value = serde_json::to_value(&names).unwrap();
+        assert_eq!(value["names"], notation);
+        assert_eq!(serde_json::from_value::<Names>(value).unwrap(), names);
+    }
+
+    // Unescaped whitespace around points is ignored.
+    let ports: Ports = serde_json::from_str(
+        r#"{ "ports": " [8000, 8080) " }"#).unwrap();
+    assert_eq!(ports.ports, Interval::closed(8000, 8079));
+    assert_eq!(serde_json::to_string(&ports).unwrap(),
+        r#"{"ports":"[8000,8079]"}"#);
+
+    let error = |s: &str| serde_json::from_value::<Ports>(
+        serde_json::json!({ "ports": s })).unwrap_err().to_string();
+    assert_eq!(error("[3 7)"), r#"invalid interval "[3 7)""#);
+    assert_eq!(error(r"[3,7\)"), r#"invalid interval "[3,7\\)""#);
+    assert_eq!(error("[3,x)"), "invalid digit found in string");
+}
+
+#[cfg(feature="schemars")]
+#[test]
+fn json_schema_matches_serialized_form() {
+    // An `Interval` is serialized as its `RawInterval`.
+    let schema = schemars::schema_for!(Interval<i32>);
+    let schema = schema.as_value();
+    assert_eq!(schema["$ref"], "#/$defs/RawInterval");
+    let variants = schema["$defs"]["RawInterval"]["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["required"][0].as_str()
+            .or_else(|| v["const"].as_str())
+            .unwrap())
+        .collect::<Vec<_>>();
+    assert!(variants.contains(&"Empty"));
+    assert!(variants.contains(&"Closed"));
+    assert!(variants.contains(&"Full"));
+
+    let value = serde_json::to_value(Interval::<i32>::closed(0, 9)).unwrap();
+    assert_eq!(value, serde_json::json!({ "Closed": [0, 9] }));
+}
+
+#[cfg(feature="serde")]
+#[test]
+fn serde_range_adapter() {
+    use serde::Deserialize;
+    use serde::Serialize;
+    use serde_test::Configure;
+    use serde_test::Token;
+    use serde_test::assert_de_tokens;
+    use serde_test::assert_de_tokens_error;
+    use serde_test::assert_tokens;
+    use std::ops::Range;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Ports {
+        #[serde(with="crate::interval::serde_range")]
+        range: Range<u16>,
+    }
+
+    let ports = Ports { range: 8000..8080 };
+    let tokens = |s| [
+        Token::Struct { name: "Ports", len: 1 },
+        Token::Str("range"),
+        Token::Str(s),
+        Token::StructEnd,
+    ];
+    assert_tokens(&ports.clone().readable(), &tokens("[8000,8079]"));
+    assert_de_tokens(&ports.readable(), &tokens("[8000, 8080)"));
+    assert_de_tokens(&Ports { range: 0..0 }.readable(), &tokens("Ø"));
+    assert_de_tokens_error::<serde_test::Readable<Ports>>(
+        &tokens("[8000,∞)"),
+        "interval containing the maximum point is not a range");
+}
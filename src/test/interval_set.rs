@@ -0,0 +1,69 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the `IntervalSet` trait.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::array_selection::ArraySelection;
+use crate::flat::SelectionView;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+/// Checks that the set operations of the sets agree with those of their
+/// `Selection`s, and with membership of each point.
+fn check_set_ops<A, B>(a: &A, b: &B)
+    where
+        A: IntervalSet<u8>,
+        B: IntervalSet<u8>,
+{
+    let (sa, sb) = (a.to_selection(), b.to_selection());
+    assert_eq!(a.union(b), sa.union(&sb));
+    assert_eq!(a.intersect(b), sa.intersect(&sb));
+    assert_eq!(a.minus(b), sa.minus(&sb));
+    assert_eq!(a.is_empty(), sa.is_empty());
+    for point in 0..=u8::MAX {
+        assert_eq!(a.contains(&point), sa.contains(&point));
+        assert_eq!(b.contains(&point), sb.contains(&point));
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalSet tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn backends_agree() {
+    let mut sel: Selection<u8> = (0..6)
+        .map(|i| Interval::closed(i * 40, i * 40 + 15))
+        .collect();
+    sel.union_in_place(Interval::unbounded_from(250));
+    let array: ArraySelection<u8, 8> = ArraySelection::try_from(
+        &Selection::from(Interval::closed(30, 90))).unwrap();
+    let bytes = sel.minus(&Selection::from(Interval::closed(45, 130)))
+        .serialize_flat();
+    let view: SelectionView<'_, u8> = SelectionView::new(&bytes).unwrap();
+    let empty: Selection<u8> = Selection::new();
+
+    check_set_ops(&sel, &array);
+    check_set_ops(&array, &view);
+    check_set_ops(&view, &sel);
+    check_set_ops(&empty, &view);
+    check_set_ops(&array, &ArraySelection::<u8, 1>::new());
+
+    assert!(IntervalSet::interval_iter(&view)
+        .eq(IntervalSet::interval_iter(&view.to_selection())));
+}
@@ -0,0 +1,106 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for lattice implementations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::lattice::BoundedLattice;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt::Debug;
+
+
+/// Asserts the lattice laws for each pair of the given elements.
+fn assert_lattice_laws<L>(elements: &[L])
+    where L: BoundedLattice + PartialEq + Debug
+{
+    for a in elements {
+        assert_eq!(a.join(a), *a);
+        assert_eq!(a.meet(a), *a);
+        assert_eq!(a.join(&L::bottom()), *a);
+        assert_eq!(a.meet(&L::top()), *a);
+        for b in elements {
+            assert_eq!(a.join(b), b.join(a));
+            assert_eq!(a.meet(b), b.meet(a));
+            assert_eq!(a.join(&a.meet(b)), *a);
+            assert_eq!(a.meet(&a.join(b)), *a);
+        }
+    }
+}
+
+/// Returns the least fixpoint of `f` above `L::bottom`.
+fn least_fixpoint<L, F>(f: F) -> L
+    where
+        L: BoundedLattice + PartialEq,
+        F: Fn(&L) -> L,
+{
+    let mut current = L::bottom();
+    loop {
+        let next = current.join(&f(&current));
+        if next == current { return current; }
+        current = next;
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Law tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn interval_lattice_laws() {
+    assert_lattice_laws::<Interval<i8>>(&[
+        Interval::empty(),
+        Interval::point(0),
+        Interval::closed(-3, 4),
+        Interval::closed(2, 9),
+        Interval::unbounded_from(5),
+        Interval::full(),
+    ]);
+}
+
+#[test]
+fn selection_lattice_laws() {
+    assert_lattice_laws::<Selection<i8>>(&[
+        Selection::empty(),
+        Selection::from(Interval::point(0)),
+        Selection::from([Interval::closed(-3, 4), Interval::point(8)]),
+        Selection::from(Interval::closed(2, 9)),
+        Selection::from(Interval::unbounded_to(-10)),
+        Selection::full(),
+    ]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Fixpoint tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_least_fixpoint() {
+    // Reachable values of `x = 0; while x < 10 { x += 3 }`.
+    let reachable: Selection<i32> = least_fixpoint(|sel: &Selection<i32>| {
+        let mut next = Selection::from(Interval::point(0));
+        for x in sel.iter().filter(|x| *x < 10) {
+            next.union_in_place(Interval::point(x + 3));
+        }
+        next
+    });
+    assert_eq!(reachable.iter().collect::<Vec<_>>(), [0, 3, 6, 9, 12]);
+
+    let hull: Interval<i32> = least_fixpoint(|i: &Interval<i32>| {
+        let upper = i.supremum().map_or(0, |x| (x + 3).min(12));
+        Interval::closed(0, upper)
+    });
+    assert_eq!(hull, Interval::closed(0, 12));
+}
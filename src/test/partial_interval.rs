@@ -0,0 +1,115 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`PartialInterval`].
+//!
+//! [`PartialInterval`] struct.PartialInterval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::partial_interval::IncomparableError;
+use crate::partial_interval::PartialInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+
+
+/// A set of flags ordered by inclusion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Flags(u8);
+
+impl PartialOrd for Flags {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.0 & other.0 == self.0, self.0 & other.0 == other.0) {
+            (true, true)  => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            _             => None,
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Construction tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn new_incomparable() {
+    assert_eq!(PartialInterval::closed(Flags(0b01), Flags(0b10)),
+        Err(IncomparableError));
+    assert_eq!(PartialInterval::open(0.0, f64::NAN), Err(IncomparableError));
+}
+
+#[test]
+fn new_degenerate() {
+    assert_eq!(PartialInterval::closed(Flags(0b11), Flags(0b01)),
+        Ok(PartialInterval::empty()));
+    assert_eq!(PartialInterval::closed(Flags(0b11), Flags(0b11)),
+        Ok(PartialInterval::point(Flags(0b11))));
+    assert_eq!(PartialInterval::right_open(Flags(0b11), Flags(0b11)),
+        Ok(PartialInterval::empty()));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_partial() {
+    let interval = PartialInterval::closed(Flags(0b001), Flags(0b111))
+        .unwrap();
+    assert_eq!(interval.contains(&Flags(0b011)), Ok(true));
+    assert_eq!(interval.contains(&Flags(0b110)), Err(IncomparableError));
+    assert_eq!(PartialInterval::<Flags>::empty().contains(&Flags(0)),
+        Ok(false));
+    assert_eq!(PartialInterval::<Flags>::full().contains(&Flags(0)),
+        Ok(true));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn intersect_partial() {
+    let a = PartialInterval::closed(Flags(0b0001), Flags(0b0111)).unwrap();
+    let b = PartialInterval::open(Flags(0b0011), Flags(0b1111)).unwrap();
+    assert_eq!(a.intersect(&b),
+        PartialInterval::left_open(Flags(0b0011), Flags(0b0111)));
+
+    let c = PartialInterval::closed(Flags(0b0010), Flags(0b1010)).unwrap();
+    assert_eq!(a.intersect(&c), Err(IncomparableError));
+    assert_eq!(a.intersect(&PartialInterval::empty()),
+        Ok(PartialInterval::empty()));
+}
+
+#[test]
+fn intersect_exclusive_endpoints() {
+    let a = PartialInterval::right_open(0.0, 1.0).unwrap();
+    let b = PartialInterval::closed(1.0, 2.0).unwrap();
+    assert_eq!(a.intersects(&b), Ok(false));
+    let c = PartialInterval::closed(0.0, 1.0).unwrap();
+    assert_eq!(c.intersect(&b), Ok(PartialInterval::point(1.0)));
+}
+
+#[test]
+fn enclose_partial() {
+    let a = PartialInterval::right_open(0.0, 1.0).unwrap();
+    let b = PartialInterval::unbounded_up_from(0.5);
+    assert_eq!(a.enclose(&b).unwrap().lower_bound(), Some(Bound::Include(0.0)));
+    assert_eq!(a.enclose(&b).unwrap().upper_bound(), Some(Bound::Infinite));
+    assert_eq!(a.enclose(&PartialInterval::empty()), Ok(a));
+    assert_eq!(a.enclose(&PartialInterval::point(f64::NAN)),
+        Err(IncomparableError));
+}
@@ -0,0 +1,82 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for query predicates.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::predicate::Clause;
+use crate::predicate::Predicate;
+use crate::selection::Selection;
+
+
+/// Returns `true` if the `Predicate` matches the given point.
+fn matches(predicate: &Predicate<i8>, point: i8) -> bool {
+    predicate.clauses().iter().any(|clause| match *clause {
+        Clause::Any           => true,
+        Clause::Equal(p)      => point == p,
+        Clause::AtLeast(l)    => point >= l,
+        Clause::AtMost(u)     => point <= u,
+        Clause::Between(l, u) => l <= point && point <= u,
+    })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Predicate tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn predicate_matches_selection() {
+    let mut sel: Selection<i8> = Selection::from(Interval::unbounded_to(-100));
+    sel.union_in_place(Interval::closed(-10, 10));
+    sel.union_in_place(Interval::point(20));
+    sel.union_in_place(Interval::unbounded_from(100));
+
+    let exact = Predicate::from_selection(&sel, 4);
+    assert!(exact.is_exact());
+    assert_eq!(exact.clauses().len(), 4);
+    let capped = Predicate::from_selection(&sel, 3);
+    assert!(!capped.is_exact());
+    assert_eq!(capped.clauses(), [Clause::Any]);
+
+    let inner = sel.intersect(&Selection::from(Interval::closed(-50, 50)));
+    let enclosed = Predicate::from_selection(&inner, 1);
+    for point in i8::MIN..=i8::MAX {
+        assert_eq!(matches(&exact, point), sel.contains(&point));
+        assert!(matches(&capped, point));
+        assert_eq!(
+            matches(&enclosed, point),
+            (-10..=20).contains(&point));
+    }
+}
+
+#[test]
+fn predicate_sql() {
+    let mut sel: Selection<i8> = Selection::from(Interval::unbounded_to(-100));
+    sel.union_in_place(Interval::point(0));
+    assert_eq!(
+        Predicate::from_selection(&sel, 8).sql("\"t\".\"x\"").to_string(),
+        "(\"t\".\"x\" <= -100 OR \"t\".\"x\" = 0)");
+    assert_eq!(
+        Predicate::from_selection(&sel, 1).sql("x").to_string(),
+        "x <= 0");
+    assert_eq!(
+        Predicate::from_selection(&Selection::<i8>::new(), 8)
+            .sql("x")
+            .to_string(),
+        "FALSE");
+    assert_eq!(
+        Predicate::from_selection(&Selection::<i8>::full(), 0)
+            .sql("x")
+            .to_string(),
+        "x IS NOT NULL");
+}
@@ -0,0 +1,78 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for prefix range decomposition.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::prefix::prefix_range_interval;
+use crate::prefix::prefix_ranges;
+use crate::prefix::selection_prefix_ranges;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Decomposition tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn prefix_ranges_cover_exactly() {
+    let intervals = [
+        Interval::closed(0, 0),
+        Interval::closed(7, 7000),
+        Interval::closed(1, 255),
+        Interval::closed(u64::MAX - 300, u64::MAX),
+        Interval::full(),
+    ];
+    for interval in &intervals {
+        for radix in [2, 3, 10, 16, 256] {
+            let ranges = prefix_ranges(interval, radix);
+            let covered: Selection<u64> = ranges
+                .iter()
+                .map(|&(prefix, len)| prefix_range_interval(prefix, len, radix)
+                    .expect("representable prefix range"))
+                .collect();
+            assert_eq!(covered, Selection::from(*interval));
+
+            // Ranges are disjoint and ascending.
+            let starts: Vec<_> = ranges
+                .iter()
+                .filter_map(|&(p, l)| prefix_range_interval(p, l, radix))
+                .filter_map(|i| i.infimum())
+                .collect();
+            assert!(starts.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
+
+#[test]
+fn prefix_ranges_are_minimal() {
+    assert_eq!(prefix_ranges(&Interval::full(), 2), [(0, 64)]);
+    assert_eq!(prefix_ranges(&Interval::closed(0, 999), 10), [(0, 3)]);
+    assert_eq!(prefix_ranges(&Interval::closed(10, 99), 10), [
+        (1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1), (7, 1), (8, 1), (9, 1),
+    ]);
+    assert_eq!(prefix_ranges(&Interval::closed(1, 6), 2),
+        [(1, 0), (1, 1), (2, 1), (6, 0)]);
+    assert!(prefix_ranges(&Interval::empty(), 10).is_empty());
+}
+
+#[test]
+fn selection_prefix_ranges_merge_adjacent() {
+    let sel = Selection::from([
+        Interval::closed(100, 149),
+        Interval::closed(150, 199),
+        Interval::closed(300, 300),
+    ]);
+    assert_eq!(selection_prefix_ranges(&sel, 10), [(1, 2), (300, 0)]);
+    assert_eq!(prefix_range_interval(1, 64, 2), None);
+    assert_eq!(prefix_range_interval(0, 64, 2), Some(Interval::full()));
+}
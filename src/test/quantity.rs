@@ -0,0 +1,97 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `uom` quantity intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// External library imports.
+use uom::si::i64::Length;
+use uom::si::i64::Time;
+use uom::si::length::centimeter;
+use uom::si::length::meter;
+use uom::si::time::millisecond;
+use uom::si::time::second;
+
+
+/// Returns the `Length` of the given number of meters.
+fn m(value: i64) -> Length {
+    Length::new::<meter>(value)
+}
+
+/// Returns the `Time` of the given number of seconds.
+fn s(value: i64) -> Time {
+    Time::new::<second>(value)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalization tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn quantities_count_base_units() {
+    assert_eq!(m(3).succ(), Some(m(4)));
+    assert_eq!(m(3).pred(), Some(m(2)));
+    assert_eq!(Length::MAXIMUM.succ(), None);
+    assert_eq!(Length::MINIMUM.pred(), None);
+
+    assert_eq!(Interval::open(m(3), m(7)), Interval::closed(m(4), m(6)));
+    assert_eq!(Interval::open(m(3), m(4)), Interval::empty());
+    assert_eq!(Interval::closed(m(1), m(3)).iter().collect::<Vec<_>>(),
+        [m(1), m(2), m(3)]);
+}
+
+#[test]
+fn quantities_convert_at_construction() {
+    // Quantities are stored in base units, so equal quantities given in
+    // different units bound the same `Interval`.
+    let interval = Interval::closed(
+        Length::new::<centimeter>(100),
+        Length::new::<meter>(2));
+    assert_eq!(interval, Interval::closed(m(1), m(2)));
+    assert_eq!(Interval::point(Time::new::<millisecond>(2000)),
+        Interval::point(s(2)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measure tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn quantity_widths_keep_dimension() {
+    let width: Option<Length> = Interval::closed(m(-5), m(20)).size();
+    assert_eq!(width, Some(m(25)));
+    assert_eq!(Interval::<Length>::full().size(), Some(Length::MAXIMUM));
+    assert_eq!(Length::extent(&m(5), &m(5)), Some(m(1)));
+    assert_eq!(Length::extent(&Length::MINIMUM, &Length::MAXIMUM), None);
+    assert!(Interval::closed(m(0), m(10)).width_at_least(&m(10)));
+    assert!(!Interval::closed(m(0), m(10)).width_at_least(&m(11)));
+}
+
+#[test]
+fn quantity_selection_measures() {
+    let mut busy: Selection<Time> = Selection::from(
+        Interval::closed(s(0), s(30)));
+    busy.union_in_place(Interval::closed(s(60), s(90)));
+    assert_eq!(busy.measure(), Some(s(60)));
+    assert_eq!(busy.covered_measure_in(&Interval::closed(s(20), s(70))),
+        Some(s(22)));
+
+    let other = Selection::from(Interval::closed(s(15), s(45)));
+    assert_eq!(busy.intersect(&other).measure(), Some(s(15)));
+    assert_eq!(Selection::<Time>::full().measure(), Some(Time::MAXIMUM));
+}
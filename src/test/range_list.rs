@@ -0,0 +1,80 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for range list parsing and formatting.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::range_list::RangeList;
+use crate::range_list::RangeListParseError;
+use crate::range_list::parse_range_list;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn parse_unsigned_items() {
+    let sel: Selection<u32> = parse_range_list(" 1 - 5 , 8, -3, 12-")
+        .expect("successful parse");
+
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 5),
+        Interval::point(8),
+        Interval::closed(12, u32::MAX),
+    ]);
+    assert_eq!(parse_range_list::<u32>("-"), Ok(Selection::full()));
+    assert_eq!(parse_range_list::<u32>(""), Ok(Selection::new()));
+}
+
+#[test]
+fn parse_signed_items() {
+    let sel: Selection<i32> = parse_range_list("-5--3,-1,2-")
+        .expect("successful parse");
+
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(-5, -3),
+        Interval::point(-1),
+        Interval::closed(2, i32::MAX),
+    ]);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse_range_list::<u32>("1,,2"),
+        Err(RangeListParseError::InvalidItem));
+    assert!(matches!(parse_range_list::<u32>("1-x"),
+        Err(RangeListParseError::InvalidValue(_))));
+    assert!(matches!(parse_range_list::<u32>("a"),
+        Err(RangeListParseError::InvalidValue(_))));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Formatting tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn format_round_trip() {
+    for list in ["", "1-5,8,10-", "0-", "3", "0-2,4-6"] {
+        let sel: Selection<u32> = parse_range_list(list)
+            .expect("successful parse");
+        assert_eq!(RangeList(&sel).to_string(), list);
+    }
+
+    let sel: Selection<i32> = parse_range_list("-5--3,-1,2-")
+        .expect("successful parse");
+    let text = RangeList(&sel).to_string();
+    assert_eq!(text, "-5--3,-1,2-");
+    assert_eq!(parse_range_list(&text), Ok(sel));
+}
@@ -354,6 +354,23 @@ fn contains() {
     assert!(a.contains(&4));
 }
 
+#[test]
+fn interior() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    let expected: [RawInterval<i32>; 11] = [
+        Empty, Empty, Open(0, 3), Open(0, 3), Open(0, 3),
+        Open(0, 3), UpTo(3), UpFrom(3), UpTo(3), UpFrom(3), Full,
+    ];
+    for (a, b) in intervals.iter().zip(expected.iter()) {
+        assert_eq!(a.interior(), *b, "{:?}", a);
+        assert_eq!(a.interior().interior(), *b, "{:?}", a);
+        assert_eq!(a.interior().closure(), b.closure(), "{:?}", a);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////
 // Set law tests
 ////////////////////////////////////////////////////////////////////////////
@@ -516,3 +516,12 @@ fn full() {
     assert_eq!(a.enclose(&From(0)),           Full);
     assert_eq!(a.enclose(&Full),              Full);
 }
+
+#[test]
+fn enclose_all() {
+    let intervals: [RawInterval<i32>; 3] = [Point(3), Empty, Open(-2, 1)];
+    assert_eq!(RawInterval::enclose_all(intervals.iter().copied()),
+        LeftOpen(-2, 3));
+    assert_eq!(RawInterval::enclose_all(std::iter::once(Point(3))), Point(3));
+    assert_eq!(RawInterval::<i32>::enclose_all(std::iter::empty()), Empty);
+}
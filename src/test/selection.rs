@@ -0,0 +1,1675 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Selection`].
+//!
+//! [`Selection`] struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::ops::SelectionOp;
+use crate::selection::BoundaryKind;
+use crate::selection::IntoIter;
+use crate::selection::Iter;
+use crate::selection::QuantizeMode;
+use crate::selection::Selection;
+use crate::selection::capped::FragmentPolicy;
+use crate::selection::map_ops::remove_keys_in;
+use crate::selection::map_ops::retain_keys_in;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+
+/// Returns the `DefaultHasher` hash of the given value.
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Asserts that two selections are equal and hash equally.
+fn assert_canonical_eq(a: &Selection<i32>, b: &Selection<i32>) {
+    assert_eq!(a, b);
+    assert_eq!(hash_of(a), hash_of(b));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Canonical form tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn canonical_minus_then_union() {
+    let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    a.minus_in_place(Interval::closed(4, 6));
+    a.union_in_place(Interval::closed(4, 6));
+    let b: Selection<i32> = Selection::from(Interval::closed(0, 10));
+
+    assert_canonical_eq(&a, &b);
+}
+
+#[test]
+fn canonical_minus_edges() {
+    let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    a.minus_in_place(Interval::closed(0, 2));
+    a.minus_in_place(Interval::closed(8, 10));
+    let b: Selection<i32> = Selection::from(Interval::closed(3, 7));
+
+    assert_canonical_eq(&a, &b);
+}
+
+#[test]
+fn canonical_intersect() {
+    let a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    let b: Selection<i32> = Selection::from(Interval::closed(5, 15));
+    let c: Selection<i32> = Selection::from(Interval::closed(5, 10));
+
+    assert_canonical_eq(&a.intersect(&b), &c);
+}
+
+#[test]
+fn canonical_complement() {
+    let a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    let b: Selection<i32> = vec![
+            Interval::unbounded_up_to(0),
+            Interval::unbounded_up_from(10),
+        ]
+        .into_iter()
+        .collect();
+
+    assert_canonical_eq(&a.complement(), &b);
+    assert_canonical_eq(&a.complement().complement(), &a);
+}
+
+#[test]
+fn canonical_points() {
+    let a: Selection<i32> = vec![1, 2, 3, 5].into_iter().collect();
+    let mut b: Selection<i32> = Selection::from(Interval::closed(1, 5));
+    b.minus_in_place(Interval::point(4));
+
+    assert_canonical_eq(&a, &b);
+}
+
+#[test]
+fn canonical_inequality() {
+    let a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    let mut b: Selection<i32> = a.clone();
+    b.minus_in_place(Interval::point(5));
+
+    assert_ne!(a, b);
+}
+
+#[test]
+fn canonicalize_preserves_points() {
+    let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    a.minus_in_place(Interval::closed(2, 3));
+    a.minus_in_place(Interval::point(7));
+    let before = a.iter().collect::<Vec<_>>();
+    let hash = hash_of(&a);
+
+    a.canonicalize();
+
+    assert_eq!(a.iter().collect::<Vec<_>>(), before);
+    assert_eq!(hash_of(&a), hash);
+}
+
+#[test]
+fn canonicalize_representation() {
+    let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    a.minus_in_place(Interval::closed(8, 10));
+    a.canonicalize();
+    let b: Selection<i32> = Selection::from(Interval::closed(0, 7));
+
+    assert_eq!(a.0, b.0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Containment tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn within_matches_point_containment() {
+    let selections: Vec<Selection<i32>> = vec![
+        Selection::empty(),
+        Selection::from(Interval::closed(0, 9)),
+        Selection::from([Interval::closed(0, 4), Interval::closed(5, 9)]),
+        Selection::from([Interval::closed(0, 3), Interval::closed(6, 9)]),
+        Selection::from([Interval::open(0, 3), Interval::left_open(5, 9)]),
+        Selection::from(Interval::point(4)),
+    ];
+    // Selections with the same points, stored differently by in-place edits.
+    let mut edited = selections.clone();
+    for sel in &selections {
+        let mut minus = sel.clone();
+        minus.minus_in_place(Interval::point(10));
+        let mut toggled = sel.clone();
+        toggled.toggle_in_place(Interval::closed(3, 5));
+        toggled.toggle_in_place(Interval::closed(3, 5));
+        edited.extend([minus, toggled]);
+    }
+    let selections = edited;
+
+    for a in &selections {
+        for b in &selections {
+            let within = a.iter().all(|p| b.contains(&p));
+            assert_eq!(a.within(b), within, "{:?} {:?}", a, b);
+            assert_eq!(b.properly_contains(a), within && a != b,
+                "{:?} {:?}", a, b);
+        }
+    }
+    assert!(!Selection::full().properly_contains(&Selection::<i32>::full()));
+
+    let a: Selection<i32> = Selection::from(Interval::point(2));
+    let mut b = a.clone();
+    b.minus_in_place(Interval::point(3));
+    assert_eq!(a, b);
+    assert!(a.within(&b));
+    assert!(b.within(&a));
+    assert!(!a.properly_contains(&b));
+}
+
+#[test]
+fn extreme_comparisons_match_points() {
+    let mut sel: Selection<i8> = Selection::from(Interval::closed(-50, -20));
+    sel.union_in_place(Interval::point(3));
+    sel.union_in_place(Interval::open(30, 60));
+    let selections = [
+        sel,
+        Selection::from(Interval::point(0)),
+        Selection::from(Interval::unbounded_to(10)),
+        Selection::from(Interval::unbounded_from(-10)),
+        Selection::full(),
+        Selection::new(),
+    ];
+    for sel in &selections {
+        for point in i8::MIN..=i8::MAX {
+            let before = sel.iter().any(|p| p < point);
+            let after = sel.iter().any(|p| p > point);
+            assert_eq!(sel.starts_before(&point), before);
+            assert_eq!(sel.starts_at_or_after(&point),
+                !sel.is_empty() && !before);
+            assert_eq!(sel.ends_after(&point), after);
+            assert_eq!(sel.ends_at_or_before(&point),
+                !sel.is_empty() && !after);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Iteration tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn iter_both_ends_and_resume() {
+    let sel: Selection<i32> = Selection::from([
+        Interval::closed(-3, -1),
+        Interval::point(4),
+        Interval::closed(8, 10),
+        Interval::closed(50, 51),
+    ]);
+    // Each bit chooses the end to take the next point from.
+    for pattern in 0_u32..256 {
+        let mut expected: VecDeque<i32> = sel.iter().collect();
+        let mut points = sel.iter();
+        let mut owned = sel.clone().into_iter();
+        for step in 0..expected.len() {
+            let front = pattern & (1 << (step % 8)) == 0;
+            let point = if front { points.next() } else { points.next_back() };
+            let owned_point = if front { owned.next() } else { owned.next_back() };
+            let model = if front {
+                expected.pop_front()
+            } else {
+                expected.pop_back()
+            };
+            assert_eq!(point, model);
+            assert_eq!(owned_point, model);
+
+            // Resuming from the saved progress yields the remaining points.
+            let state = points.state();
+            assert_eq!(owned.state(), state);
+            assert_eq!(Iter::resume(&sel, &state).collect::<VecDeque<_>>(),
+                expected);
+            assert_eq!(
+                IntoIter::resume(sel.clone(), &state)
+                    .rev()
+                    .collect::<Vec<_>>(),
+                expected.iter().rev().copied().collect::<Vec<_>>());
+        }
+        assert_eq!(points.next(), None);
+        assert_eq!(owned.next_back(), None);
+    }
+}
+
+#[test]
+fn raw_spans_match_interval_iter() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(-20, 20));
+    sel.minus_in_place(Interval::point(0));
+    sel.minus_in_place(Interval::closed(5, 7));
+    sel.union_in_place(Interval::point(40));
+    sel.union_in_place(Interval::unbounded_from(50));
+
+    let to_interval = |(l, u): (Bound<&i32>, Bound<&i32>)| Interval::from(
+        RawInterval::new(l.map(Clone::clone), u.map(Clone::clone))
+            .normalized());
+    let intervals: Vec<_> = sel.interval_iter().collect();
+    assert_eq!(sel.raw_spans().map(to_interval).collect::<Vec<_>>(), intervals);
+    assert_eq!(sel.raw_spans().rev().map(to_interval).collect::<Vec<_>>(),
+        intervals.iter().rev().copied().collect::<Vec<_>>());
+
+    // Spans may be taken from both ends.
+    let mut spans = sel.raw_spans();
+    assert_eq!(spans.next().map(to_interval), Some(intervals[0]));
+    assert_eq!(spans.next_back().map(to_interval), Some(intervals[4]));
+    assert_eq!(spans.map(to_interval).collect::<Vec<_>>(), &intervals[1..4]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Measure tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn measure_disjoint() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    sel.union_in_place(Interval::closed(10, 12));
+    sel.union_in_place(Interval::point(20));
+    assert_eq!(sel.measure(), Some(7));
+    assert_eq!(Selection::<i32>::empty().measure(), Some(0));
+    assert_eq!(Selection::<i32>::from(Interval::unbounded_to(4)).measure(),
+        Some(i32::MIN.unsigned_abs() + 4));
+}
+
+#[test]
+fn similarity_metrics() {
+    use std::time::Duration;
+    let a: Selection<u32> = Selection::from(Interval::closed(0, 9));
+    let b: Selection<u32> = Selection::from(Interval::closed(20, 29));
+    let empty = Selection::<u32>::empty();
+
+    assert_eq!(a.jaccard(&a), Some(1.0));
+    assert_eq!(a.jaccard(&b), Some(0.0));
+    assert_eq!(a.jaccard(&empty), Some(0.0));
+    assert_eq!(empty.jaccard(&empty), None);
+    assert_eq!(a.overlap_fraction(&a.union(&b)), Some(1.0));
+    assert_eq!(a.overlap_fraction(&Selection::from(Interval::closed(5, 24))),
+        Some(0.5));
+    assert_eq!(empty.overlap_fraction(&a), None);
+    assert_eq!(a.coverage_of(&Interval::closed(5, 24)), Some(0.25));
+    assert_eq!(a.coverage_of(&Interval::empty()), None);
+
+    let busy = Selection::from(Interval::right_open(
+        Duration::from_secs(0),
+        Duration::from_secs(15)));
+    let minute = Interval::right_open(Duration::ZERO, Duration::from_mins(1));
+    assert_eq!(busy.coverage_of(&minute), Some(0.25));
+}
+
+#[test]
+fn similarity_metrics_count_single_points() {
+    let one: Selection<i32> = Selection::from(Interval::point(1));
+    let two: Selection<i32> = Selection::from(Interval::point(2));
+
+    assert_eq!(one.jaccard(&one), Some(1.0));
+    assert_eq!(one.jaccard(&two), Some(0.0));
+    assert_eq!(one.jaccard(&one.union(&two)), Some(0.5));
+    assert_eq!(one.overlap_fraction(&one), Some(1.0));
+    assert_eq!(one.overlap_fraction(&two), Some(0.0));
+    assert_eq!(one.coverage_of(&Interval::point(1)), Some(1.0));
+    assert_eq!(one.coverage_of(&Interval::point(2)), Some(0.0));
+    assert_eq!(one.coverage_of(&Interval::closed(0, 3)), Some(0.25));
+
+    // Open intervals with no points are empty.
+    assert_eq!(one.coverage_of(&Interval::open(1, 2)), None);
+    // The full domain has more points than its `Length` can count.
+    let full = Selection::<u8>::full();
+    assert_eq!(full.jaccard(&full), None);
+    assert_eq!(Selection::from(Interval::closed(0u8, 254)).jaccard(&full),
+        None);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Quantize tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn quantize_merges_blocks() {
+    let mut sel: Selection<u8> = Selection::from(Interval::closed(1, 3));
+    sel.union_in_place(Interval::closed(6, 6));
+    sel.union_in_place(Interval::closed(250, 255));
+    assert_eq!(sel.quantize(&4, QuantizeMode::Cover)
+            .interval_iter()
+            .collect::<Vec<_>>(),
+        [Interval::closed(0, 7), Interval::closed(248, 255)]);
+    assert_eq!(sel.quantize(&4, QuantizeMode::Within)
+            .interval_iter()
+            .collect::<Vec<_>>(),
+        [Interval::closed(252, 255)]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn apply_all_replicates() {
+    let log = vec![
+        SelectionOp::Union(Interval::closed(0, 20)),
+        SelectionOp::Minus(Interval::closed(5, 8)),
+        SelectionOp::Intersect(Interval::closed(2, 15)),
+        SelectionOp::Complement,
+        SelectionOp::Union(Interval::point(6)),
+    ];
+    let mut a: Selection<i32> = Selection::new();
+    let mut b: Selection<i32> = Selection::from(Interval::closed(40, 50));
+    b.apply(SelectionOp::Clear);
+    a.apply_all(log.clone());
+    b.apply_all(log);
+    assert_eq!(a, b);
+    assert!(a.contains(&6));
+    assert!(a.contains(&7));
+    assert!(!a.contains(&2));
+}
+
+#[test]
+fn apply_invertible_round_trip() {
+    let ops = vec![
+        SelectionOp::Union(Interval::closed(0, 20)),
+        SelectionOp::Minus(Interval::closed(5, 8)),
+        SelectionOp::Intersect(Interval::closed(2, 15)),
+        SelectionOp::Complement,
+        SelectionOp::Clear,
+        SelectionOp::Union(Interval::point(6)),
+        SelectionOp::Batch(vec![
+            SelectionOp::Union(Interval::closed(30, 40)),
+            SelectionOp::Minus(Interval::closed(32, 33)),
+        ]),
+    ];
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(-5, 3));
+    let mut history = vec![sel.clone()];
+    let mut undo = Vec::new();
+    for op in ops {
+        undo.push(sel.apply_invertible(op));
+        history.push(sel.clone());
+    }
+    let _ = history.pop();
+    while let Some(op) = undo.pop() {
+        sel.apply(op);
+        assert_eq!(Some(&sel), history.last());
+        let _ = history.pop();
+    }
+}
+
+#[test]
+fn split_off_matches_points() {
+    let mut small: Selection<i8> = Selection::from(Interval::closed(-100, -50));
+    small.union_in_place(Interval::point(0));
+    small.union_in_place(Interval::closed(10, i8::MAX));
+    let mut large: Selection<i8> = Selection::new();
+    for i in -30..30 {
+        large.union_in_place(Interval::closed(i * 4, i * 4 + 1));
+    }
+    for sel in [small, large, Selection::full(), Selection::new()] {
+        for at in [i8::MIN, -100, -51, -50, -1, 0, 1, 10, 11, 57, i8::MAX] {
+            let mut below = sel.clone();
+            let mut above = below.split_off(&at);
+            for point in i8::MIN..=i8::MAX {
+                assert_eq!(below.contains(&point), sel.contains(&point)
+                    && point < at);
+                assert_eq!(above.contains(&point), sel.contains(&point)
+                    && point >= at);
+            }
+            above.union_in_place(Interval::closed(i8::MIN, at));
+            assert!(above.contains(&at));
+            below.union_in_place(Interval::closed(at, i8::MAX));
+            assert_eq!(below.upper_bound(), Some(Bound::Include(i8::MAX)));
+        }
+    }
+}
+
+#[test]
+fn append_matches_union() {
+    let mut shards: Vec<Selection<i8>> = Vec::new();
+    for i in -3..3 {
+        let mut shard = Selection::new();
+        for j in 0..8 {
+            let start = i * 40 + j * 5;
+            shard.union_in_place(Interval::closed(start, start + 2));
+        }
+        shards.push(shard);
+    }
+    let mut sel: Selection<i8> =
+        Selection::from(Interval::closed(i8::MIN, -121));
+    shards.push(Selection::from(Interval::closed(-121, -119)));
+    shards.push(Selection::from(Interval::point(i8::MAX)));
+    shards.push(Selection::new());
+    shards.push(Selection::from(Interval::closed(-5, 15)));
+
+    let mut expected = sel.clone();
+    for shard in &shards {
+        sel.append(shard.clone());
+        expected = expected.union(shard);
+        assert_eq!(sel, expected);
+    }
+    let mut reversed: Selection<i8> = Selection::new();
+    for shard in shards.into_iter().rev() {
+        reversed.append(shard);
+    }
+    reversed.append(Selection::from(Interval::closed(i8::MIN, -121)));
+    assert_eq!(reversed, expected);
+
+    let mut below: Selection<i8> = Selection::from(Interval::closed(0, 20));
+    let above = below.split_off(&10);
+    below.append(above);
+    assert_eq!(below, Selection::from(Interval::closed(0, 20)));
+}
+
+#[test]
+fn operations_match_point_model() {
+    let mut intervals: Vec<Interval<i8>> = vec![
+        Interval::empty(),
+        Interval::full(),
+    ];
+    for a in -3..=3 {
+        intervals.push(Interval::unbounded_to(a));
+        intervals.push(Interval::unbounded_from(a));
+        for b in a..=3 {
+            intervals.push(Interval::closed(a, b));
+        }
+    }
+    let mut sels: Vec<Selection<i8>> = Vec::new();
+    for a in &intervals {
+        for b in &intervals {
+            let mut sel = Selection::from(*a);
+            sel.union_in_place(*b);
+            sels.push(sel);
+        }
+    }
+    let points: Vec<i8> = vec![i8::MIN, i8::MIN + 1, i8::MAX - 1, i8::MAX]
+        .into_iter()
+        .chain(-5..=5)
+        .collect();
+
+    for sel in &sels {
+        let complement = sel.complement();
+        for p in &points {
+            assert_eq!(complement.contains(p), !sel.contains(p));
+        }
+        for interval in &intervals {
+            let other = Selection::from(*interval);
+            let mut union = sel.clone();
+            union.union_in_place(*interval);
+            let mut intersect = sel.clone();
+            intersect.intersect_in_place(*interval);
+            let mut minus = sel.clone();
+            minus.minus_in_place(*interval);
+            let mut toggle = sel.clone();
+            toggle.toggle_in_place(*interval);
+            for p in &points {
+                let (s, i) = (sel.contains(p), interval.contains(p));
+                assert_eq!(union.contains(p), s || i);
+                assert_eq!(intersect.contains(p), s && i);
+                assert_eq!(minus.contains(p), s && !i);
+                assert_eq!(toggle.contains(p), s != i);
+            }
+            assert_eq!(union, sel.union(&other));
+            assert_eq!(intersect, sel.intersect(&other));
+            assert_eq!(minus, sel.minus(&other));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Partition tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn partition_matches_intersect_minus() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(-20, -10));
+    sel.union_in_place(Interval::closed(-5, 5));
+    sel.union_in_place(Interval::point(8));
+    sel.union_in_place(Interval::unbounded_from(30));
+    let windows = vec![
+        Interval::closed(-7, 8),
+        Interval::empty(),
+        Interval::full(),
+        Interval::point(0),
+        Interval::unbounded_to(-15),
+    ];
+    for window in windows {
+        let (inside, outside) = sel.partition(&window);
+        let w = Selection::from(window);
+        assert_eq!(inside, sel.intersect(&w));
+        assert_eq!(outside, sel.minus(&w));
+    }
+}
+
+#[test]
+fn common_refinement_matches_membership() {
+    let selections: Vec<Selection<u8>> = vec![
+        Selection::empty(),
+        Selection::from([Interval::closed(0, 10), Interval::closed(20, 30)]),
+        Selection::from([Interval::closed(5, 25), Interval::point(40)]),
+        Selection::from([Interval::open(9, 21), Interval::closed(30, 40)]),
+        Selection::from(Interval::unbounded_from(35)),
+    ];
+    for a in &selections {
+        for b in &selections {
+            let refinement = a.common_refinement(b);
+            // Every point in either selection is in the one piece whose
+            // flags give its membership.
+            for p in 0..=u8::MAX {
+                let pieces: Vec<_> = refinement
+                    .iter()
+                    .filter(|(interval, _, _)| interval.contains(&p))
+                    .collect();
+                if a.contains(&p) || b.contains(&p) {
+                    assert_eq!(pieces.len(), 1, "{:?} {:?} {}", a, b, p);
+                    assert_eq!((pieces[0].1, pieces[0].2),
+                        (a.contains(&p), b.contains(&p)));
+                } else {
+                    assert!(pieces.is_empty());
+                }
+            }
+            // The pieces are ordered, and adjacent pieces differ in flags.
+            for pair in refinement.windows(2) {
+                let (x, y) = (&pair[0], &pair[1]);
+                assert!(x.0.supremum() < y.0.infimum());
+                assert!(x.0.supremum().map(|p| p + 1) != y.0.infimum()
+                    || (x.1, x.2) != (y.1, y.2));
+            }
+        }
+    }
+}
+
+#[test]
+fn intersect_skipped_intervals() {
+    let mut a: Selection<i32> = Selection::from(Interval::closed(-20, -10));
+    a.union_in_place(Interval::closed(-5, 5));
+    let b = Selection::from(Interval::closed(-7, 8));
+    assert_eq!(a.intersect(&b).interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(-5, 5)]);
+    assert_eq!(b.intersect(&a).interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(-5, 5)]);
+}
+
+#[test]
+fn intersect_bounds_without_points() {
+    let a: Selection<i32> = Selection::from(Interval::closed(-5, 5));
+    let b = Selection::from(Interval::closed(6, 8));
+    assert!(a.intersect(&b).is_empty());
+    let mut c = a;
+    c.intersect_in_place(Interval::closed(6, 8));
+    assert!(c.is_empty());
+    assert_eq!(c.interval_iter().count(), 0);
+}
+
+#[test]
+fn union_from_sorted_selections_matches_union() {
+    let mut state: u32 = 0x5eed_1234;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        i32::try_from(state >> 24).unwrap()
+    };
+    let mut selections: Vec<Selection<i32>> = (0..40)
+        .map(|_| (0..4)
+            .map(|_| {
+                let lower = next() * 8;
+                Interval::closed(lower, lower + next() % 12)
+            })
+            .collect())
+        .collect();
+    selections.push(Selection::new());
+    selections.push(Selection::from(Interval::unbounded_to(-20)));
+
+    let expected = selections
+        .iter()
+        .fold(Selection::new(), |acc, sel| acc.union(sel));
+    let merged = Selection::union_from_sorted_selections(&selections);
+    assert_eq!(
+        merged.interval_iter().collect::<Vec<_>>(),
+        expected.interval_iter().collect::<Vec<_>>());
+    assert_eq!(merged.minus(&expected), Selection::new());
+    assert_eq!(
+        Selection::<i32>::union_from_sorted_selections(&[]),
+        Selection::new());
+}
+
+#[test]
+fn overlay_priorities() {
+    let base: Selection<i32> = Selection::from(Interval::closed(0, 100));
+    let top = Selection::from(Interval::closed(40, 60));
+    let tied = Selection::from(Interval::closed(50, 70));
+    let hidden = Selection::from(Interval::closed(45, 55));
+
+    let visible = Selection::overlay(vec![
+        ('a', base),
+        ('c', top),
+        ('b', hidden),
+        ('c', tied),
+    ]);
+
+    assert_eq!(visible.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+        ['a', 'c', 'b', 'c']);
+    assert_eq!(visible[0].1, Selection::from([
+        Interval::closed(0, 39),
+        Interval::closed(71, 100),
+    ]));
+    assert_eq!(visible[1].1, Selection::from(Interval::closed(40, 60)));
+    assert!(visible[2].1.is_empty());
+    assert_eq!(visible[3].1, Selection::from(Interval::closed(61, 70)));
+    assert!(Selection::<i32>::overlay(Vec::<(u8, _)>::new()).is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Scheduling tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn earliest_fit_constraints() {
+    let mut free: Selection<u8> = Selection::from(Interval::closed(4, 12));
+    free.union_in_place(Interval::closed(20, 23));
+    free.union_in_place(Interval::closed(250, 255));
+
+    assert_eq!(free.earliest_fit(9, &0, None), Some(Interval::closed(4, 12)));
+    assert_eq!(free.earliest_fit(10, &0, None), None);
+    assert_eq!(free.earliest_fit(6, &13, None),
+        Some(Interval::closed(250, 255)));
+    assert_eq!(free.earliest_fit(4, &5, Some(8)),
+        Some(Interval::closed(8, 11)));
+    assert_eq!(free.earliest_fit(4, &9, Some(8)), None);
+    assert_eq!(free.earliest_fit(0, &0, None), None);
+    assert_eq!(Selection::<u8>::empty().earliest_fit(1, &0, None), None);
+}
+
+#[test]
+fn earliest_fit_signed_timestamps() {
+    let mut free: Selection<i64> = Selection::from(Interval::closed(-100, 100));
+    free.minus_in_place(Interval::closed(-90, 10));
+
+    assert_eq!(free.earliest_fit(5, &-100, None),
+        Some(Interval::closed(-100, -96)));
+    assert_eq!(free.earliest_fit(11, &-100, None),
+        Some(Interval::closed(11, 21)));
+    assert_eq!(free.earliest_fit(5, &-100, Some(25)),
+        Some(Interval::closed(-100, -96)));
+    assert_eq!(free.earliest_fit(5, &-100, Some(30)),
+        Some(Interval::closed(30, 34)));
+    assert_eq!(free.earliest_fit(91, &0, None), None);
+    assert_eq!(free.earliest_fit(-5, &0, None), None);
+
+    let full: Selection<i64> = Selection::full();
+    assert_eq!(full.earliest_fit(i64::MAX, &i64::MIN, None),
+        Some(Interval::closed(i64::MIN, -2)));
+}
+
+#[test]
+fn earliest_fit_durations() {
+    use std::time::Duration;
+    let mut free = Selection::from(Interval::closed(
+        Duration::ZERO,
+        Duration::from_hours(8)));
+    free.minus_in_place(Interval::right_open(
+        Duration::from_mins(50),
+        Duration::from_mins(95)));
+
+    assert_eq!(free.earliest_fit(
+            Duration::from_mins(30),
+            &Duration::from_mins(30),
+            Some(Duration::from_mins(15))),
+        Some(Interval::right_open(
+            Duration::from_mins(105),
+            Duration::from_mins(135))));
+}
+
+
+#[test]
+fn shard_balances_points() {
+    let selections: Vec<Selection<u8>> = vec![
+        Selection::empty(),
+        Selection::full(),
+        Selection::from(Interval::point(3)),
+        Selection::from([
+            Interval::closed(0, 10),
+            Interval::point(100),
+            Interval::closed(105, 109),
+            Interval::closed(140, 200),
+        ]),
+    ];
+    for sel in &selections {
+        let points: Vec<u8> = sel.iter().collect();
+        for n in 1..20 {
+            let shards = sel.shard(n);
+            assert_eq!(shards.len(), n);
+            // The shards are ordered, and together hold every point.
+            let sharded: Vec<u8> = shards.iter().flat_map(Selection::iter)
+                .collect();
+            assert_eq!(sharded, points);
+            let sizes: Vec<usize> = shards.iter().map(|s| s.iter().count())
+                .collect();
+            assert!(sizes.windows(2).all(|pair| pair[0] >= pair[1]));
+            assert!(sizes[0] - sizes[n - 1] <= 1, "{:?} {}", sel, n);
+        }
+    }
+}
+
+#[test]
+fn shard_balances_signed_points() {
+    let sizes = |sel: &Selection<i8>, n| sel.shard(n)
+        .iter()
+        .map(|s| s.iter().count())
+        .collect::<Vec<_>>();
+    assert_eq!(sizes(&Selection::full(), 4), [64, 64, 64, 64]);
+    assert_eq!(sizes(&Selection::full(), 3), [86, 85, 85]);
+    assert_eq!(sizes(&Selection::full(), 1), [256]);
+
+    let shards = Selection::<i8>::full().shard(2);
+    assert_eq!(shards[0], Selection::from(Interval::closed(-128, -1)));
+    assert_eq!(shards[1], Selection::from(Interval::closed(0, 127)));
+
+    let sel = Selection::from([
+        Interval::closed(-100, -91),
+        Interval::closed(90, 99),
+    ]);
+    assert_eq!(sizes(&sel, 4), [5, 5, 5, 5]);
+
+    let full = Selection::<i128>::full().shard(2);
+    assert_eq!(full[0], Selection::from(Interval::unbounded_to(-1)));
+    assert_eq!(full[1], Selection::from(Interval::unbounded_from(0)));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Capacity tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn with_capacity_holds_intervals() {
+    let mut sel: Selection<i32> = Selection::with_capacity(16);
+    let capacity = sel.capacity();
+    assert!(capacity >= 16);
+
+    for i in 0..16 {
+        sel.union_in_place(Interval::closed(i * 10, i * 10 + 5));
+    }
+
+    assert_eq!(sel.interval_iter().count(), 16);
+    assert_eq!(sel.capacity(), capacity);
+}
+
+#[test]
+fn with_capacity_reserves_large_selections() {
+    let mut sel: Selection<i32> = Selection::with_capacity(100);
+    let capacity = sel.capacity();
+    assert!(capacity >= 100);
+
+    for i in 0..100 {
+        sel.union_in_place(Interval::closed(i * 10, i * 10 + 5));
+    }
+
+    assert_eq!(sel.interval_iter().count(), 100);
+    assert_eq!(sel.capacity(), capacity);
+}
+
+#[test]
+fn outgrown_capacity_counts_held_intervals() {
+    let mut sel: Selection<i32> = Selection::new();
+    for i in 0..100 {
+        sel.union_in_place(Interval::closed(i * 10, i * 10 + 5));
+    }
+    assert_eq!(sel.capacity(), 100);
+
+    sel.union_in_place(Interval::point(2000));
+    assert!(sel.capacity() <= 101);
+
+    sel.clear();
+    assert_eq!(sel.capacity(), 0);
+}
+
+#[test]
+fn clear_retains_capacity() {
+    let mut sel: Selection<i32> = Selection::with_capacity(8);
+    for i in 0..8 {
+        sel.union_in_place(Interval::closed(i * 10, i * 10 + 5));
+    }
+    let capacity = sel.capacity();
+
+    sel.clear();
+    assert!(sel.is_empty());
+    assert_eq!(sel.capacity(), capacity);
+
+    sel.union_in_place(Interval::closed(3, 4));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(3, 4)]);
+
+    sel.intersect_in_place(Interval::empty());
+    assert!(sel.is_empty());
+    assert_eq!(sel.capacity(), capacity);
+}
+
+#[test]
+fn try_in_place_respects_size_limit() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 999));
+    for point in (1..999).step_by(2) {
+        let _ = sel.try_minus_in_place(Interval::point(point), 64);
+    }
+    assert!(sel.bound_count() <= 64);
+    assert!(!sel.contains(&1));
+    assert!(sel.contains(&998));
+
+    // Operations which merge `Interval`s succeed at the limit.
+    sel.try_union_in_place(Interval::closed(0, 500), 64).unwrap();
+    sel.try_minus_in_place(Interval::closed(600, 999), 64).unwrap();
+    assert!(sel.contains(&500));
+    assert!(!sel.contains(&600));
+}
+
+#[test]
+fn try_in_place_matches_infallible_ops() {
+    // A linear congruential generator, for reproducible operations.
+    let mut state: u32 = 0x2545_f491;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        i8::try_from((state >> 16) % 64).unwrap() - 32
+    };
+
+    let mut sel: Selection<i8> = Selection::new();
+    for step in 0..2000 {
+        let (a, b) = (next(), next());
+        let interval = Interval::closed(a.min(b), a.min(b) + (b % 4).abs());
+        let union = step % 3 == 0;
+
+        let before = sel.clone();
+        let mut expected = sel.clone();
+        let result = if union {
+            expected.union_in_place(interval);
+            sel.try_union_in_place(interval, 12)
+        } else {
+            expected.minus_in_place(interval);
+            sel.try_minus_in_place(interval, 12)
+        };
+
+        let grown = expected.bound_count() > before.bound_count()
+            && expected.bound_count() > 12;
+        assert_eq!(result.is_err(), grown);
+        assert_eq!(sel, if grown { before } else { expected });
+        assert!(sel.bound_count() <= 12);
+    }
+}
+/// Returns the number of maximal runs of adjacent points in the `Selection`.
+fn run_count(sel: &Selection<i8>) -> usize {
+    let points: Vec<i8> = sel.iter().collect();
+    points.windows(2).filter(|w| w[1] != w[0] + 1).count()
+        + usize::from(!points.is_empty())
+}
+
+#[test]
+fn capped_selection_tracks_fragments() {
+    let mut state: u32 = 0x1234_5678;
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        i8::try_from((state >> 16) % 96).unwrap() - 48
+    };
+
+    for policy in [FragmentPolicy::MergeSmallestGap, FragmentPolicy::Reject] {
+        let mut sel = Selection::<i8>::with_max_fragments(4, policy);
+        for step in 0..1000 {
+            let (a, b) = (next(), next());
+            let interval = Interval::closed(a.min(b), a.min(b) + (b % 6).abs());
+            let mut expected = sel.selection().clone();
+            let result = if step % 3 == 0 {
+                expected.minus_in_place(interval);
+                sel.minus_in_place(interval)
+            } else {
+                expected.union_in_place(interval);
+                sel.union_in_place(interval)
+            };
+
+            assert_eq!(sel.fragment_count(), run_count(sel.selection()));
+            assert!(sel.fragment_count() <= 4);
+            match policy {
+                FragmentPolicy::MergeSmallestGap => {
+                    assert!(result.is_ok());
+                    assert!(expected.within(sel.selection()));
+                },
+                FragmentPolicy::Reject => {
+                    assert_eq!(result.is_err(), run_count(&expected) > 4);
+                    if result.is_ok() {
+                        assert_eq!(sel.selection(), &expected);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[test]
+fn capped_selection_merges_smallest_gap() {
+    let mut sel = Selection::<i32>::with_max_fragments(
+        3,
+        FragmentPolicy::MergeSmallestGap);
+    sel.union_in_place(Interval::closed(0, 10)).unwrap();
+    sel.union_in_place(Interval::closed(20, 30)).unwrap();
+    sel.union_in_place(Interval::closed(33, 40)).unwrap();
+    sel.union_in_place(Interval::closed(45, 50)).unwrap();
+    assert_eq!(sel.selection(), &Selection::from([
+        Interval::closed(0, 10),
+        Interval::closed(20, 40),
+        Interval::closed(45, 50),
+    ]));
+
+    // A narrow gap left by a removal is filled again.
+    sel.minus_in_place(Interval::point(5)).unwrap();
+    assert!(sel.selection().contains(&5));
+
+    sel.minus_in_place(Interval::closed(2, 8)).unwrap();
+    assert_eq!(sel.fragment_count(), 3);
+    assert_eq!(sel.selection(), &Selection::from([
+        Interval::closed(0, 1),
+        Interval::closed(9, 10),
+        Interval::closed(20, 50),
+    ]));
+
+    sel.intersect_in_place(Interval::closed(1, 30));
+    assert_eq!(sel.fragment_count(), 3);
+    sel.intersect_in_place(Interval::closed(8, 30));
+    assert_eq!(sel.fragment_count(), 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Negation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn neg_matches_negated_points() {
+    let mut sel: Selection<i8> = Selection::from(Interval::unbounded_to(-100));
+    sel.union_in_place(Interval::closed(-5, 20));
+    sel.union_in_place(Interval::point(64));
+
+    let negated = -&sel;
+    for point in i8::MIN..=i8::MAX {
+        let expected = point
+            .checked_neg()
+            .is_some_and(|p| sel.contains(&p));
+        assert_eq!(negated.contains(&point), expected);
+    }
+    assert_eq!(-negated, sel.minus(&Selection::from(Interval::point(i8::MIN))));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn extend_points() {
+    let ids = [4, 1, 2, 9, 3, 8];
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(-2, 0));
+    sel.extend(ids.iter());
+    sel.extend(Some(20));
+
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(-2, 4),
+        Interval::closed(8, 9),
+        Interval::point(20),
+    ]);
+    assert_eq!(sel, ids.iter().copied().chain(vec![-2, -1, 0, 20]).collect());
+}
+
+#[test]
+fn from_interval_collections() {
+    let a = Interval::closed(0, 3);
+    let b = Interval::point(7);
+    let c = Interval::closed(2, 5);
+
+    let from_array: Selection<i32> = Selection::from([a, b, c]);
+    let from_vec: Selection<i32> = Selection::from(vec![c, b, a]);
+
+    assert_eq!(from_array.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 5),
+        Interval::point(7),
+    ]);
+    assert_eq!(from_array, from_vec);
+    assert_eq!(from_array, vec![a, b, c].into_iter().collect());
+    assert!(Selection::<i32>::from([]).is_empty());
+}
+
+#[test]
+fn galois_connection_laws() {
+    let selections: [Selection<i8>; 4] = [
+        Selection::empty(),
+        Selection::from([Interval::closed(-4, -2), Interval::point(3)]),
+        Selection::from([Interval::point(0), Interval::closed(5, 9)]),
+        Selection::full(),
+    ];
+    let intervals: [Interval<i8>; 4] = [
+        Interval::empty(),
+        Interval::closed(-5, 3),
+        Interval::closed(0, 9),
+        Interval::unbounded_from(-4),
+    ];
+
+    for s in &selections {
+        assert!(s.minus(&s.abstracted().concretized()).is_empty());
+        for i in &intervals {
+            let s_in_i = s.minus(&i.concretized()).is_empty();
+            let abstract_in_i = s.abstracted().minus(i).all(|r| r.is_empty());
+            assert_eq!(s_in_i, abstract_in_i);
+        }
+    }
+    for i in &intervals {
+        assert_eq!(i.concretized().abstracted(), *i);
+    }
+}
+
+#[test]
+fn selection_macro() {
+    let sel: Selection<i32> = crate::selection![0..5, 8, 10.., ];
+    assert_eq!(sel, Selection::from([
+        Interval::closed(0, 4),
+        Interval::point(8),
+        Interval::unbounded_from(10),
+    ]));
+
+    let empty: Selection<i32> = crate::selection![];
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn start_len_pairs_round_trip() {
+    let pairs = [(0, 2), (4, 1), (9, 3)];
+    let sel = Selection::from_start_len_pairs(pairs);
+    assert_eq!(sel.to_start_len_pairs(), pairs);
+
+    let sel = Selection::from_start_len_pairs([(3, 0), (0, 2), (2, 2)]);
+    assert_eq!(sel.to_start_len_pairs(), [(0, 4)]);
+    assert!(Selection::from_start_len_pairs([(5, 0)]).is_empty());
+}
+
+#[test]
+fn toggle_matches_symmetric_difference() {
+    let selections: [Selection<u8>; 4] = [
+        Selection::empty(),
+        Selection::from([Interval::closed(2, 4), Interval::closed(8, 9)]),
+        Selection::from([Interval::closed(0, 3), Interval::point(6)]),
+        Selection::from([Interval::point(5), Interval::unbounded_from(250)]),
+    ];
+    let mut intervals = vec![Interval::empty(), Interval::full()];
+    for l in 0..12 {
+        for u in l..12 {
+            intervals.push(Interval::closed(l, u));
+        }
+        intervals.push(Interval::unbounded_from(l));
+        intervals.push(Interval::unbounded_up_to(l));
+    }
+
+    for s in &selections {
+        for i in &intervals {
+            let mut toggled = s.clone();
+            toggled.toggle_in_place(*i);
+
+            let i = Selection::from(*i);
+            let expected = s.minus(&i).union(&i.minus(s));
+            assert_eq!(toggled, expected);
+
+            // Adjacent intervals are merged.
+            let parts: Vec<_> = toggled.interval_iter().collect();
+            assert!(parts.windows(2).all(|w| w[0]
+                .supremum()
+                .and_then(|end| end.checked_add(1))
+                < w[1].infimum()), "{:?} toggle {:?}: {:?}", s, i, parts);
+        }
+    }
+}
+
+#[cfg(feature="rand")]
+#[test]
+fn random_subselection_within_selection() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+
+    let sel: Selection<i32> = Selection::from([
+        Interval::closed(0, 99),
+        Interval::closed(200, 299),
+        Interval::point(500),
+    ]);
+    for fragments in 0..20 {
+        let sub = sel.random_subselection(&mut rng, fragments);
+        assert!(sub.interval_iter().count() <= fragments);
+        assert!(sub.minus(&sel).is_empty());
+    }
+    assert!(sel.random_subselection(&mut rng, 0).is_empty());
+    assert!(Selection::<i32>::empty()
+        .random_subselection(&mut rng, 5)
+        .is_empty());
+}
+
+#[test]
+fn eq_within_tolerance() {
+    let a: Selection<i32> = Selection::from([
+        Interval::closed(0, 10),
+        Interval::closed(13, 20),
+        Interval::unbounded_from(40),
+    ]);
+    let b: Selection<i32> = Selection::from([
+        Interval::closed(-1, 21),
+        Interval::unbounded_from(38),
+    ]);
+
+    assert!(a.eq_within(&a, 0));
+    assert!(!a.eq_within(&b, 2));
+    assert!(a.eq_within(&b, 3));
+    assert!(b.eq_within(&a, 3));
+
+    assert!(!b.eq_within(&Selection::full(), 100));
+    assert!(Selection::<i32>::empty().eq_within(&Selection::empty(), 0));
+    assert!(!Selection::empty().eq_within(&b, 100));
+}
+
+#[test]
+fn coalesce_gaps_smaller_than_threshold() {
+    let sel: Selection<i32> = Selection::from([
+        Interval::unbounded_to(-10),
+        Interval::closed(-8, 0),
+        Interval::closed(4, 5),
+        Interval::point(9),
+    ]);
+
+    assert_eq!(sel.coalesce_gaps_smaller_than(0), sel);
+    assert_eq!(sel.coalesce_gaps_smaller_than(2), sel);
+    assert_eq!(sel.coalesce_gaps_smaller_than(3), Selection::from([
+        Interval::unbounded_to(0),
+        Interval::closed(4, 5),
+        Interval::point(9),
+    ]));
+    assert_eq!(sel.coalesce_gaps_smaller_than(4),
+        sel.coalesce_gaps_smaller_than(3));
+    assert_eq!(sel.coalesce_gaps_smaller_than(5),
+        Selection::from(Interval::unbounded_to(9)));
+    assert_eq!(sel.coalesce_gaps_smaller_than(u32::MAX),
+        Selection::from(Interval::unbounded_to(9)));
+    assert!(Selection::<i32>::empty().coalesce_gaps_smaller_than(9).is_empty());
+}
+
+#[test]
+fn drop_intervals_shorter_than_threshold() {
+    let sel: Selection<i32> = Selection::from([
+        Interval::unbounded_to(-10),
+        Interval::closed(-8, 0),
+        Interval::closed(4, 5),
+        Interval::point(9),
+    ]);
+
+    assert_eq!(sel.drop_intervals_shorter_than(0), sel);
+    assert_eq!(sel.drop_intervals_shorter_than(1), Selection::from([
+        Interval::unbounded_to(-10),
+        Interval::closed(-8, 0),
+        Interval::closed(4, 5),
+    ]));
+    assert_eq!(sel.drop_intervals_shorter_than(8), Selection::from([
+        Interval::unbounded_to(-10),
+        Interval::closed(-8, 0),
+    ]));
+    assert_eq!(sel.drop_intervals_shorter_than(9),
+        Selection::from(Interval::unbounded_to(-10)));
+
+    // Opening removes short runs, and closing then fills short gaps.
+    let smoothed = sel
+        .drop_intervals_shorter_than(1)
+        .coalesce_gaps_smaller_than(5);
+    assert_eq!(smoothed, Selection::from(Interval::unbounded_to(5)));
+}
+
+#[test]
+fn coverage_per_window_edge_cases() {
+    let sel: Selection<i32> = Selection::from([
+        Interval::closed(-5, 25),
+        Interval::point(33),
+        Interval::closed(38, 45),
+    ]);
+
+    // Intervals spanning several windows are split between them.
+    let windows: Vec<_> = sel
+        .coverage_per_window(10, &Interval::closed(0, 45))
+        .collect();
+    assert_eq!(windows, [
+        (Interval::closed(0, 9), 10),
+        (Interval::closed(10, 19), 10),
+        (Interval::closed(20, 29), 6),
+        (Interval::closed(30, 39), 3),
+        (Interval::closed(40, 45), 6),
+    ]);
+
+    // A domain which fits a whole number of windows.
+    assert_eq!(sel.coverage_per_window(5, &Interval::closed(0, 9)).count(), 2);
+
+    assert_eq!(sel.coverage_per_window(0, &Interval::closed(0, 9)).count(), 0);
+    assert_eq!(sel.coverage_per_window(-1, &Interval::closed(0, 9)).count(), 0);
+    assert_eq!(sel.coverage_per_window(5, &Interval::empty()).count(), 0);
+    assert_eq!(Selection::<i32>::empty()
+        .coverage_per_window(5, &Interval::closed(0, 9))
+        .collect::<Vec<_>>(), [
+        (Interval::closed(0, 4), 0),
+        (Interval::closed(5, 9), 0),
+    ]);
+}
+
+#[test]
+fn coverage_per_window_full_domain() {
+    // The end of the last window is not representable.
+    let windows: Vec<_> = Selection::<i8>::full()
+        .coverage_per_window(16, &Interval::closed(-128, 127))
+        .collect();
+    assert_eq!(windows.len(), 16);
+    assert!(windows.iter().all(|(window, covered)|
+        window.size() == Some(15) && *covered == 16));
+    assert_eq!(windows.last(), Some(&(Interval::closed(112, 127), 16)));
+
+    let windows: Vec<_> = Selection::from(Interval::point(u8::MAX))
+        .coverage_per_window(100, &Interval::full())
+        .collect();
+    assert_eq!(windows, [
+        (Interval::closed(0, 99), 0),
+        (Interval::closed(100, 199), 0),
+        (Interval::closed(200, 255), 1),
+    ]);
+}
+
+#[test]
+fn covered_measure_in_counts_intersection_points() {
+    // Both a small selection and one large enough to be stored in a tree.
+    for &count in &[3, 40] {
+        let sel: Selection<i32> = (0..count)
+            .map(|i| Interval::closed(i * 10, i * 10 + 4))
+            .collect();
+
+        for lower in -5..(count * 10 + 5) {
+            for &width in &[0, 1, 3, 7, 25] {
+                let query = Interval::closed(lower, lower + width);
+                let points = sel.intersect(&Selection::from(query))
+                    .iter()
+                    .count();
+                assert_eq!(
+                    sel.covered_measure_in(&query),
+                    u32::try_from(points).ok(),
+                    "query {:?}", query);
+            }
+        }
+    }
+
+    let sel: Selection<i32> = Selection::from(Interval::unbounded_to(5));
+    assert_eq!(sel.covered_measure_in(&Interval::closed(0, 9)), Some(6));
+    assert_eq!(sel.covered_measure_in(&Interval::empty()), Some(0));
+    assert_eq!(Selection::<i32>::empty()
+        .covered_measure_in(&Interval::closed(0, 9)), Some(0));
+    assert_eq!(Selection::<i32>::from(Interval::point(5))
+        .covered_measure_in(&Interval::closed(0, 10)), Some(1));
+}
+
+#[test]
+fn boundary_queries_match_interval_bounds() {
+    let mut small: Selection<i32> = Selection::from([
+        Interval::closed(0, 10),
+        Interval::point(15),
+        Interval::unbounded_from(40),
+    ]);
+    small.minus_in_place(Interval::point(5));
+    let large: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+
+    for sel in &[small, large, Selection::empty()] {
+        let bounds: Vec<_> = sel.interval_iter()
+            .flat_map(|i| vec![
+                (i.lower_bound().unwrap(), BoundaryKind::Lower),
+                (i.upper_bound().unwrap(), BoundaryKind::Upper),
+            ])
+            .filter(|(b, _)| b.is_finite())
+            .collect();
+
+        for point in -5..410 {
+            assert_eq!(
+                sel.next_boundary_after(&point),
+                bounds.iter().find(|(b, _)| *b.as_ref().unwrap() > point)
+                    .copied(),
+                "after {}", point);
+            assert_eq!(
+                sel.prev_boundary_before(&point),
+                bounds.iter().rev().find(|(b, _)| *b.as_ref().unwrap() < point)
+                    .copied(),
+                "before {}", point);
+        }
+    }
+}
+
+#[test]
+fn spans_alternate_and_tile_domain() {
+    let mut small: Selection<i32> = Selection::from([
+        Interval::closed(0, 10),
+        Interval::point(15),
+        Interval::closed(40, 60),
+    ]);
+    small.minus_in_place(Interval::point(5));
+    let large: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+
+    for sel in &[small, large, Selection::empty(), Selection::full()] {
+        for lower in -5..50 {
+            for &width in &[0, 1, 6, 23, 400] {
+                let domain = Interval::closed(lower, lower + width);
+                let spans: Vec<_> = sel.spans(&domain).collect();
+
+                // Spans alternate and each follows directly on the last.
+                for pair in spans.windows(2) {
+                    assert_ne!(pair[0].1, pair[1].1);
+                    assert_eq!(
+                        pair[0].0.supremum().map(|p| p + 1),
+                        pair[1].0.infimum());
+                }
+                let points: Vec<_> = spans.iter()
+                    .flat_map(|(i, c)| i.iter().map(move |p| (p, *c)))
+                    .collect();
+                let expected: Vec<_> = domain.iter()
+                    .map(|p| (p, sel.contains(&p)))
+                    .collect();
+                assert_eq!(points, expected, "domain {:?}", domain);
+            }
+        }
+    }
+
+    let sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    assert_eq!(sel.spans(&Interval::empty()).count(), 0);
+    assert_eq!(sel.spans(&Interval::full()).collect::<Vec<_>>(), [
+        (Interval::closed(i32::MIN, -1), false),
+        (Interval::closed(0, 10), true),
+        (Interval::closed(11, i32::MAX), false),
+    ]);
+}
+
+#[test]
+fn filter_points_matches_contains() {
+    let mut small: Selection<i32> = Selection::from([
+        Interval::closed(0, 10),
+        Interval::point(15),
+        Interval::unbounded_from(40),
+    ]);
+    small.minus_in_place(Interval::point(5));
+    let large: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+
+    for sel in &[small, large, Selection::empty(), Selection::full()] {
+        let points: Vec<i32> = (-20..420).flat_map(|p| vec![p, p]).collect();
+        assert_eq!(
+            sel.filter_points(points.clone()).collect::<Vec<_>>(),
+            points.into_iter().filter(|p| sel.contains(p)).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn classify_points_partitions_stream() {
+    let sel: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+
+    let points: Vec<i32> = (-20..420).step_by(3).collect();
+    let classified: Vec<_> = sel.classify_points(points.clone()).collect();
+    assert_eq!(
+        classified.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+        points);
+    for (point, contained) in classified {
+        assert_eq!(contained, sel.contains(&point), "point {}", point);
+    }
+}
+
+#[test]
+fn contains_sorted_matches_contains() {
+    let mut sel: Selection<i32> = (0..40)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+    sel.minus_in_place(Interval::point(102));
+    sel.union_in_place(Interval::point(-7));
+    sel.union_in_place(Interval::unbounded_from(500));
+
+    let points: Vec<i32> = (-20..520).collect();
+    let contained = sel.contains_sorted(&points);
+    assert_eq!(contained.len(), points.len());
+    for (point, contained) in points.iter().zip(contained) {
+        assert_eq!(contained, sel.contains(point), "point {}", point);
+    }
+    // Repeated points are each checked.
+    assert_eq!(sel.contains_sorted(&[3, 3, 5, 5]), [true, true, false, false]);
+    assert_eq!(Selection::<i32>::empty().contains_sorted(&[1]), [false]);
+}
+
+#[test]
+fn range_queries_match_filter() {
+    let map: BTreeMap<u8, u32> = (0..=u8::MAX)
+        .step_by(3)
+        .map(|k| (k, u32::from(k) * 7))
+        .collect();
+    let mut sel: Selection<u8> = (0..20)
+        .map(|i| Interval::closed(i * 12, i * 12 + 5))
+        .collect();
+    sel.union_in_place(Interval::point(0));
+    sel.union_in_place(Interval::unbounded_from(250));
+
+    let expected: Vec<_> = map.iter().filter(|(k, _)| sel.contains(k)).collect();
+    assert_eq!(sel.range_queries(&map).collect::<Vec<_>>(), expected);
+    assert_eq!(Selection::<u8>::new().range_queries(&map).count(), 0);
+    assert_eq!(Selection::<u8>::full().range_queries(&map).count(), map.len());
+}
+
+#[test]
+fn convert_between_integer_widths() {
+    let mut sel: Selection<u16> = Selection::from(Interval::closed(3, 700));
+    sel.union_in_place(Interval::point(u16::MAX));
+
+    let wide: Selection<u32> = sel.convert();
+    assert_eq!(wide.iter().count(), sel.iter().count());
+    assert!(wide.contains(&u32::from(u16::MAX)));
+    assert!(!wide.contains(&(u32::from(u16::MAX) + 1)));
+    assert_eq!(wide.try_convert::<u16>(), Ok(sel.clone()));
+
+    let signed: Selection<i32> = sel.convert();
+    assert!(signed.try_convert::<i16>().is_err());
+    assert_eq!(signed.try_convert::<u16>(), Ok(sel));
+
+    let negative: Selection<i32> = Selection::from(Interval::closed(-5, 5));
+    assert!(negative.try_convert::<u32>().is_err());
+    assert_eq!(Selection::<i32>::empty().try_convert::<u8>(),
+        Ok(Selection::empty()));
+}
+
+#[test]
+fn clamped_to_type_matches_points() {
+    let mut sel: Selection<i16> = Selection::from(Interval::closed(-300, -2));
+    sel.union_in_place(Interval::closed(0, 3));
+    sel.union_in_place(Interval::closed(200, 400));
+
+    let clamped: Selection<u8> = sel.clamped_to_type();
+    for point in u8::MIN..=u8::MAX {
+        assert_eq!(clamped.contains(&point), sel.contains(&i16::from(point)));
+    }
+    let signed: Selection<i8> = sel.clamped_to_type();
+    for point in i8::MIN..=i8::MAX {
+        assert_eq!(signed.contains(&point), sel.contains(&i16::from(point)));
+    }
+    let wide: Selection<i64> = sel.clamped_to_type();
+    assert_eq!(wide, sel.convert());
+    assert_eq!(Selection::<i64>::full().clamped_to_type::<u16>(),
+        Selection::full());
+    assert!(Selection::from(Interval::closed(-9_i64, -1))
+        .clamped_to_type::<u32>()
+        .is_empty());
+}
+
+#[test]
+fn integer_width_conversion_traits() {
+    let sel: Selection<u8> = Selection::from([
+        Interval::closed(3, 20),
+        Interval::point(255),
+    ]);
+    let wide = Selection::<i64>::from(sel.clone());
+    assert!(wide.contains(&255));
+    assert_eq!(Selection::<u8>::try_from(wide.clone()), Ok(sel));
+    assert!(Selection::<i8>::try_from(wide).is_err());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Map operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn remove_and_retain_keys_partition_map() {
+    let map: BTreeMap<i16, usize> = (-300..300)
+        .step_by(7)
+        .enumerate()
+        .map(|(idx, k)| (k, idx))
+        .collect();
+    let mut sel: Selection<i16> = (0..10)
+        .map(|i| Interval::closed(i * 50 - 260, i * 50 - 230))
+        .collect();
+    sel.union_in_place(Interval::unbounded_to(-290));
+    sel.union_in_place(Interval::point(294));
+
+    let mut removed = map.clone();
+    remove_keys_in(&mut removed, &sel);
+    let mut retained = map.clone();
+    retain_keys_in(&mut retained, &sel);
+
+    for (k, v) in &map {
+        assert_eq!(removed.get(k), (!sel.contains(k)).then_some(v));
+        assert_eq!(retained.get(k), sel.contains(k).then_some(v));
+    }
+    assert_eq!(removed.len() + retained.len(), map.len());
+
+    let mut all = map.clone();
+    remove_keys_in(&mut all, &Selection::new());
+    assert_eq!(all, map);
+    retain_keys_in(&mut all, &Selection::new());
+    assert!(all.is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// JSON schema tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature="schemars")]
+#[test]
+fn json_schema_matches_serialized_form() {
+    use serde_json::Value;
+
+    // Returns the names of the variants of an externally tagged enum schema.
+    fn variants(schema: &Value) -> Vec<&str> {
+        schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["required"][0].as_str()
+                .or_else(|| v["const"].as_str())
+                .unwrap())
+            .collect()
+    }
+
+    let schema = schemars::schema_for!(Selection<i32>);
+    let schema = schema.as_value();
+    assert_eq!(schema["type"], "array");
+    assert_eq!(schema["items"]["$ref"], "#/$defs/Tine");
+    let tines = variants(&schema["$defs"]["Tine"]);
+    let bounds = variants(&schema["$defs"]["Bound"]);
+    assert_eq!(tines, ["Lower", "Point", "Upper"]);
+    assert_eq!(bounds, ["Include", "Exclude", "Infinite"]);
+
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    sel.union_in_place(Interval::point(20));
+    sel.union_in_place(Interval::unbounded_from(30));
+    let value = serde_json::to_value(&sel).unwrap();
+    for item in value.as_array().unwrap() {
+        let (tine, bound) = item.as_object().unwrap().iter().next().unwrap();
+        assert!(tines.contains(&tine.as_str()));
+        let bound = bound.as_str().or_else(|| bound
+            .as_object()
+            .and_then(|b| b.keys().next())
+            .map(String::as_str));
+        assert!(bounds.contains(&bound.unwrap()));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Heavyweight point tests
+////////////////////////////////////////////////////////////////////////////////
+
+thread_local! {
+    /// The number of times a `Counted` point has been cloned on this thread.
+    static CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A point which counts its clones, standing in for heavyweight points such
+/// as big integers.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Counted(i64);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Self(self.0)
+    }
+}
+
+impl Normalize for RawInterval<Counted> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+impl crate::measure::Measurable for Counted {
+    type Length = u64;
+
+    fn span(lower: &Self, upper: &Self) -> Self::Length {
+        upper.0.abs_diff(lower.0)
+    }
+}
+
+/// Returns the number of `Counted` clones made by the given function.
+fn clones_in<F: FnOnce()>(f: F) -> usize {
+    let before = CLONES.with(std::cell::Cell::get);
+    f();
+    CLONES.with(std::cell::Cell::get) - before
+}
+
+#[test]
+fn heavyweight_operations_do_not_clone_neighbors() {
+    let mut sel = Selection::from(Interval::closed(Counted(0), Counted(10)));
+    let interval = Interval::closed(Counted(20), Counted(30));
+    assert_eq!(clones_in(|| { let _ = interval.size(); }), 0);
+    assert_eq!(clones_in(|| sel.union_in_place(interval)), 0);
+    assert_eq!(clones_in(|| sel.minus_in_place(
+        Interval::closed(Counted(5), Counted(25)))), 0);
+
+    // Only the bounds of the resulting intervals are cloned, once each.
+    let clones = clones_in(|| sel.intersect_in_place(
+        Interval::closed(Counted(2), Counted(28))));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::right_open(Counted(2), Counted(5)),
+        Interval::left_open(Counted(25), Counted(28)),
+    ]);
+    assert!(clones <= 4, "{} clones for 4 bounds", clones);
+    let clones = clones_in(|| { let _ = sel.measure(); });
+    assert!(clones <= 4, "{} clones for 4 bounds", clones);
+}
@@ -0,0 +1,64 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for sorted `Interval` iterators.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::sorted_iter::AssumeSorted;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Construction tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn from_sorted_disjoint_matches_collect() {
+    let mut sel: Selection<i8> = Selection::new();
+    for start in (-120..120).step_by(7) {
+        sel.union_in_place(Interval::closed(start, start + (start % 4).abs()));
+    }
+    sel.union_in_place(Interval::unbounded_to(-125));
+    sel.minus_in_place(Interval::point(10));
+
+    assert_eq!(Selection::from_sorted_disjoint(sel.interval_iter()), sel);
+    assert_eq!(
+        Selection::from_sorted_disjoint(sel.clone().into_interval_iter()),
+        sel);
+    assert_eq!(
+        Selection::from_sorted_disjoint(sel.interval_iter().skip(3).take(5)),
+        sel.interval_iter().skip(3).take(5).collect::<Selection<_>>());
+}
+
+#[test]
+fn from_sorted_disjoint_merges_adjacent() {
+    let intervals = vec![
+        Interval::empty(),
+        Interval::closed(0, 4),
+        Interval::closed(5, 9),
+        Interval::empty(),
+        Interval::point(10),
+        Interval::right_open(12, 15),
+        Interval::closed(15, 20),
+    ];
+    let sel = Selection::from_sorted_disjoint(AssumeSorted::new(
+        intervals.into_iter()));
+
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 10),
+        Interval::closed(12, 20),
+    ]);
+    assert_eq!(sel, Selection::from([
+        Interval::closed(0, 10),
+        Interval::closed(12, 20),
+    ]));
+}
@@ -0,0 +1,100 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for piecewise-constant functions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::step_fn::StepFn;
+
+
+/// Returns a pseudo-random `StepFn` over `u8` points, along with its value at
+/// each point.
+fn step_fn(state: &mut u32, sets: usize) -> (StepFn<u8, u8>, Vec<u8>) {
+    let mut f = StepFn::constant(0);
+    let mut model = vec![0; 256];
+    for _ in 0..sets {
+        *state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        let [a, b, value, ..] = (*state >> 8).to_le_bytes();
+        let (lower, upper, value) = (a.min(b), a.max(b), value % 4);
+        f.set(Interval::closed(lower, upper), value);
+        for p in lower..=upper {
+            model[usize::from(p)] = value;
+        }
+    }
+    (f, model)
+}
+
+/// Asserts that the `StepFn` has the modelled value at each point, and that
+/// its pieces are ordered, maximal, and cover every point.
+fn assert_matches_model<V>(f: &StepFn<u8, V>, model: &[V])
+    where V: PartialEq + std::fmt::Debug
+{
+    for p in 0..=u8::MAX {
+        assert_eq!(f.eval(&p), &model[usize::from(p)], "{:?} {}", f, p);
+    }
+    let pieces: Vec<_> = f.iter().collect();
+    assert_eq!(pieces.first().and_then(|(i, _)| i.infimum()), Some(0));
+    assert_eq!(pieces.last().and_then(|(i, _)| i.supremum()), Some(255));
+    for pair in pieces.windows(2) {
+        let ((prev, prev_value), (next, next_value)) = (pair[0], pair[1]);
+        assert_eq!(prev.supremum().map(|p| p + 1), next.infimum());
+        assert_ne!(prev_value, next_value);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// StepFn tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_matches_model() {
+    let mut state: u32 = 0x5eed_1234;
+    for sets in 0..40 {
+        let (f, model) = step_fn(&mut state, sets);
+        assert_matches_model(&f, &model);
+    }
+}
+
+#[test]
+fn zip_with_matches_model() {
+    let mut state: u32 = 0x0dd_ba11;
+    for sets in 0..40 {
+        let (f, f_model) = step_fn(&mut state, sets);
+        let (g, g_model) = step_fn(&mut state, sets / 2);
+        let h = f.zip_with(&g, |a, b| a.max(b) - a.min(b));
+        let h_model: Vec<_> = f_model
+            .iter()
+            .zip(g_model.iter())
+            .map(|(a, b)| a.max(b) - a.min(b))
+            .collect();
+        assert_matches_model(&h, &h_model);
+    }
+}
+
+#[test]
+fn set_edge_cases() {
+    let mut f: StepFn<u8, bool> = StepFn::constant(false);
+    f.set(Interval::empty(), true);
+    assert!(f.is_constant());
+    f.set(Interval::full(), true);
+    assert_eq!(f, StepFn::constant(true));
+    f.set(Interval::point(7), false);
+    f.set(Interval::point(7), true);
+    assert_eq!(f.piece_count(), 1);
+    f.set(Interval::open(0, 2), false);
+    assert_eq!(f.into_pieces(), [
+        (Interval::point(0), true),
+        (Interval::point(1), false),
+        (Interval::closed(2, 255), true),
+    ]);
+}
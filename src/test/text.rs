@@ -0,0 +1,79 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for text positions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::text::Position;
+use crate::text::span;
+use crate::text::span_bounds;
+use crate::text::span_list;
+use crate::text::spans;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Position tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn position_steps_across_lines() {
+    let end_of_line = Position::new(2, usize::MAX);
+
+    assert_eq!(end_of_line.succ(), Some(Position::new(3, 0)));
+    assert_eq!(Position::new(3, 0).pred(), Some(end_of_line));
+    assert_eq!(Position::new(3, 4).pred(), Some(Position::new(3, 3)));
+    assert_eq!(Position::MINIMUM.pred(), None);
+    assert_eq!(Position::MAXIMUM.succ(), None);
+    assert!(Position::new(1, 9) < Position::new(2, 0));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn span_of_reversed_selection() {
+    let anchor = Position::new(4, 2);
+    let head = Position::new(1, 8);
+
+    assert_eq!(span(anchor, head), span(head, anchor));
+    assert_eq!(span_bounds(&span(anchor, head)), Some((head, anchor)));
+    assert!(span(anchor, anchor).is_empty());
+}
+
+#[test]
+fn span_bounds_at_line_end() {
+    let start = Position::new(0, 3);
+    let last = Position::new(0, usize::MAX);
+
+    assert_eq!(span_bounds(&Interval::closed(start, last)),
+        Some((start, Position::new(1, 0))));
+    assert_eq!(span_bounds(&Interval::unbounded_from(start)), None);
+    assert_eq!(span_bounds(&Interval::empty()), None);
+}
+
+#[test]
+fn spans_merge_multiple_cursors() {
+    let sel = spans([
+        (Position::new(0, 0), Position::new(0, 4)),
+        (Position::new(0, 4), Position::new(0, 9)),
+        (Position::new(5, 0), Position::new(3, 2)),
+        (Position::new(7, 7), Position::new(7, 7)),
+    ]);
+
+    assert_eq!(span_list(&sel), [
+        (Position::new(0, 0), Position::new(0, 9)),
+        (Position::new(3, 2), Position::new(5, 0)),
+    ]);
+}
@@ -17,4 +17,7 @@
 // Module declarations.
 mod intersect;
 mod minus;
+mod model;
+mod storage;
+mod toggle;
 mod union;
@@ -54,6 +54,24 @@ fn nested_aggregation() {
     assert_eq!(t.into_iter().collect::<Vec<_>>(), [Point(25)]);
 }
 
+#[test]
+fn enclosing_intersection() {
+    let mut t: TineTree<i32> = Open(5, 10).into();
+    t.union_in_place(&Point(15));
+    t.union_in_place(&Closed(20, 25));
+
+    t.intersect_in_place(&Open(0, 30));
+    assert_eq!(t.clone().into_iter().collect::<Vec<_>>(),
+        [Open(5, 10), Point(15), Closed(20, 25)]);
+
+    t.intersect_in_place(&Closed(5, 20));
+    assert_eq!(t.clone().into_iter().collect::<Vec<_>>(),
+        [Open(5, 10), Point(15), Point(20)]);
+
+    t.intersect_in_place(&Open(12, 20));
+    assert_eq!(t.into_iter().collect::<Vec<_>>(), [Point(15)]);
+}
+
 
 
 ////////////////////////////////////////////////////////////////////////////////
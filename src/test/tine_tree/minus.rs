@@ -18,6 +18,21 @@ use crate::raw_interval::RawInterval::*;
 // Aggregation tests
 ////////////////////////////////////////////////////////////////////////////////
 
+#[test]
+fn point_exclusion_aggregation() {
+    let mut t: TineTree<i32> = Open(0, 5).into();
+    t.union_in_place(&Open(5, 10));
+
+    t.minus_in_place(&Open(0, 5));
+    assert_eq!(t.clone().into_iter().collect::<Vec<_>>(), [Open(5, 10)]);
+
+    let mut t: TineTree<i32> = Open(0, 5).into();
+    t.union_in_place(&Open(5, 10));
+
+    t.minus_in_place(&Open(5, 10));
+    assert_eq!(t.into_iter().collect::<Vec<_>>(), [Open(0, 5)]);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Non-mutating minus tests.
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,146 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Exhaustive model tests for [`TineTree`].
+//!
+//! Every operation is checked against a brute-force model of the points of
+//! each tree. Intervals are bounded at even points, and the model samples
+//! every point from `-7` to `7`, so that the odd points stand in for the
+//! points strictly between bounds and beyond them.
+//!
+//! [`TineTree`] struct.TineTree.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::bound::Bound;
+use crate::raw_interval::RawInterval;
+use crate::tine_tree::TineTree;
+
+
+/// The sampled points of the model.
+const SAMPLES: std::ops::RangeInclusive<i8> = -7..=7;
+
+/// Returns every distinct interval bounded at the given points.
+fn intervals(points: &[i8]) -> Vec<RawInterval<i8>> {
+    let mut bounds = vec![Bound::Infinite];
+    for &p in points {
+        bounds.push(Bound::Include(p));
+        bounds.push(Bound::Exclude(p));
+    }
+    let mut intervals = Vec::new();
+    for lower in &bounds {
+        for upper in &bounds {
+            let interval = RawInterval::new(*lower, *upper);
+            if !intervals.contains(&interval) {
+                intervals.push(interval);
+            }
+        }
+    }
+    intervals
+}
+
+/// Returns the sampled points contained by the given predicate as a bit set.
+fn model<F>(contains: F) -> u16 where F: Fn(&i8) -> bool {
+    SAMPLES
+        .enumerate()
+        .filter(|(_, p)| contains(p))
+        .fold(0, |bits, (i, _)| bits | 1 << i)
+}
+
+/// Returns `true` if the bit set is a single nonempty run of bits.
+fn is_run(bits: u16) -> bool {
+    bits != 0 && ((bits >> bits.trailing_zeros()) + 1).is_power_of_two()
+}
+
+/// Returns the sampled points of the tree as a bit set, checking that its
+/// intervals are nonempty, ordered, and separated by excluded points.
+fn checked_model(tree: &TineTree<i8>) -> u16 {
+    let mut bits = 0;
+    let mut last: Option<RawInterval<i8>> = None;
+    for interval in tree.interval_iter() {
+        let current = model(|p| interval.contains(p));
+        assert!(is_run(current), "{:?} of {:?}", interval, tree);
+        if let Some(last) = last {
+            let previous = model(|p| last.contains(p));
+            assert!(previous < current, "{:?} after {:?}", last, interval);
+            assert!(!is_run(previous | current),
+                "{:?} unmerged with {:?}", last, interval);
+        }
+        bits |= current;
+        last = Some(interval);
+    }
+    assert_eq!(bits, model(|p| tree.contains(p)));
+    bits
+}
+
+/// Checks every operation of the tree with the given interval.
+fn check_operations(tree: &TineTree<i8>, interval: RawInterval<i8>) {
+    let a = checked_model(tree);
+    let b = model(|p| interval.contains(p));
+    let other = TineTree::from(interval);
+    let all = model(|_| true);
+
+    let mut union = tree.clone();
+    union.union_in_place(&interval);
+    assert_eq!(checked_model(&union), a | b, "{:?} | {:?}", tree, interval);
+    assert_eq!(checked_model(&tree.union(&other)), a | b);
+
+    let mut intersect = tree.clone();
+    intersect.intersect_in_place(&interval);
+    assert_eq!(checked_model(&intersect), a & b,
+        "{:?} & {:?}", tree, interval);
+    assert_eq!(checked_model(&tree.intersect(&other)), a & b);
+
+    let mut minus = tree.clone();
+    minus.minus_in_place(&interval);
+    assert_eq!(checked_model(&minus), a & !b,
+        "{:?} - {:?}", tree, interval);
+    assert_eq!(checked_model(&tree.minus(&other)), a & !b);
+
+    let mut toggle = tree.clone();
+    toggle.toggle_in_place(&interval);
+    assert_eq!(checked_model(&toggle), a ^ b,
+        "{:?} ^ {:?}", tree, interval);
+
+    assert_eq!(checked_model(&tree.complement()), all & !a);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Model tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn single_interval_operations_match_model() {
+    let intervals = intervals(&[-6, -4, -2, 0, 2, 4, 6]);
+    for a in &intervals {
+        let tree = TineTree::from(*a);
+        for b in &intervals {
+            check_operations(&tree, *b);
+        }
+    }
+}
+
+#[test]
+fn two_interval_operations_match_model() {
+    // The trees are bounded at fewer points, so that the operands may also
+    // be bounded between them.
+    let tree_intervals = intervals(&[-4, 0, 4]);
+    let intervals = intervals(&[-4, -2, 0, 2, 4]);
+    for a in &tree_intervals {
+        for c in &tree_intervals {
+            let mut tree = TineTree::from(*a);
+            tree.union_in_place(c);
+            for b in &intervals {
+                check_operations(&tree, *b);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+// Standard library imports.
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+
+/// Returns the hash of the given `TineTree`.
+fn hash_of(t: &TineTree<i32>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Storage representation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn grow_past_small_capacity() {
+    let mut t: TineTree<i32> = TineTree::new();
+    for i in (0..50).rev() {
+        t.union_in_place(&Closed(i * 4, i * 4 + 1));
+    }
+
+    assert_eq!(t.interval_iter().count(), 50);
+    assert_eq!(
+        t.interval_iter().collect::<Vec<_>>(),
+        (0..50).map(|i| Closed(i * 4, i * 4 + 1)).collect::<Vec<_>>());
+    assert_eq!(
+        t.interval_iter().next_back(),
+        Some(Closed(196, 197)));
+}
+
+#[test]
+fn reverse_iteration_over_point_exclusions() {
+    let mut t: TineTree<i32> = TineTree::from(Open(0, 10));
+    t.union_in_place(&Open(10, 20));
+    t.union_in_place(&Open(20, 30));
+
+    assert_eq!(
+        t.interval_iter().rev().collect::<Vec<_>>(),
+        [Open(20, 30), Open(10, 20), Open(0, 10)]);
+
+    let mut iter = t.into_iter();
+    assert_eq!(iter.next(), Some(Open(0, 10)));
+    assert_eq!(iter.next_back(), Some(Open(20, 30)));
+    assert_eq!(iter.next(), Some(Open(10, 20)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn partial_iteration_around_point_exclusions() {
+    let mut t: TineTree<i32> = TineTree::from(Open(0, 10));
+    t.union_in_place(&Open(10, 20));
+    t.union_in_place(&Open(20, 30));
+
+    assert_eq!(
+        t.interval_iter_from(&Point(15)).collect::<Vec<_>>(),
+        [Open(10, 20), Open(20, 30)]);
+    assert_eq!(
+        t.interval_iter_to(&Point(5)).collect::<Vec<_>>(),
+        [Open(0, 10)]);
+    assert_eq!(
+        t.interval_iter_to(&Point(10)).rev().collect::<Vec<_>>(),
+        [Open(0, 10)]);
+    assert_eq!(
+        t.interval_iter_to(&Point(25)).rev().collect::<Vec<_>>(),
+        [Open(20, 30), Open(10, 20), Open(0, 10)]);
+    assert_eq!(t.interval_iter_to(&Empty).count(), 0);
+}
+
+#[test]
+fn shrink_below_small_capacity() {
+    let mut t: TineTree<i32> = TineTree::new();
+    for i in 0..50 {
+        t.union_in_place(&Closed(i * 4, i * 4 + 1));
+    }
+    t.intersect_in_place(&Closed(0, 9));
+
+    let mut small: TineTree<i32> = TineTree::new();
+    small.union_in_place(&Closed(0, 1));
+    small.union_in_place(&Closed(4, 5));
+    small.union_in_place(&Closed(8, 9));
+
+    assert_eq!(t, small);
+    assert_eq!(hash_of(&t), hash_of(&small));
+}
+
+#[test]
+fn equality_across_representations() {
+    let mut large: TineTree<i32> = TineTree::new();
+    for i in 0..50 {
+        large.union_in_place(&Point(i * 2));
+    }
+    large.minus_in_place(&Closed(10, 100));
+
+    let mut small: TineTree<i32> = TineTree::new();
+    for i in 0..5 {
+        small.union_in_place(&Point(i * 2));
+    }
+
+    assert_eq!(large, small);
+    assert_eq!(hash_of(&large), hash_of(&small));
+    assert_eq!(format!("{large:?}"), format!("{small:?}"));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Split tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn split_off_partitions_tree() {
+    let mut t: TineTree<i32> = TineTree::new();
+    for i in 0..50 {
+        t.union_in_place(&Closed(i * 4, i * 4 + 1));
+    }
+    let mut below = t.clone();
+    let above = below.split_off(&101);
+    assert_eq!(below.interval_iter().count(), 26);
+    assert_eq!(below.interval_iter().next_back(), Some(RightOpen(100, 101)));
+    assert_eq!(above.interval_iter().next(), Some(Point(101)));
+    assert_eq!(below.union(&above), t);
+
+    let mut t: TineTree<i32> = TineTree::from(Open(0, 10));
+    t.union_in_place(&Open(10, 20));
+    let above = t.split_off(&10);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Open(0, 10)]);
+    assert_eq!(above.interval_iter().collect::<Vec<_>>(), [Open(10, 20)]);
+
+    let mut t: TineTree<i32> = TineTree::from(Full);
+    let above = t.split_off(&0);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [UpTo(0)]);
+    assert_eq!(above.interval_iter().collect::<Vec<_>>(), [From(0)]);
+}
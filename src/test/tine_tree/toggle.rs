@@ -0,0 +1,112 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::raw_interval::RawInterval;
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+
+/// Returns a copy of the tree with the given interval toggled.
+fn toggled(tree: &TineTree<i32>, interval: RawInterval<i32>) -> TineTree<i32> {
+    let mut tree = tree.clone();
+    tree.toggle_in_place(&interval);
+    tree
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Toggle tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn empty() {
+    let a: TineTree<i32> = Empty.into();
+
+    assert_eq_i!(toggled(&a, Empty),        []);
+    assert_eq_i!(toggled(&a, Point(3)),     [Point(3)]);
+    assert_eq_i!(toggled(&a, Open(0, 3)),   [Open(0, 3)]);
+    assert_eq_i!(toggled(&a, UpTo(3)),      [UpTo(3)]);
+    assert_eq_i!(toggled(&a, Full),         [Full]);
+}
+
+#[test]
+fn full() {
+    let a: TineTree<i32> = Full.into();
+
+    assert_eq_i!(toggled(&a, Empty),        [Full]);
+    assert_eq_i!(toggled(&a, Point(3)),     [UpTo(3), UpFrom(3)]);
+    assert_eq_i!(toggled(&a, Closed(0, 3)), [UpTo(0), UpFrom(3)]);
+    assert_eq_i!(toggled(&a, To(3)),        [UpFrom(3)]);
+    assert_eq_i!(toggled(&a, Full),         []);
+}
+
+#[test]
+fn closed_center() {
+    let a: TineTree<i32> = Closed(0, 5).into();
+
+    assert_eq_i!(toggled(&a, Point(0)),     [LeftOpen(0, 5)]);
+    assert_eq_i!(toggled(&a, Point(3)),     [RightOpen(0, 3), LeftOpen(3, 5)]);
+    assert_eq_i!(toggled(&a, Open(0, 5)),   [Point(0), Point(5)]);
+    assert_eq_i!(toggled(&a, Closed(0, 5)), []);
+    assert_eq_i!(toggled(&a, Closed(3, 8)), [RightOpen(0, 3), LeftOpen(5, 8)]);
+    assert_eq_i!(toggled(&a, Open(-2, 2)),  [Open(-2, 0), Closed(2, 5)]);
+    assert_eq_i!(toggled(&a, UpFrom(5)),    [From(0)]);
+    assert_eq_i!(toggled(&a, From(5)),      [RightOpen(0, 5), UpFrom(5)]);
+    assert_eq_i!(toggled(&a, Full),         [UpTo(0), UpFrom(5)]);
+}
+
+#[test]
+fn spanning_gaps() {
+    let a: TineTree<i32> = [Closed(0, 2), Point(4), Open(6, 8)]
+        .iter()
+        .copied()
+        .collect();
+
+    assert_eq_i!(toggled(&a, Closed(1, 7)), [
+        RightOpen(0, 1),
+        Open(2, 4),
+        LeftOpen(4, 6),
+        Open(7, 8),
+    ]);
+    assert_eq_i!(toggled(&a, Full), [
+        UpTo(0),
+        Open(2, 4),
+        LeftOpen(4, 6),
+        From(8),
+    ]);
+}
+
+#[test]
+fn toggle_twice_restores() {
+    let a: TineTree<i32> = [Closed(0, 2), Point(4), Open(6, 8), From(12)]
+        .iter()
+        .copied()
+        .collect();
+    let toggles = [
+        Point(4), Point(5), Closed(1, 7), Open(2, 6), LeftOpen(8, 12),
+        UpTo(3), From(7), Full,
+    ];
+
+    for interval in &toggles {
+        let b = toggled(&toggled(&a, *interval), *interval);
+        assert_eq_i!(b.interval_iter(), a.interval_iter().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn large_toggle_is_complement() {
+    let a: TineTree<i32> = (0..40)
+        .map(|i| Closed(i * 4, i * 4 + 1))
+        .collect();
+
+    assert_eq_i!(toggled(&a, Full), a.complement().interval_iter()
+        .collect::<Vec<_>>());
+}
@@ -0,0 +1,95 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`UnitTable`].
+//!
+//! [`UnitTable`] struct.UnitTable.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::raw_interval::IntervalParseError;
+use crate::units::UnitParseError;
+use crate::units::UnitTable;
+
+// Standard library imports.
+use std::time::Duration;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Value parsing tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn parse_value_errors() {
+    let units = UnitTable::bytes();
+
+    assert!(matches!(units.parse_value("k"),
+        Err(UnitParseError::InvalidMagnitude(_))));
+    assert_eq!(units.parse_value("4q"), Err(UnitParseError::UnknownUnit));
+    assert_eq!(units.parse_value("4kib"), Err(UnitParseError::UnknownUnit));
+    assert_eq!(units.parse_value("16E"), Err(UnitParseError::Overflow));
+    assert_eq!(units.parse_value("15E"), Ok(15 << 60));
+}
+
+#[test]
+fn parse_value_duration_extremes() {
+    let units = UnitTable::durations();
+
+    assert_eq!(units.parse_value("0s"), Ok(Duration::ZERO));
+    assert_eq!(
+        units.parse_value(&format!("{}ns", u64::MAX)),
+        Ok(Duration::from_nanos(u64::MAX)));
+    assert_eq!(
+        units.parse_value(&format!("{}d", u64::MAX)),
+        Err(UnitParseError::Overflow));
+}
+
+#[test]
+fn with_unit_replaces() {
+    let units = UnitTable::new()
+        .with_unit("x", 2u32)
+        .with_unit("x", 3);
+
+    assert_eq!(units.unit("x"), Some(&3));
+    assert_eq!(units.parse_value("5x"), Ok(15));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval parsing tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn parse_interval_edges() {
+    let units = UnitTable::bytes();
+
+    assert_eq!(units.parse_interval(" (4k, 8k] "),
+        Ok(Interval::closed(4097, 8192)));
+    assert_eq!(units.parse_interval("(-∞, 1k)"),
+        Ok(Interval::closed(0, 1023)));
+    assert_eq!(units.parse_interval("4k"), Ok(Interval::point(4096)));
+    assert_eq!(units.parse_interval("[8k, 4k]"), Ok(Interval::empty()));
+    assert_eq!(units.parse_interval("Ø"), Ok(Interval::empty()));
+    assert_eq!(units.parse_interval("[4k 8k]"),
+        Err(IntervalParseError::InvalidInterval));
+    assert_eq!(units.parse_interval("[4k, 8q]"),
+        Err(IntervalParseError::InvalidValue(UnitParseError::UnknownUnit)));
+}
+
+#[test]
+fn parse_interval_durations() {
+    let units = UnitTable::durations();
+
+    assert_eq!(units.parse_interval("[1s, 1min)"),
+        Ok(Interval::right_open(
+            Duration::from_secs(1),
+            Duration::from_mins(1))));
+}
@@ -0,0 +1,121 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for version requirement conversions.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::version::caret;
+use crate::version::parse_requirement;
+use crate::version::tilde;
+
+// External library imports.
+use semver::Version;
+
+
+/// Parses a version, panicking on failure.
+fn v(s: &str) -> Version {
+    Version::parse(s).expect("valid version")
+}
+
+/// Asserts that the given requirement contains exactly the listed versions
+/// among the given candidates.
+fn assert_matches(req: &str, matching: &[&str], excluded: &[&str]) {
+    let sel = parse_requirement(req).expect("valid requirement");
+    for version in matching {
+        assert!(sel.contains(&v(version)), "{} should match {}", req, version);
+    }
+    for version in excluded {
+        assert!(!sel.contains(&v(version)), "{} should not match {}",
+            req, version);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Comparator tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn caret_requirements() {
+    assert_matches("^1.2.3",
+        &["1.2.3", "1.2.3+build", "1.9.0"],
+        &["1.2.3-rc.1", "1.2.2", "2.0.0-alpha", "2.0.0"]);
+    assert_matches("^0.2.3", &["0.2.3", "0.2.9"], &["0.3.0-0", "0.3.0"]);
+    assert_matches("^0.0.3", &["0.0.3"], &["0.0.4-0", "0.0.4"]);
+    assert_matches("^0.0", &["0.0.0", "0.0.7"], &["0.1.0"]);
+    assert_matches("^1.2.3-beta.2",
+        &["1.2.3-beta.2", "1.2.3-rc", "1.2.3"],
+        &["1.2.3-beta.1", "2.0.0-0"]);
+
+    assert_eq!(caret(&v("1.2.3")), Interval::right_open(
+        v("1.2.3"),
+        v("2.0.0-0")));
+}
+
+#[test]
+fn tilde_requirements() {
+    assert_matches("~1.2.3", &["1.2.3", "1.2.9"], &["1.2.2", "1.3.0-0"]);
+    assert_matches("~1.2", &["1.2.0", "1.2.9"], &["1.3.0"]);
+    assert_matches("~1", &["1.0.0", "1.9.9"], &["2.0.0-rc.1"]);
+
+    assert_eq!(tilde(&v("1.2.3")), Interval::right_open(
+        v("1.2.3"),
+        v("1.3.0-0")));
+}
+
+#[test]
+fn comparison_requirements() {
+    assert_matches(">1.2.3", &["1.2.4", "3.0.0"], &["1.2.3", "1.2.3+build"]);
+    assert_matches(">1.2.3-alpha", &["1.2.3-beta", "1.2.3"], &["1.2.3-alpha"]);
+    assert_matches(">1.2.3", &["1.2.4-alpha", "1.2.4-0"], &["1.2.3-alpha"]);
+    assert_matches(">1.2", &["1.3.0", "1.3.0-0"], &["1.2.9", "1.2.9-rc"]);
+    assert_matches(">=1.2", &["1.2.0", "4.0.0"], &["1.2.0-rc", "1.1.9"]);
+    assert_matches("<1.2.3", &["1.2.2"], &["1.2.3-alpha", "1.2.3"]);
+    assert_matches("<=1.2.3", &["1.2.3", "1.2.3+build"], &["1.2.4-0"]);
+    assert_matches("<=1", &["1.9.9"], &["2.0.0-0"]);
+    assert_matches("=1.2.3", &["1.2.3", "1.2.3+build"], &["1.2.4-0"]);
+    assert_matches("=1.2", &["1.2.0", "1.2.7"], &["1.3.0-0"]);
+    assert_matches("1.2.*", &["1.2.0", "1.2.7"], &["1.3.0"]);
+}
+
+#[test]
+fn compound_requirements() {
+    assert_matches(">=1, <2", &["1.0.0", "1.9.9"], &["2.0.0-0", "0.9.0"]);
+    assert_matches("*", &["0.0.0", "9.9.9"], &[]);
+    assert_matches(">=2, <1", &[], &["1.5.0", "2.0.0"]);
+}
+
+#[test]
+fn strict_and_inclusive_lower_bounds_agree() {
+    // `>1.2.3` is `>=1.2.3` without the versions matching `=1.2.3`.
+    let greater = parse_requirement(">1.2.3").expect("valid requirement");
+    let greater_eq = parse_requirement(">=1.2.3").expect("valid requirement");
+    let exact = parse_requirement("=1.2.3").expect("valid requirement");
+
+    assert_eq!(greater_eq.minus(&exact), greater);
+    assert_eq!(greater.intersect(&greater_eq), greater);
+    assert!(greater.contains(&v("1.2.4-alpha")));
+    assert!(greater_eq.contains(&v("1.2.4-alpha")));
+}
+
+#[test]
+fn intersect_requirements() {
+    let a = parse_requirement("^1.2").expect("valid requirement");
+    let b = parse_requirement("~1.4.1").expect("valid requirement");
+    let both = a.intersect(&b);
+
+    assert_eq!(both.interval_iter().collect::<Vec<_>>(), [
+        Interval::right_open(v("1.4.1"), v("1.5.0-0")),
+    ]);
+    assert!(a.intersect(&parse_requirement("^2").expect("valid requirement"))
+        .is_empty());
+}
@@ -0,0 +1,224 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a line and column `Position` type for selecting ranges of text.
+//!
+//! Editors describe a selection by its anchor, where the selection started,
+//! and its head, where the cursor is. The head may come before the anchor,
+//! and a selection whose anchor and head are equal is a caret which selects
+//! no text. The functions of this module convert such selections into the
+//! half-open `Interval<Position>` of the text they cover.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// External library imports.
+#[cfg(feature="serde")] use serde::Deserialize;
+#[cfg(feature="serde")] use serde::Serialize;
+
+// Standard library imports.
+use std::fmt;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Position
+////////////////////////////////////////////////////////////////////////////////
+/// A position in a text, given by a zero-based line and column.
+///
+/// Positions are ordered by line and then by column. Lines have no maximum
+/// length, so the successor of the last column of a line is the first column
+/// of the next line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+pub struct Position {
+    /// The zero-based line number.
+    pub line: usize,
+    /// The zero-based column number.
+    pub column: usize,
+}
+
+impl Position {
+    /// Constructs a new `Position` from the given line and column.
+    #[must_use]
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+impl Finite for Position {
+    const MINIMUM: Self = Self::new(0, 0);
+    const MAXIMUM: Self = Self::new(usize::MAX, usize::MAX);
+
+    fn pred(&self) -> Option<Self> {
+        if self.column == 0 {
+            self.line.checked_sub(1).map(|line| Self::new(line, usize::MAX))
+        } else {
+            Some(Self::new(self.line, self.column - 1))
+        }
+    }
+
+    fn succ(&self) -> Option<Self> {
+        if self.column == usize::MAX {
+            self.line.checked_add(1).map(|line| Self::new(line, 0))
+        } else {
+            Some(Self::new(self.line, self.column + 1))
+        }
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((line, column): (usize, usize)) -> Self {
+        Self::new(line, column)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection conversions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the `Interval` of text covered by the selection with the given
+/// anchor and head. The `Interval` includes the earlier `Position` and
+/// excludes the later one, and is empty if the two are equal.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::text::Position;
+/// # use normalize_interval::text::span;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Position::new(1, 4);
+/// let b = Position::new(3, 0);
+///
+/// assert_eq!(span(a, b), Interval::right_open(a, b));
+/// assert_eq!(span(b, a), Interval::right_open(a, b));
+/// assert_eq!(span(a, a), Interval::empty());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn span(anchor: Position, head: Position) -> Interval<Position> {
+    if anchor == head { return Interval::empty(); }
+    Interval::right_open(anchor.min(head), anchor.max(head))
+}
+
+/// Returns the `Selection` of text covered by the given anchor and head
+/// pairs. Overlapping and adjacent selections are merged, and carets select
+/// nothing.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::text::Position;
+/// # use normalize_interval::text::spans;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel = spans([
+///     (Position::new(0, 2), Position::new(0, 6)),
+///     (Position::new(1, 0), Position::new(0, 5)),
+///     (Position::new(4, 1), Position::new(4, 1)),
+/// ]);
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::right_open(Position::new(0, 2), Position::new(1, 0)),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn spans<I>(selections: I) -> Selection<Position>
+    where I: IntoIterator<Item=(Position, Position)>
+{
+    selections
+        .into_iter()
+        .map(|(anchor, head)| span(anchor, head))
+        .collect()
+}
+
+/// Returns the start and end `Position`s of the text covered by the given
+/// `Interval`, where the end `Position` is excluded. Returns `None` if the
+/// `Interval` is empty or contains the maximum `Position`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::text::Position;
+/// # use normalize_interval::text::span_bounds;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = Position::new(1, 4);
+/// let b = Position::new(3, 0);
+///
+/// assert_eq!(span_bounds(&Interval::right_open(a, b)), Some((a, b)));
+/// assert_eq!(span_bounds(&Interval::point(a)),
+///     Some((a, Position::new(1, 5))));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn span_bounds(interval: &Interval<Position>)
+    -> Option<(Position, Position)>
+{
+    let start = *interval.0.lower_point()?;
+    let end = interval.0.upper_point()?.succ()?;
+    Some((start, end))
+}
+
+/// Returns the start and end `Position`s of each `Interval` of text in the
+/// `Selection`, in order, where the end `Position`s are excluded. An
+/// `Interval` containing the maximum `Position` is omitted.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::text::Position;
+/// # use normalize_interval::text::span_list;
+/// # use normalize_interval::text::spans;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = (Position::new(0, 0), Position::new(0, 3));
+/// let b = (Position::new(2, 1), Position::new(1, 7));
+///
+/// assert_eq!(span_list(&spans([a, b])), [
+///     (Position::new(0, 0), Position::new(0, 3)),
+///     (Position::new(1, 7), Position::new(2, 1)),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn span_list(selection: &Selection<Position>)
+    -> Vec<(Position, Position)>
+{
+    selection
+        .interval_iter()
+        .filter_map(|interval| span_bounds(&interval))
+        .collect()
+}
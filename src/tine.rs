@@ -16,6 +16,7 @@ use crate::raw_interval::RawInterval;
 // External library imports.
 #[cfg(feature="serde")] use serde::Deserialize;
 #[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="schemars")] use schemars::JsonSchema;
 use few::Few;
 
 // Standard library imports.
@@ -32,6 +33,7 @@ use std::cmp::Ordering;
 /// for a given bound type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature="serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature="schemars", derive(JsonSchema))]
 pub enum Tine<T> {
     /// The lower `Bound` of an `Interval`.
     Lower(Bound<T>),
@@ -107,6 +109,16 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
         }
     }
 
+    /// Returns a reference to the inner `Bound`.
+    pub const fn bound(&self) -> &Bound<T> {
+        use Tine::*;
+        match self {
+            Lower(x) |
+            Point(x) |
+            Upper(x) => x,
+        }
+    }
+
     /// Returns the inner `Bound`.
     pub fn into_inner(self) -> Bound<T> {
         use Tine::*;
@@ -226,6 +238,7 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Lower(_),          &Lower(_))          => None,
 
             (Lower(Include(l)), &Point(Include(_))) => Some(Lower(Exclude(l))),
+            (Lower(Exclude(l)), &Point(Include(_))) => Some(Lower(Exclude(l))),
             (Lower(_),          &Point(_))          => None,
 
             (Lower(Include(l)), &Upper(Include(_))) => Some(Lower(Exclude(l))),
@@ -238,10 +251,10 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Point(Include(_)), &Upper(Include(_))) => None,
             (Point(Include(l)), _)                  => Some(Point(Include(l))),
 
-            (Point(Exclude(l)), &Lower(_))          => Some(Lower(Exclude(l))),
+            (Point(Exclude(l)), &Lower(_))          => Some(Upper(Exclude(l))),
             (Point(Exclude(l)), &Point(Include(_))) => Some(Point(Exclude(l))),
             (Point(Exclude(_)), &Point(Exclude(_))) => None,
-            (Point(Exclude(l)), &Upper(_))          => Some(Upper(Exclude(l))),
+            (Point(Exclude(l)), &Upper(_))          => Some(Lower(Exclude(l))),
 
             (Upper(Include(l)), &Lower(Include(_))) => Some(Upper(Exclude(l))),
             (Upper(Include(l)), &Lower(Exclude(_))) => Some(Upper(Include(l))),
@@ -249,6 +262,7 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Upper(Exclude(l)), &Lower(Exclude(_))) => Some(Upper(Exclude(l))),
 
             (Upper(Include(l)), &Point(Include(_))) => Some(Upper(Exclude(l))),
+            (Upper(Exclude(l)), &Point(Include(_))) => Some(Upper(Exclude(l))),
             (Upper(_),          &Point(_))          => None,
 
             (Upper(Include(l)), &Upper(Exclude(_))) => Some(Point(Include(l))),
@@ -277,11 +291,50 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             _ => panic!("cannot invert infinite Tine"),
         }
     }
+
+    /// Returns whether the points just below, at, and just above the `Tine`
+    /// are included, in that order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Tine` is infinite.
+    pub fn sides(&self) -> [bool; 3] {
+        use Bound::*;
+        use Tine::*;
+        match self {
+            Lower(Include(_)) => [false, true,  true],
+            Lower(Exclude(_)) => [false, false, true],
+            Point(Include(_)) => [false, true,  false],
+            Point(Exclude(_)) => [true,  false, true],
+            Upper(Include(_)) => [true,  true,  false],
+            Upper(Exclude(_)) => [true,  false, false],
+            _ => panic!("cannot get sides of infinite Tine"),
+        }
+    }
+
+    /// Returns the `Tine` at the given point with the given sides included,
+    /// as returned by [`Tine::sides`]. Returns `None` if the sides are all
+    /// included or all excluded, as no `Tine` is needed there.
+    ///
+    /// [`Tine::sides`]: #method.sides
+    pub fn from_sides(point: T, sides: [bool; 3]) -> Option<Self> {
+        use Bound::*;
+        use Tine::*;
+        match sides {
+            [false, true,  true]  => Some(Lower(Include(point))),
+            [false, false, true]  => Some(Lower(Exclude(point))),
+            [false, true,  false] => Some(Point(Include(point))),
+            [true,  false, true]  => Some(Point(Exclude(point))),
+            [true,  true,  false] => Some(Upper(Include(point))),
+            [true,  false, false] => Some(Upper(Exclude(point))),
+            _                     => None,
+        }
+    }
 }
 
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<T> PartialOrd for Tine<T> where T: PartialOrd + Ord + Clone {
-    #[allow(clippy::non_canonical_partial_ord_impl)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if let (Some(l), Some(r)) = (self.as_ref(), other.as_ref()) {
             // Compare points.
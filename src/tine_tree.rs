@@ -9,13 +9,17 @@
 //! Interval `TineTree` implementation.
 ////////////////////////////////////////////////////////////////////////////////
 // NOTE: Unused results are permitted here because the `TineTree` calls
-// `BTreeSet::insert` frequently without concern for its return value.
+// `TineSet::insert` frequently without concern for its return value.
 #![allow(unused_results)]
 
+// Internal modules.
+mod tine_set;
+
 // Internal library imports.
 use crate::bound::Bound;
 use crate::raw_interval::RawInterval;
 use crate::tine::Tine;
+use tine_set::TineSet;
 
 // External library imports.
 #[cfg(feature="serde")] use serde::Deserialize;
@@ -23,9 +27,8 @@ use crate::tine::Tine;
 use few::Few;
 
 // Standard library imports.
-use std::collections::BTreeSet;
-use std::collections::btree_set;
 use std::iter::FromIterator;
+use std::mem;
 
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -35,6 +38,9 @@ use std::iter::FromIterator;
 /// Implemented as an ordered list of `Tine`s. Used to implement the internal
 /// state of `Selection`.
 ///
+/// Small trees keep their `Tine`s in a sorted `Vec`, switching to a `BTreeSet`
+/// once they grow beyond 32 `Tine`s.
+///
 /// Informally, a `TineTree` acts like a number line with markers (`Tine`s) on
 /// it for each `Interval` bound in a possibly disjoint union of `Interval`s.
 /// 
@@ -49,7 +55,7 @@ use std::iter::FromIterator;
 #[cfg_attr(feature="serde", serde(transparent))]
 #[cfg_attr(feature="serde", 
     serde(bound="for<'a> T: Ord + Serialize + Deserialize<'a> + Clone + 'a"))]
-pub struct TineTree<T>(BTreeSet<Tine<T>>);
+pub struct TineTree<T>(TineSet<T>);
 
 impl<T> TineTree<T> where T: Ord + Clone {
     ////////////////////////////////////////////////////////////////////////////
@@ -58,8 +64,16 @@ impl<T> TineTree<T> where T: Ord + Clone {
 
     /// Constructs an empty `TineTree`.
     #[must_use]
-    pub fn new() -> Self {
-        Self(BTreeSet::new())
+    pub const fn new() -> Self {
+        Self(TineSet::new())
+    }
+
+    /// Constructs an empty `TineTree` with space for `capacity` `Tine`s. The
+    /// `Tine`s are stored contiguously until the tree exceeds the reserved
+    /// space, after which it is stored in a `BTreeSet`.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(TineSet::with_capacity(capacity))
     }
 
     /// Constructs a `TineTree` from a `RawInterval`.
@@ -68,6 +82,45 @@ impl<T> TineTree<T> where T: Ord + Clone {
         Self(Tine::from_raw_interval(interval).collect())
     }
 
+    /// Constructs a `TineTree` from `RawInterval`s in ascending order of
+    /// their lower bounds, merging any which overlap or are adjacent.
+    pub(crate) fn from_sorted<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=RawInterval<T>>
+    {
+        let mut merged: Vec<RawInterval<T>> = Vec::new();
+        for interval in intervals {
+            if interval.is_empty() { continue; }
+            match merged.last_mut() {
+                Some(last) if last.intersects(&interval)
+                    || last.is_adjacent_to(&interval) =>
+                {
+                    *last = last.enclose(&interval);
+                },
+                _ => merged.push(interval),
+            }
+        }
+        Self::from_sorted_disjoint(merged)
+    }
+
+    /// Constructs a `TineTree` from disjoint, nonadjacent `RawInterval`s in
+    /// ascending order.
+    fn from_sorted_disjoint<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=RawInterval<T>>
+    {
+        let mut tines: Vec<Tine<T>> = Vec::new();
+        for tine in intervals.into_iter().flat_map(Tine::from_raw_interval) {
+            // Intervals meeting at an excluded point share a tine there.
+            match tines.last() {
+                Some(last) if last.cmp(&tine).is_eq() => {
+                    let last = tines.pop().expect("last tine");
+                    tines.extend(last.union(&tine));
+                },
+                _ => tines.push(tine),
+            }
+        }
+        Self(TineSet::from_sorted(tines))
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
@@ -86,11 +139,39 @@ impl<T> TineTree<T> where T: Ord + Clone {
         self.0.iter().next_back().cloned().map(Tine::into_inner)
     }
 
+    /// Returns a reference to the lower [`Bound`] of the `TineTree`, or `None`
+    /// if the `TineTree` is empty.
+    #[inline]
+    pub fn lower_bound_ref(&self) -> Option<&Bound<T>> {
+        self.0.iter().next().map(Tine::bound)
+    }
+
+    /// Returns a reference to the upper [`Bound`] of the `TineTree`, or `None`
+    /// if the `TineTree` is empty.
+    #[inline]
+    pub fn upper_bound_ref(&self) -> Option<&Bound<T>> {
+        self.0.iter().next_back().map(Tine::bound)
+    }
+
 
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
     ////////////////////////////////////////////////////////////////////////////
     
+    /// Returns the number of `Tine`s the `TineTree` can hold without
+    /// allocating. Once a tree is stored in a `BTreeSet`, this is the number
+    /// of `Tine`s it already holds, as a `BTreeSet` cannot reserve space.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns the number of `Tine`s in the `TineTree`.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     /// Returns `true` if the `TineTree` is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -181,23 +262,29 @@ impl<T> TineTree<T> where T: Ord + Clone {
     #[must_use]
     pub fn intersect(&self, other: &Self) -> Self {
         let mut intersection = Self::new();
-        let self_intervals = self.interval_iter();
-        let mut other_intervals = other.interval_iter();
-
-        for self_interval in self_intervals {
-            'segment: loop {
-                if let Some(other_interval) = other_intervals.next() {
-                    let i = self_interval.intersect(&other_interval);
-                    if i.is_empty() {
-                        // Nothing else overlaps in this segment.
-                        break 'segment;
-                    }
-
-                    intersection.union_in_place(&i);
-                } else {
-                    // Nothing else overlaps anywhere.
-                    return intersection;
-                }
+        let mut self_intervals = self.interval_iter().peekable();
+        let mut other_intervals = other.interval_iter().peekable();
+
+        while let (Some(self_interval), Some(other_interval))
+            = (self_intervals.peek(), other_intervals.peek())
+        {
+            let i = self_interval.intersect(other_interval);
+            if !i.is_empty() {
+                intersection.union_in_place_owned(i);
+            }
+
+            // Advance past whichever interval ends first; it cannot overlap
+            // any later intervals of the other tree.
+            let self_upper = self_interval.upper_bound();
+            let other_upper = other_interval.upper_bound();
+            let self_ends_first = match (&self_upper, &other_upper) {
+                (Some(s), Some(o)) => s.least_intersect(o) == *s,
+                _                  => true,
+            };
+            if self_ends_first {
+                let _ = self_intervals.next();
+            } else {
+                let _ = other_intervals.next();
             }
         }
         intersection
@@ -209,7 +296,7 @@ impl<T> TineTree<T> where T: Ord + Clone {
     pub fn union(&self, other: &Self) -> Self {
         let mut union = self.clone();
         for interval in other.interval_iter() {
-            union.union_in_place(&interval);
+            union.union_in_place_owned(interval);
         }
         union
     }
@@ -220,7 +307,7 @@ impl<T> TineTree<T> where T: Ord + Clone {
     pub fn minus(&self, other: &Self) -> Self {
         let mut minus = self.clone();
         for interval in other.interval_iter() {
-            minus.minus_in_place(&interval);
+            minus.minus_in_place_owned(interval);
         }
         minus
     }
@@ -276,39 +363,53 @@ impl<T> TineTree<T> where T: Ord + Clone {
     // In-place operations
     ////////////////////////////////////////////////////////////////////////////
 
+    /// Removes all intervals from the tree, retaining its allocated storage
+    /// unless it is stored in a `BTreeSet`.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
     /// Intersects the given interval with the contents of the tree.
     pub fn intersect_in_place(&mut self, interval: &RawInterval<T>) {
+        self.intersect_in_place_owned(interval.clone());
+    }
+
+    /// Intersects the given interval with the contents of the tree, taking ownership of
+    /// the interval to avoid cloning its bounds.
+    pub(in crate) fn intersect_in_place_owned(&mut self, interval: RawInterval<T>) {
         use Bound::*;
         use Tine::*;
 
         // Early exit if we're intersecting a full interval or are empty.
-        if self.0.is_empty() || interval.is_full() {return};
+        if self.0.is_empty() || interval.is_full() {return;}
 
         // Early exit if we're intersection an empty interval.
         if interval.is_empty() {
-            *self = Self::new();
+            self.clear();
             return;
         }
 
         // Early exit if we're intersection a point interval.
-        if let RawInterval::Point(pt) = interval {
+        if let RawInterval::Point(pt) = &interval {
             if self.contains(pt) {
-                *self = Self::from_raw_interval(interval.clone());
+                self.clear();
+                self.union_in_place_owned(interval);
             } else {
-                *self = Self::new();
+                self.clear();
             }
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero                   => {
-                *self = Self::new();
+                self.clear();
             },
             Few::One(Point(Include(p))) => {
                 if self.contains(&p) {
-                    *self = Self::from_raw_interval(RawInterval::Point(p));
+                    self.clear();
+                    self.0.insert(Point(Include(p)));
                 } else {
-                    *self = Self::new();
+                    self.clear();
                 }
             },
             Few::Two(l, u)              => {
@@ -321,18 +422,20 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// Internal implementation of `intersect_in_place`, handling the proper
     /// interval case.
     fn intersect_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.interior_split_for_proper_interval(&l, &u);
+        let (open_before, upper_after_l, mut lower, mut upper, lower_before_u,
+            closed_after) = self.interior_split_for_proper_interval(&l, &u);
+        let (had_l, had_u) = (lower.is_some(), upper.is_some());
 
         // Merge tines if overlap or use given ones. We should only have `None`
         // in the case of a intersection annhiliation.
-        let merged_l = if ts[2].is_some() {
-            ts[2].take().and_then(|lower| lower.intersect(&l))
+        let merged_l = if lower.is_some() {
+            lower.take().and_then(|lower| lower.intersect(&l))
         } else {
             Some(l)
         };
 
-        let merged_u = if ts[3].is_some() {
-            ts[3].take().and_then(|upper| upper.intersect(&u))
+        let merged_u = if upper.is_some() {
+            upper.take().and_then(|upper| upper.intersect(&u))
         } else {
             Some(u)
         };
@@ -340,85 +443,39 @@ impl<T> TineTree<T> where T: Ord + Clone {
         // Ensure inner tines have the correct bounds.
         debug_assert!(merged_l
             .as_ref()
-            .map_or(true, Tine::is_lower_bound));
+            .is_none_or(|t| t.is_lower_bound() || t.is_point_include()));
         debug_assert!(merged_u
             .as_ref()
-            .map_or(true, Tine::is_upper_bound));
-
-        
-        // We need to detect whether the point is inside or outside an interval.
-        // To do this, we look at the tines inside and outside the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-        let closed_after = ts[5]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
-
-        let in_l = ts[1]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
-        let in_r = ts[4]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-
-
-        // Insert tines into the tree, ignoring them if the are not wrapped by a
-        // surrounding interval, or not wrapping a surrounding interval.
-        match (open_before, merged_l, in_l, in_r, merged_u, closed_after) {
-            (_,     Some(l), true,  true,  Some(u), _   )  |
-            (_,     Some(l), false, false, Some(u), _   )  => {
-                // (   ) (   )
-                //   (     )
-                //     O R
-                // (     )
-                //   ( )
-                //     O R
-                // (     )
-                // (  )
-                //     O R
-                // (     )
-                //    (  )
-                self.0.insert(l);
-                self.0.insert(u);
-            },
-            (true, Some(l),  true,  false, _,       false) => {
-                // (   )
-                //   (   )
-                //     O R
-                // (   ) ( )
-                //   (   )
-                self.0.insert(l);
-            },
-            (false, _,       false, true,  Some(u), true)  => {
-                //   (   )
-                // (   )
-                //     O R
-                // ( ) (   )
-                //   (   )
-                self.0.insert(u);
-            },
-            (false, _,       false, false, _,       false) => {
-                // )     (
-                // (     )
-                //     O R
-                //   ( )
-                // (     )
-                /* Do nothing. */
-            },
-            _ => unreachable!("invalid bounds for intersection interval"),
-        }
+            .is_none_or(|t| t.is_upper_bound() || t.is_point_include()));
+
+        // We need to detect whether the points just inside each bound are in
+        // the tree. A merged tine already accounts for them. Otherwise, we
+        // look at the nearest tine inside the interval, or if there are none,
+        // the nearest tine outside it.
+        let in_l = had_l || upper_after_l.unwrap_or(open_before);
+        let in_u = had_u || lower_before_u.unwrap_or(closed_after);
+
+        // Insert tines into the tree, ignoring them if they do not bound
+        // points of the tree.
+        if let (true, Some(l)) = (in_l, merged_l) { self.0.insert(l); }
+        if let (true, Some(u)) = (in_u, merged_u) { self.0.insert(u); }
     }
 
     /// Unions the given interval with the contents of the tree.
     pub fn union_in_place(&mut self, interval: &RawInterval<T>) {
+        self.union_in_place_owned(interval.clone());
+    }
+
+    /// Unions the given interval with the contents of the tree, taking ownership of
+    /// the interval to avoid cloning its bounds.
+    pub(in crate) fn union_in_place_owned(&mut self, interval: RawInterval<T>) {
         // Early exit if we're unioning a full interval.
         if interval.is_full() {
             *self = Self::from_raw_interval(RawInterval::Full);
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero      => (),
             Few::One(p)    => self.union_point_interval(p),
             Few::Two(l, u) => self.union_proper_interval(l, u),
@@ -429,10 +486,11 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// case.
     #[allow(clippy::cognitive_complexity)]
     fn union_point_interval(&mut self, p: Tine<T>) {
-        let mut ts = self.exterior_split_for_point_interval(&p);
+        let (open_before, mut pt, closed_after) = self
+            .exterior_split_for_point_interval(&p);
 
-        let p = if ts[1].is_some() {
-            if let Some(merged) = ts[1]
+        let p = if pt.is_some() {
+            if let Some(merged) = pt
                 .take()
                 .and_then(|pt| pt.union(&p)) 
             {
@@ -449,12 +507,6 @@ impl<T> TineTree<T> where T: Ord + Clone {
         
         // We need to detect whether the point is inside or outside an interval.
         // To do this, we look at the tines before and after the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-        let closed_after = ts[2]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
 
         // Insert tine into the tree, ignoring it if it is wrapped by a
         // surrounding interval.
@@ -487,18 +539,19 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// Internal implementation of `union_in_place`, handling the proper
     /// interval case.
     fn union_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.exterior_split_for_proper_interval(&l, &u);
+        let (open_before, mut lower, mut upper, closed_after) = self
+            .exterior_split_for_proper_interval(&l, &u);
 
         // Merge tines if overlap or use given one. We should only have `None`
         // in the case of a union annhiliation.
-        let merged_l = if ts[1].is_some() {
-            ts[1].take().and_then(|lower| lower.union(&l))
+        let merged_l = if lower.is_some() {
+            lower.take().and_then(|lower| lower.union(&l))
         } else {
             Some(l)
         };
 
-        let merged_u = if ts[2].is_some() {
-            ts[2].take().and_then(|upper| upper.union(&u))
+        let merged_u = if upper.is_some() {
+            upper.take().and_then(|upper| upper.union(&u))
         } else {
             Some(u)
         };
@@ -506,20 +559,14 @@ impl<T> TineTree<T> where T: Ord + Clone {
         // Ensure inner tines have the correct bounds.
         debug_assert!(merged_l
             .as_ref()
-            .map_or(true, Tine::is_lower_bound));
+            .is_none_or(Tine::is_lower_bound));
         debug_assert!(merged_u
             .as_ref()
-            .map_or(true, Tine::is_upper_bound));
+            .is_none_or(Tine::is_upper_bound));
 
         // We need to detect whether the interval is inside or outside an 
         // existing interval. To do this, we look at the tines before and after
         // the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-        let closed_after = ts[3]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
         
         // Insert tines into the tree, ignoring them if the are wrapped by a
         // surrounding interval.
@@ -610,16 +657,22 @@ impl<T> TineTree<T> where T: Ord + Clone {
 
     /// Minuses the given interval from the contents of the tree.
     pub fn minus_in_place(&mut self, interval: &RawInterval<T>) {
+        self.minus_in_place_owned(interval.clone());
+    }
+
+    /// Minuses the given interval from the contents of the tree, taking ownership of
+    /// the interval to avoid cloning its bounds.
+    pub(in crate) fn minus_in_place_owned(&mut self, interval: RawInterval<T>) {
         // Early exit if we're minusing an empty interval or are empty.
-        if self.0.is_empty() || interval.is_empty() {return};
+        if self.0.is_empty() || interval.is_empty() {return;}
 
         // Early exit if we're minusing a full interval.
         if interval.is_full() {
-            *self = Self::new();
+            self.clear();
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero      => (),
             Few::One(p)    => self.minus_point_interval(p),
             Few::Two(l, u) => self.minus_proper_interval(l, u),
@@ -629,32 +682,22 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// Internal implementation of `minus_in_place`, handling the point interval
     /// case.
     fn minus_point_interval(&mut self, p: Tine<T>) {
-        let mut ts = self.exterior_split_for_point_interval(&p);
-
-        let p = if ts[1].is_some() {
-            if let Some(merged) = ts[1]
-                .take()
-                .and_then(|pt| pt.minus(&p)) 
-            {
-                merged
-            } else {
-                // If the point annhilates, then we've already joined two
-                // intervals by removing the Point(Exclude(_)) from the tree in
-                // minus_split_tree_point. So nothing else needs to happen.
-                return;
+        let (open_before, pt, closed_after) = self
+            .exterior_split_for_point_interval(&p);
+
+        if let Some(pt) = pt {
+            // A tine already at the point accounts for the intervals around
+            // it, so the difference replaces it directly. If the point
+            // annhilates, then removing it from the tree in
+            // exterior_split_for_point_interval was enough.
+            if let Some(merged) = pt.minus(&p) {
+                self.0.insert(merged);
             }
-        } else {
-            p
-        };
+            return;
+        }
         
         // We need to detect whether the point is inside or outside an interval.
         // To do this, we look at the tines before and after the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-        let closed_after = ts[2]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
 
         // Insert tine into the tree, ignoring it if it is wrapped by a
         // surrounding interval.
@@ -691,17 +734,18 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// interval case.
     #[allow(clippy::items_after_statements)]
     fn minus_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.exterior_split_for_proper_interval(&l, &u);
+        let (open_before, mut lower, mut upper, closed_after) = self
+            .exterior_split_for_proper_interval(&l, &u);
 
         // Merge tines if overlap
-        let merged_l = if ts[1].is_some() {
-            ts[1].take().and_then(|lower| lower.minus(&l))
+        let merged_l = if lower.is_some() {
+            lower.take().and_then(|lower| lower.minus(&l))
         } else {
             Some(l)
         };
 
-        let merged_u = if ts[2].is_some() {
-            ts[2].take().and_then(|upper| upper.minus(&u))
+        let merged_u = if upper.is_some() {
+            upper.take().and_then(|upper| upper.minus(&u))
         } else {
             Some(u)
         };
@@ -709,12 +753,6 @@ impl<T> TineTree<T> where T: Ord + Clone {
         // We need to detect whether the interval is inside or outside an 
         // existing interval. To do this, we look at the tines before and after
         // the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .is_some_and(Tine::is_lower_bound);
-        let closed_after = ts[3]
-            .as_ref()
-            .is_some_and(Tine::is_upper_bound);
         
         // Insert tines into the tree, ignoring them if the are not wrapped by a
         // surounding interval.
@@ -802,100 +840,220 @@ impl<T> TineTree<T> where T: Ord + Clone {
         }
     }
 
+    /// Toggles the given interval in the contents of the tree, so that the
+    /// points of the interval are present if and only if they were not.
+    pub fn toggle_in_place(&mut self, interval: &RawInterval<T>) {
+        self.toggle_in_place_owned(interval.clone());
+    }
+
+    /// Splits the tree at the given point, returning the intervals at or
+    /// above it and leaving those below it.
+    #[must_use]
+    pub fn split_off(&mut self, point: &T) -> Self {
+        let pivot = Tine::Point(Bound::Include(point.clone()));
+        let mut above = Self(self.0.split_off(&pivot));
+
+        // The `Tine` at the point, if any, is split between the trees.
+        // Otherwise the point is inside an interval if the last remaining
+        // `Tine` opens one.
+        let sides = match above.0.take(&pivot) {
+            Some(tine) => tine.sides(),
+            None => {
+                let inside = self.0.iter()
+                    .next_back()
+                    .is_some_and(Tine::is_lower_bound);
+                [inside; 3]
+            },
+        };
+        if let Some(tine) = Tine::from_sides(
+            point.clone(),
+            [sides[0], false, false])
+        {
+            self.0.insert(tine);
+        }
+        if let Some(tine) = Tine::from_sides(
+            point.clone(),
+            [false, sides[1], sides[2]])
+        {
+            above.0.insert(tine);
+        }
+        above
+    }
+
+    /// Moves all intervals of the given tree into this tree. If every
+    /// interval of one tree lies below those of the other, their `Tine`s are
+    /// concatenated rather than merged one interval at a time.
+    pub fn append(&mut self, mut other: Self) {
+        let below = |lower: &Self, upper: &Self| match (
+            lower.0.iter().next_back(),
+            upper.0.iter().next())
+        {
+            (Some(last), Some(first)) => last < first,
+            _                         => true,
+        };
+        if below(&other, self) {
+            mem::swap(self, &mut other);
+        }
+        if below(self, &other) {
+            self.0.append(other.0);
+        } else {
+            for interval in other {
+                self.union_in_place_owned(interval);
+            }
+        }
+    }
+
+    /// Toggles the given interval in the contents of the tree, taking
+    /// ownership of the interval to avoid cloning its bounds.
+    pub(in crate) fn toggle_in_place_owned(&mut self, interval: RawInterval<T>) {
+        match Tine::from_raw_interval(interval) {
+            Few::Zero      => (),
+            Few::One(p)    => {
+                let toggled = self.toggled_tine(&p);
+                self.0.take(&p);
+                if let Some(p) = toggled { self.0.insert(p); }
+            },
+            Few::Two(l, u) => {
+                // Both bounds must be resolved before the tree is modified,
+                // as they depend on the tines surrounding them.
+                let toggled_l = self.toggled_tine(&l);
+                let toggled_u = self.toggled_tine(&u);
+                self.0.take(&l);
+                self.0.take(&u);
+
+                // Every point strictly between the bounds is toggled, which
+                // inverts each of the tines there.
+                self.0.invert_range(&l, &u);
+                if let Some(l) = toggled_l { self.0.insert(l); }
+                if let Some(u) = toggled_u { self.0.insert(u); }
+            },
+        }
+    }
+
+    /// Internal implementation of `toggle_in_place`, returning the tine which
+    /// should replace any in the tree equal to the given tine once its points
+    /// are toggled, or `None` if no tine is needed there.
+    fn toggled_tine(&self, tine: &Tine<T>) -> Option<Tine<T>> {
+        let existing = self.0
+            .from(tine)
+            .filter(|t| (*t).cmp(tine).is_eq());
+
+        // An infinite bound includes the infinite region around it, so
+        // toggling either adds or removes it.
+        let Some(point) = tine.as_ref() else {
+            return if existing.is_some() { None } else { Some(tine.clone()) };
+        };
+
+        // Without a tine at this point, the points around it are all present
+        // if the preceding tine opens an interval.
+        let present = existing.map_or_else(
+            || [self.0.before(tine).is_some_and(Tine::is_lower_bound); 3],
+            Tine::sides);
+        let toggle = tine.sides();
+        Tine::from_sides(point.clone(), [
+            present[0] ^ toggle[0],
+            present[1] ^ toggle[1],
+            present[2] ^ toggle[2],
+        ])
+    }
+
     /// Splits the tine tree into three sections for an interval-like Tine to
     /// prepare for an intersect operation.
     ///
-    /// The resulting array contains the following values:
+    /// The resulting tuple contains the following values:
     /// ```rust,ignore
-    /// [
-    ///     0 => Copy of the first tine less than the lower tine.
-    ///     1 => Copy of the first tine greater than the lower tine.
+    /// (
+    ///     0 => Whether the first tine less than the lower tine is a lower
+    ///          bound.
+    ///     1 => Whether the first tine greater than the lower tine is an upper
+    ///          bound, if it is less than the upper tine.
     ///     2 => The tine equal to the lower tine.
     ///     3 => The tine equal to the upper tine.
-    ///     4 => Copy of the first tine less than the upper tine.
-    ///     5 => Copy of the first tine greater than the upper tine.
-    /// ]
+    ///     4 => Whether the first tine less than the upper tine is a lower
+    ///          bound, if it is greater than the lower tine.
+    ///     5 => Whether the first tine greater than the upper tine is an upper
+    ///          bound.
+    /// )
     /// ```
     ///
-    /// Any tines not between lower and upper are dropped.
+    /// Any tines not between lower and upper are dropped. The neighboring
+    /// tines are only classified, so none of them are cloned.
+    #[allow(clippy::type_complexity)]
     fn interior_split_for_proper_interval(
         &mut self,
         lower: &Tine<T>,
         upper: &Tine<T>) 
-        -> [Option<Tine<T>>; 6]
+        -> (bool, Option<bool>, Option<Tine<T>>, Option<Tine<T>>,
+            Option<bool>, bool)
     {
         debug_assert!(lower < upper);
-        let mut res = [None, None, None, None, None, None];
 
         // Get lower and upper if they are in the tree.
-        res[2] = self.0.take(lower);
-        res[3] = self.0.take(upper);
-        
-        // Get before and after points and drop anything not in the center.
-        let mut center = self.0.split_off(lower);
-        let right_side = center.split_off(upper);
+        let taken_lower = self.0.take(lower);
+        let taken_upper = self.0.take(upper);
 
-        {
-            let mut backward = self.0.iter();
-            res[0] = backward.next_back().cloned();
-
-            let mut forward = center.iter();
-            res[1] = forward.next().cloned();
-        }
-
-        {
-            let mut backward = center.iter().rev();
-            res[4] = backward.next().cloned();
+        // Classify before and after points.
+        let open_before = self.0.before(lower)
+            .is_some_and(Tine::is_lower_bound);
+        let upper_after_lower = self.0.from(lower)
+            .filter(|t| *t < upper)
+            .map(Tine::is_upper_bound);
+        let lower_before_upper = self.0.before(upper)
+            .filter(|t| *t >= lower)
+            .map(Tine::is_lower_bound);
+        let closed_after = self.0.from(upper)
+            .is_some_and(Tine::is_upper_bound);
 
-            let mut forward = right_side.iter();
-            res[5] = forward.next().cloned();
-        }
+        debug_assert_eq!(
+            upper_after_lower.is_some(),
+            lower_before_upper.is_some());
 
-        debug_assert_eq!(res[1].is_some(), res[4].is_some());
-        
-        self.0 = center;
-        res
+        // Drop anything not in the center.
+        self.0.retain_range(lower, upper);
+        (open_before, upper_after_lower, taken_lower, taken_upper,
+            lower_before_upper, closed_after)
     }
 
     /// Splits the tine tree into three sections for a point-like Tine to
     /// prepare for a union operation.
     ///
-    /// The resulting array contains the following values:
+    /// The resulting tuple contains the following values:
     /// ```rust,ignore
-    /// [
-    ///     0 => Copy of the first tine less than the given tine.
+    /// (
+    ///     0 => Whether the first tine less than the given tine is a lower
+    ///          bound.
     ///     1 => The tine equal to the given tine.
-    ///     2 => Copy of the first tine greater than the given tine.
-    /// ]
+    ///     2 => Whether the first tine greater than the given tine is an upper
+    ///          bound.
+    /// )
     /// ```
     fn exterior_split_for_point_interval(&mut self, tine: &Tine<T>)
-        -> [Option<Tine<T>>; 3]
+        -> (bool, Option<Tine<T>>, bool)
     {
-        let mut res = [None, None, None];
-
         // Get pt if it is in the tree.
-        res[1] = self.0.take(tine);
+        let taken = self.0.take(tine);
 
-        // Get before and after points.
-        let mut right_side = self.0.split_off(tine);
-        res[0] = self.0.iter().next_back().cloned();
-        res[2] = right_side.iter().next().cloned();
-
-        self.0.append(&mut right_side);
-        res
+        // Classify before and after points.
+        let open_before = self.0.before(tine)
+            .is_some_and(Tine::is_lower_bound);
+        let closed_after = self.0.from(tine)
+            .is_some_and(Tine::is_upper_bound);
+        (open_before, taken, closed_after)
     }
 
     /// Splits the tine tree into three sections for an interval-like Tine in
     /// preperation for a union or minus operation.
     ///
-    /// The resulting array contains the following values:
+    /// The resulting tuple contains the following values:
     /// ```rust,ignore
-    /// [
-    ///     0 => Copy of the first tine less than the lower tine.
+    /// (
+    ///     0 => Whether the first tine less than the lower tine is a lower
+    ///          bound.
     ///     1 => The tine equal to the lower tine.
     ///     2 => The tine equal to the upper tine.
-    ///     3 => Copy of the first tine greater than the upper tine.
-    /// ]
+    ///     3 => Whether the first tine greater than the upper tine is an upper
+    ///          bound.
+    /// )
     /// ```
     ///
     /// Any tines between lower and upper are dropped.
@@ -903,29 +1061,19 @@ impl<T> TineTree<T> where T: Ord + Clone {
         &mut self,
         lower: &Tine<T>,
         upper: &Tine<T>)
-        -> [Option<Tine<T>>; 4]
+        -> (bool, Option<Tine<T>>, Option<Tine<T>>, bool)
     {
-        let mut res = [None, None, None, None];
-
         // Get lower and upper if they are in the tree.
-        res[1] = self.0.take(lower);
-        res[2] = self.0.take(upper);
-
-        // Get before and after points and drop anything in the center.
-        let mut center = self.0.split_off(lower);
-        {
-            let mut backward = self.0.iter();
-            res[0] = backward.next_back().cloned();
-        }
+        let taken_lower = self.0.take(lower);
+        let taken_upper = self.0.take(upper);
 
-        let mut right_side = center.split_off(upper);
-        {
-            let mut forward = right_side.iter();
-            res[3] = forward.next().cloned();
-        }
-        
-        self.0.append(&mut right_side);
-        res
+        // Classify before and after points and drop anything in the center.
+        let open_before = self.0.before(lower)
+            .is_some_and(Tine::is_lower_bound);
+        let closed_after = self.0.from(upper)
+            .is_some_and(Tine::is_upper_bound);
+        self.0.remove_range(lower, upper);
+        (open_before, taken_lower, taken_upper, closed_after)
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -941,6 +1089,79 @@ impl<T> TineTree<T> where T: Ord + Clone {
             saved_upper: None,
         }
     }
+
+    /// Returns an iterator over the bounds of each of the `RawInterval`s in
+    /// the tree, borrowed from the tree's `Tine`s.
+    #[must_use]
+    pub fn bound_iter(&self) -> BoundIter<'_, T> {
+        BoundIter {
+            tine_iter: self.0.iter(),
+            saved_lower: None,
+            saved_upper: None,
+        }
+    }
+
+    /// Returns an iterator over the `RawInterval`s in the tree, beginning with
+    /// the first which may intersect the given interval. The iterator is empty
+    /// if the interval is empty.
+    #[must_use]
+    pub fn interval_iter_from(&self, interval: &RawInterval<T>) -> Iter<'_, T> {
+        let lower = match Tine::from_raw_interval(interval.clone()) {
+            Few::Zero      => return Iter {
+                tine_iter: tine_set::Iter::Small([].iter()),
+                saved_lower: None,
+                saved_upper: None,
+            },
+            Few::One(p)    => p,
+            Few::Two(l, _) => l,
+        };
+        // An interval ending at or after the lower bound begins at the
+        // preceding tine if that tine opens it.
+        let tine_iter = match self.0.before(&lower) {
+            Some(before) if before.is_lower_bound() => self.0.iter_from(before),
+            _ => self.0.iter_from(&lower),
+        };
+        Iter {
+            tine_iter,
+            saved_lower: None,
+            saved_upper: None,
+        }
+    }
+
+    /// Returns an iterator over the `RawInterval`s in the tree which begin
+    /// before the upper bound of the given interval. The iterator is empty if
+    /// the interval is empty.
+    #[must_use]
+    pub fn interval_iter_to(&self, interval: &RawInterval<T>) -> Iter<'_, T> {
+        let upper = match Tine::from_raw_interval(interval.clone()) {
+            Few::Zero      => return Iter {
+                tine_iter: tine_set::Iter::Small([].iter()),
+                saved_lower: None,
+                saved_upper: None,
+            },
+            Few::One(p)    => p,
+            Few::Two(_, u) => u,
+        };
+        // An interval beginning before the upper bound ends at the next tine,
+        // which is saved if it also begins the interval after it.
+        let (tine_iter, saved_upper) = match self.0.from(&upper) {
+            Some(end) if end.is_point_exclude() =>
+                (self.0.iter_to(end), Some(end.clone())),
+            Some(end) if end.is_upper_bound() => {
+                let tines = self.0.iter_from(end)
+                    .nth(1)
+                    .map_or_else(|| self.0.iter(), |next| self.0.iter_to(next));
+                (tines, None)
+            },
+            Some(end) => (self.0.iter_to(end), None),
+            None      => (self.0.iter(), None),
+        };
+        Iter {
+            tine_iter,
+            saved_lower: None,
+            saved_upper,
+        }
+    }
 }
 
 impl<T> Default for TineTree<T> where T: Ord + Clone {
@@ -966,7 +1187,7 @@ impl<T, I> From<I> for TineTree<T>
     fn from(iter: I) -> Self {
         let mut tine_tree = Self::new();
         for interval in iter {
-            tine_tree.union_in_place(&interval);
+            tine_tree.union_in_place_owned(interval);
         }
         tine_tree
     }
@@ -980,7 +1201,7 @@ impl<T> FromIterator<RawInterval<T>> for TineTree<T>
     {
         let mut tine_tree = Self::new();
         for interval in iter {
-            tine_tree.union_in_place(&interval);
+            tine_tree.union_in_place_owned(interval);
         }
         tine_tree
     }
@@ -1009,7 +1230,7 @@ impl<T> IntoIterator for TineTree<T>
 #[derive(Debug)]
 pub struct IntoIter<T> {
     /// The tree's `Tine`s in order.
-    inner: btree_set::IntoIter<Tine<T>>,
+    inner: tine_set::IntoIter<T>,
     /// A saved lower-bound tine.
     saved_lower: Option<Tine<T>>,
     /// A saved upper-bound tine.
@@ -1033,13 +1254,17 @@ impl<T> Iterator for IntoIter<T> where T: Ord + Clone {
                     // Next tine must be a lower bound of an interval.
                     debug_assert!(lower.is_lower_bound());
 
-                    let upper = self.inner.next()
-                        .or_else(|| self.saved_upper.take())
-                        .expect("interval is not partial");
-
-                    if upper.is_point_exclude() {
-                        self.saved_lower = Some(upper.clone());
-                    }
+                    let upper = match self.inner.next() {
+                        // A point exclusion also bounds the next interval.
+                        Some(upper) if upper.is_point_exclude() => {
+                            self.saved_lower = Some(upper.clone());
+                            upper
+                        },
+                        Some(upper) => upper,
+                        // A saved tine bounds an interval already produced.
+                        None => self.saved_upper.take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be an upper bound.
                     debug_assert!(upper.is_upper_bound());
@@ -1069,13 +1294,17 @@ impl<T> DoubleEndedIterator for IntoIter<T>
                     // Next tine must be an upper bound of an interval.
                     debug_assert!(upper.is_upper_bound());
 
-                    let lower = self.inner.next_back()
-                        .or_else(|| self.saved_lower.take())
-                        .expect("interval is not partial");
-
-                    if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
-                    }
+                    let lower = match self.inner.next_back() {
+                        // A point exclusion also bounds the previous interval.
+                        Some(lower) if lower.is_point_exclude() => {
+                            self.saved_upper = Some(lower.clone());
+                            lower
+                        },
+                        Some(lower) => lower,
+                        // A saved tine bounds an interval already produced.
+                        None => self.saved_lower.take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be a lower bound.
                     debug_assert!(lower.is_lower_bound());
@@ -1096,14 +1325,14 @@ impl<T> DoubleEndedIterator for IntoIter<T>
 pub struct Iter<'t, T> {
     /// The tree's `Tine`s in order.
     #[allow(clippy::struct_field_names)]
-    tine_iter: btree_set::Iter<'t, Tine<T>>,
+    tine_iter: tine_set::Iter<'t, T>,
     /// A saved lower-bound tine.
     saved_lower: Option<Tine<T>>,
     /// A saved upper-bound tine.
     saved_upper: Option<Tine<T>>,
 }
 
-impl<'t, T> Iterator for Iter<'t, T>
+impl<T> Iterator for Iter<'_, T>
     where T: Ord + Clone
 {
     type Item = RawInterval<T>;
@@ -1122,13 +1351,17 @@ impl<'t, T> Iterator for Iter<'t, T>
                     // Next tine must be a lower bound of an interval.
                     debug_assert!(lower.is_lower_bound());
 
-                    let upper = self.tine_iter.next().cloned()
-                        .or_else(|| self.saved_upper.take())
-                        .expect("interval is not partial");
-
-                    if upper.is_point_exclude() {
-                        self.saved_lower = Some(upper.clone());
-                    }
+                    let upper = match self.tine_iter.next().cloned() {
+                        // A point exclusion also bounds the next interval.
+                        Some(upper) if upper.is_point_exclude() => {
+                            self.saved_lower = Some(upper.clone());
+                            upper
+                        },
+                        Some(upper) => upper,
+                        // A saved tine bounds an interval already produced.
+                        None => self.saved_upper.take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be an upper bound.
                     debug_assert!(upper.is_upper_bound());
@@ -1142,7 +1375,7 @@ impl<'t, T> Iterator for Iter<'t, T>
     }
 }
 
-impl<'t, T> DoubleEndedIterator for Iter<'t, T>
+impl<T> DoubleEndedIterator for Iter<'_, T>
     where T: Ord + Clone 
 {
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -1159,13 +1392,17 @@ impl<'t, T> DoubleEndedIterator for Iter<'t, T>
                     // Next tine must be an upper bound of an interval.
                     debug_assert!(upper.is_upper_bound());
 
-                    let lower = self.tine_iter.next_back().cloned()
-                        .or_else(|| self.saved_lower.take())
-                        .expect("interval is not partial");
-
-                    if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
-                    }
+                    let lower = match self.tine_iter.next_back().cloned() {
+                        // A point exclusion also bounds the previous interval.
+                        Some(lower) if lower.is_point_exclude() => {
+                            self.saved_upper = Some(lower.clone());
+                            lower
+                        },
+                        Some(lower) => lower,
+                        // A saved tine bounds an interval already produced.
+                        None => self.saved_lower.take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be a lower bound.
                     debug_assert!(lower.is_lower_bound());
@@ -1177,3 +1414,83 @@ impl<'t, T> DoubleEndedIterator for Iter<'t, T>
             })
     }
 }
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BoundIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the borrowed bounds of the `RawInterval`s formed by a
+/// sequence of `Tine`s.
+#[derive(Debug)]
+pub struct BoundIter<'t, T> {
+    /// The tree's `Tine`s in order.
+    tine_iter: tine_set::Iter<'t, T>,
+    /// A saved lower-bound tine.
+    saved_lower: Option<&'t Tine<T>>,
+    /// A saved upper-bound tine.
+    saved_upper: Option<&'t Tine<T>>,
+}
+
+impl<'t, T> Iterator for BoundIter<'t, T>
+    where T: Ord + Clone
+{
+    type Item = (Bound<&'t T>, Bound<&'t T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lower = self.saved_lower
+            .take()
+            .or_else(|| self.tine_iter.next())?;
+        if let Tine::Point(point @ Bound::Include(_)) = lower {
+            // Next tine is a single point.
+            return Some((point.as_bound_ref(), point.as_bound_ref()));
+        }
+        // Next tine must be a lower bound of an interval.
+        debug_assert!(lower.is_lower_bound());
+
+        let upper = match self.tine_iter.next() {
+            // A point exclusion also bounds the next interval.
+            Some(upper) if upper.is_point_exclude() => {
+                self.saved_lower = Some(upper);
+                upper
+            },
+            Some(upper) => upper,
+            // A saved tine bounds an interval already produced.
+            None => self.saved_upper.take()
+                .expect("interval is not partial"),
+        };
+        debug_assert!(upper.is_upper_bound());
+
+        Some((lower.bound().as_bound_ref(), upper.bound().as_bound_ref()))
+    }
+}
+
+impl<T> DoubleEndedIterator for BoundIter<'_, T>
+    where T: Ord + Clone
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let upper = self.saved_upper
+            .take()
+            .or_else(|| self.tine_iter.next_back())?;
+        if let Tine::Point(point @ Bound::Include(_)) = upper {
+            // Next tine is a single point.
+            return Some((point.as_bound_ref(), point.as_bound_ref()));
+        }
+        // Next tine must be an upper bound of an interval.
+        debug_assert!(upper.is_upper_bound());
+
+        let lower = match self.tine_iter.next_back() {
+            // A point exclusion also bounds the previous interval.
+            Some(lower) if lower.is_point_exclude() => {
+                self.saved_upper = Some(lower);
+                lower
+            },
+            Some(lower) => lower,
+            // A saved tine bounds an interval already produced.
+            None => self.saved_lower.take()
+                .expect("interval is not partial"),
+        };
+        debug_assert!(lower.is_lower_bound());
+
+        Some((lower.bound().as_bound_ref(), upper.bound().as_bound_ref()))
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//! Adaptive ordered `Tine` storage for the `TineTree`.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::tine::Tine;
+
+// External library imports.
+#[cfg(feature="serde")] use serde::Deserialize;
+#[cfg(feature="serde")] use serde::Deserializer;
+#[cfg(feature="serde")] use serde::Serialize;
+#[cfg(feature="serde")] use serde::Serializer;
+
+// Standard library imports.
+use std::collections::BTreeSet;
+use std::collections::btree_set;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter::FromIterator;
+use std::mem;
+use std::slice;
+use std::vec;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+
+/// The largest number of `Tine`s stored in a sorted `Vec` before the
+/// `TineSet` upgrades to a `BTreeSet`, unless more space was reserved.
+const SMALL_CAPACITY: usize = 32;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TineSet
+////////////////////////////////////////////////////////////////////////////////
+/// An ordered set of `Tine`s.
+///
+/// Small sets are stored in a sorted `Vec`, which is faster to search and
+/// cheaper to clone than a `BTreeSet` for the handful of `Tine`s found in most
+/// `Selection`s. Once the set grows beyond `SMALL_CAPACITY` elements, or
+/// beyond the capacity reserved for its `Vec` if that is larger, it is
+/// upgraded to a `BTreeSet`, and it is downgraded again when removing a range
+/// leaves it at half of that size or less.
+///
+/// Equality and hashing depend only on the stored `Tine`s, not on the
+/// representation.
+#[derive(Clone)]
+pub(super) enum TineSet<T> {
+    /// A sorted `Vec` of unique `Tine`s.
+    Small(Vec<Tine<T>>),
+    /// A `BTreeSet` of `Tine`s.
+    Large(BTreeSet<Tine<T>>),
+}
+
+impl<T> TineSet<T> {
+    /// Constructs an empty `TineSet`.
+    pub(super) const fn new() -> Self {
+        Self::Small(Vec::new())
+    }
+
+    /// Constructs an empty `TineSet` with space for `capacity` `Tine`s. The
+    /// set remains a sorted `Vec` until it exceeds the reserved space.
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        Self::Small(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of `Tine`s the set can hold without allocating. A
+    /// `BTreeSet` allocates as it grows and cannot reserve space, so a
+    /// `Large` set can only hold the `Tine`s it already has.
+    pub(super) fn capacity(&self) -> usize {
+        match self {
+            Self::Small(tines) => tines.capacity(),
+            Self::Large(tines) => tines.len(),
+        }
+    }
+
+    /// Returns the number of `Tine`s in the set.
+    pub(super) fn len(&self) -> usize {
+        match self {
+            Self::Small(tines) => tines.len(),
+            Self::Large(tines) => tines.len(),
+        }
+    }
+
+    /// Returns `true` if the set contains no `Tine`s.
+    pub(super) fn is_empty(&self) -> bool {
+        match self {
+            Self::Small(tines) => tines.is_empty(),
+            Self::Large(tines) => tines.is_empty(),
+        }
+    }
+
+    /// Returns an iterator over the `Tine`s of the set in order.
+    pub(super) fn iter(&self) -> Iter<'_, T> {
+        match self {
+            Self::Small(tines) => Iter::Small(tines.iter()),
+            Self::Large(tines) => Iter::Large(tines.iter()),
+        }
+    }
+}
+
+impl<T> TineSet<T> where T: Ord + Clone {
+    /// Constructs a `TineSet` from `Tine`s in strictly ascending order.
+    pub(super) fn from_sorted(tines: Vec<Tine<T>>) -> Self {
+        debug_assert!(tines.windows(2).all(|pair| pair[0] < pair[1]));
+        if tines.len() > SMALL_CAPACITY {
+            Self::Large(tines.into_iter().collect())
+        } else {
+            Self::Small(tines)
+        }
+    }
+
+    /// Adds a `Tine` to the set, returning `false` if an equal `Tine` was
+    /// already present.
+    pub(super) fn insert(&mut self, tine: Tine<T>) -> bool {
+        let reserved = self.capacity();
+        let inserted = match self {
+            Self::Small(tines) => match tines.binary_search(&tine) {
+                Ok(_)  => false,
+                Err(idx) => { tines.insert(idx, tine); true },
+            },
+            Self::Large(tines) => tines.insert(tine),
+        };
+        self.upgrade(reserved);
+        inserted
+    }
+
+    /// Removes and returns the `Tine` equal to the given one, if any.
+    pub(super) fn take(&mut self, tine: &Tine<T>) -> Option<Tine<T>> {
+        match self {
+            Self::Small(tines) => tines
+                .binary_search(tine)
+                .ok()
+                .map(|idx| tines.remove(idx)),
+            Self::Large(tines) => tines.take(tine),
+        }
+    }
+
+    /// Removes all `Tine`s from the set. A `Small` set retains its allocated
+    /// storage, while a `Large` set releases its nodes, as a `BTreeSet`
+    /// cannot retain them.
+    pub(super) fn clear(&mut self) {
+        match self {
+            Self::Small(tines) => tines.clear(),
+            Self::Large(_)     => *self = Self::new(),
+        }
+    }
+
+    /// Returns the greatest `Tine` in the set less than the given one.
+    pub(super) fn before(&self, tine: &Tine<T>) -> Option<&Tine<T>> {
+        match self {
+            Self::Small(tines) => {
+                let idx = tines.partition_point(|t| t < tine);
+                idx.checked_sub(1).map(|idx| &tines[idx])
+            },
+            Self::Large(tines) => tines.range(..tine).next_back(),
+        }
+    }
+
+    /// Returns the least `Tine` in the set greater than or equal to the given
+    /// one.
+    pub(super) fn from(&self, tine: &Tine<T>) -> Option<&Tine<T>> {
+        match self {
+            Self::Small(tines) => {
+                let idx = tines.partition_point(|t| t < tine);
+                tines.get(idx)
+            },
+            Self::Large(tines) => tines.range(tine..).next(),
+        }
+    }
+
+    /// Returns an iterator over the `Tine`s of the set greater than or equal
+    /// to the given one, in order.
+    pub(super) fn iter_from(&self, tine: &Tine<T>) -> Iter<'_, T> {
+        match self {
+            Self::Small(tines) => {
+                let idx = tines.partition_point(|t| t < tine);
+                Iter::Small(tines[idx..].iter())
+            },
+            Self::Large(tines) => Iter::Range(tines.range(tine..)),
+        }
+    }
+
+    /// Returns an iterator over the `Tine`s of the set less than the given
+    /// one, in order.
+    pub(super) fn iter_to(&self, tine: &Tine<T>) -> Iter<'_, T> {
+        match self {
+            Self::Small(tines) => {
+                let idx = tines.partition_point(|t| t < tine);
+                Iter::Small(tines[..idx].iter())
+            },
+            Self::Large(tines) => Iter::Range(tines.range(..tine)),
+        }
+    }
+
+    /// Removes all `Tine`s greater than or equal to `lower` and less than
+    /// `upper`.
+    pub(super) fn remove_range(&mut self, lower: &Tine<T>, upper: &Tine<T>) {
+        match self {
+            Self::Small(tines) => {
+                let start = tines.partition_point(|t| t < lower);
+                let end = tines.partition_point(|t| t < upper);
+                let _ = tines.drain(start..end);
+            },
+            Self::Large(tines) => {
+                // Removing the tines one at a time avoids the allocations of
+                // splitting and rejoining the tree.
+                while let Some(tine) = tines.range(lower..upper).next() {
+                    let tine = tine.clone();
+                    let _ = tines.remove(&tine);
+                }
+            },
+        }
+        self.downgrade();
+    }
+
+    /// Inverts all `Tine`s greater than or equal to `lower` and less than
+    /// `upper`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `Tine`s are infinite.
+    pub(super) fn invert_range(&mut self, lower: &Tine<T>, upper: &Tine<T>) {
+        match self {
+            Self::Small(tines) => {
+                let start = tines.partition_point(|t| t < lower);
+                let end = tines.partition_point(|t| t < upper);
+                for tine in &mut tines[start..end] {
+                    *tine = tine.clone().invert();
+                }
+            },
+            Self::Large(tines) => {
+                // Inverting a `Tine` preserves its order, so the inverted
+                // `Tine`s can be appended back in place.
+                let mut center = tines.split_off(lower);
+                let mut above = center.split_off(upper);
+                tines.extend(center.into_iter().map(Tine::invert));
+                tines.append(&mut above);
+            },
+        }
+    }
+
+    /// Removes all `Tine`s less than `lower` or greater than or equal to
+    /// `upper`.
+    pub(super) fn retain_range(&mut self, lower: &Tine<T>, upper: &Tine<T>) {
+        match self {
+            Self::Small(tines) => {
+                let start = tines.partition_point(|t| t < lower);
+                let end = tines.partition_point(|t| t < upper);
+                tines.truncate(end);
+                let _ = tines.drain(..start);
+            },
+            Self::Large(tines) => {
+                let mut center = tines.split_off(lower);
+                let _ = center.split_off(upper);
+                *tines = center;
+            },
+        }
+        self.downgrade();
+    }
+
+    /// Removes and returns all `Tine`s greater than or equal to the given
+    /// one.
+    pub(super) fn split_off(&mut self, tine: &Tine<T>) -> Self {
+        let mut above = match self {
+            Self::Small(tines) => {
+                let start = tines.partition_point(|t| t < tine);
+                Self::Small(tines.split_off(start))
+            },
+            Self::Large(tines) => Self::Large(tines.split_off(tine)),
+        };
+        self.downgrade();
+        above.downgrade();
+        above
+    }
+
+    /// Moves all `Tine`s of the given set, which must all be greater than those
+    /// of this set, into this set.
+    pub(super) fn append(&mut self, other: Self) {
+        debug_assert!(match (self.iter().next_back(), other.iter().next()) {
+            (Some(last), Some(first)) => last < first,
+            _                         => true,
+        });
+        let reserved = self.capacity();
+        match (&mut *self, other) {
+            (Self::Small(tines), Self::Small(mut above)) => {
+                tines.append(&mut above);
+            },
+            (Self::Large(tines), Self::Large(mut above)) => {
+                tines.append(&mut above);
+            },
+            (Self::Large(tines), Self::Small(above)) => tines.extend(above),
+            (Self::Small(tines), Self::Large(mut above)) => {
+                above.extend(mem::take(tines));
+                *self = Self::Large(above);
+            },
+        }
+        self.upgrade(reserved);
+    }
+
+    /// Converts a `Small` set which has exceeded both `SMALL_CAPACITY` and the
+    /// given reserved capacity into a `Large` set.
+    fn upgrade(&mut self, reserved: usize) {
+        if let Self::Small(tines) = self {
+            if tines.len() > SMALL_CAPACITY.max(reserved) {
+                *self = Self::Large(mem::take(tines).into_iter().collect());
+            }
+        }
+    }
+
+    /// Converts a `Large` set with no more than half of `SMALL_CAPACITY`
+    /// elements into a `Small` set.
+    fn downgrade(&mut self) {
+        if let Self::Large(tines) = self {
+            if tines.len() <= SMALL_CAPACITY / 2 {
+                *self = Self::Small(mem::take(tines).into_iter().collect());
+            }
+        }
+    }
+}
+
+impl<T> Default for TineSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PartialEq for TineSet<T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for TineSet<T> where T: Eq {}
+
+impl<T> Hash for TineSet<T> where T: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for tine in self.iter() {
+            tine.hash(state);
+        }
+    }
+}
+
+impl<T> fmt::Debug for TineSet<T> where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<Tine<T>> for TineSet<T> where T: Ord + Clone {
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=Tine<T>>
+    {
+        let mut set = Self::new();
+        for tine in iter {
+            set.insert(tine);
+        }
+        set
+    }
+}
+
+impl<T> IntoIterator for TineSet<T> {
+    type Item = Tine<T>;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::Small(tines) => IntoIter::Small(tines.into_iter()),
+            Self::Large(tines) => IntoIter::Large(tines.into_iter()),
+        }
+    }
+}
+
+#[cfg(feature="serde")]
+impl<T> Serialize for TineSet<T> where T: Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature="serde")]
+impl<'de, T> Deserialize<'de> for TineSet<T>
+    where T: Ord + Clone + Deserialize<'de>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        BTreeSet::<Tine<T>>::deserialize(deserializer)
+            .map(|tines| tines.into_iter().collect())
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Tine`s of a `TineSet` in order.
+#[derive(Debug, Clone)]
+pub(super) enum Iter<'t, T> {
+    /// An iterator over a `Small` set.
+    Small(slice::Iter<'t, Tine<T>>),
+    /// An iterator over a `Large` set.
+    Large(btree_set::Iter<'t, Tine<T>>),
+    /// An iterator over a range of a `Large` set.
+    Range(btree_set::Range<'t, Tine<T>>),
+}
+
+impl<'t, T> Iterator for Iter<'t, T> {
+    type Item = &'t Tine<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(iter) => iter.next(),
+            Self::Large(iter) => iter.next(),
+            Self::Range(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Small(iter) => iter.size_hint(),
+            Self::Large(iter) => iter.size_hint(),
+            Self::Range(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(iter) => iter.next_back(),
+            Self::Large(iter) => iter.next_back(),
+            Self::Range(iter) => iter.next_back(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntoIter
+////////////////////////////////////////////////////////////////////////////////
+/// An owning `Iterator` over the `Tine`s of a `TineSet` in order.
+#[derive(Debug)]
+pub(super) enum IntoIter<T> {
+    /// An iterator over a `Small` set.
+    Small(vec::IntoIter<Tine<T>>),
+    /// An iterator over a `Large` set.
+    Large(btree_set::IntoIter<Tine<T>>),
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = Tine<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(iter) => iter.next(),
+            Self::Large(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Small(iter) => iter.size_hint(),
+            Self::Large(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(iter) => iter.next_back(),
+            Self::Large(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
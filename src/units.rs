@@ -0,0 +1,355 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides parsing of intervals whose points are written with unit suffixes,
+//! such as `[4k, 64k)` or `(10ms, 1s]`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::IntervalParseError;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::convert::TryFrom;
+use std::num::ParseIntError;
+use std::time::Duration;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Scale
+////////////////////////////////////////////////////////////////////////////////
+/// Provides multiplication of a unit by a whole-number magnitude. Used to
+/// compute the values parsed by a [`UnitTable`].
+///
+/// [`UnitTable`]: struct.UnitTable.html
+pub trait Scale: Sized {
+    /// Returns the unit multiplied by the given magnitude, or `None` if the
+    /// result is not representable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::units::Scale;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(1024u32.checked_scale(4), Some(4096));
+    /// assert_eq!(128u8.checked_scale(2), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn checked_scale(&self, magnitude: u64) -> Option<Self>;
+}
+
+/// Implements `Scale` for a builtin integer type by checked multiplication.
+macro_rules! std_integer_scale_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Scale for $t {
+            fn checked_scale(&self, magnitude: u64) -> Option<Self> {
+                $t::try_from(magnitude)
+                    .ok()
+                    .and_then(|magnitude| self.checked_mul(magnitude))
+            }
+        })*
+    };
+}
+
+// Provide implementations of Scale for builtin integer types.
+std_integer_scale_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+impl Scale for Duration {
+    fn checked_scale(&self, magnitude: u64) -> Option<Self> {
+        /// The number of nanoseconds in a second.
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let nanos = self.as_nanos().checked_mul(u128::from(magnitude))?;
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+        let subsec_nanos = u32::try_from(nanos % NANOS_PER_SEC).ok()?;
+        Some(Self::new(secs, subsec_nanos))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// UnitTable
+////////////////////////////////////////////////////////////////////////////////
+/// A table of unit suffixes used to parse values such as `4k` or `10ms`.
+///
+/// Each value is written as a whole-number magnitude followed by a suffix,
+/// optionally separated by whitespace. The suffix is matched exactly against
+/// the suffixes in the table, and the magnitude is multiplied by the
+/// corresponding unit. A value without a suffix is only accepted if the table
+/// contains the empty suffix.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::units::UnitTable;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let units = UnitTable::new()
+///     .with_unit("", 1u32)
+///     .with_unit("dozen", 12);
+///
+/// assert_eq!(units.parse_value("3 dozen")?, 36);
+/// assert_eq!(units.parse_interval("[1dozen, 30)").ok(),
+///     Some(Interval::closed(12, 29)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnitTable<T> {
+    /// The suffixes of the table and their corresponding units.
+    units: Vec<(String, T)>,
+}
+
+impl<T> UnitTable<T> {
+    /// Constructs an empty `UnitTable`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { units: Vec::new() }
+    }
+
+    /// Returns the `UnitTable` with the given suffix added, replacing any
+    /// existing unit for the suffix.
+    #[must_use]
+    pub fn with_unit(mut self, suffix: &str, unit: T) -> Self {
+        match self.units.iter_mut().find(|(s, _)| s == suffix) {
+            Some(entry) => entry.1 = unit,
+            None        => self.units.push((suffix.to_owned(), unit)),
+        }
+        self
+    }
+
+    /// Returns the unit for the given suffix, or `None` if the suffix is not
+    /// in the table.
+    #[must_use]
+    pub fn unit(&self, suffix: &str) -> Option<&T> {
+        self.units
+            .iter()
+            .find(|(s, _)| s == suffix)
+            .map(|(_, unit)| unit)
+    }
+}
+
+impl<T> UnitTable<T> where T: Scale {
+    /// Parses a value written as a magnitude followed by a unit suffix.
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitParseError`] if the magnitude is not a whole number,
+    /// the suffix is not in the table, or the value is not representable.
+    ///
+    /// [`UnitParseError`]: enum.UnitParseError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::time::Duration;
+    /// # use normalize_interval::units::UnitTable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let units = UnitTable::durations();
+    ///
+    /// assert_eq!(units.parse_value("250ms")?, Duration::from_millis(250));
+    /// assert_eq!(units.parse_value(" 2 h ")?, Duration::from_secs(7200));
+    /// assert!(units.parse_value("10").is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_value(&self, s: &str) -> Result<T, UnitParseError> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(s.len());
+        let (magnitude, suffix) = s.split_at(split);
+
+        let magnitude: u64 = magnitude
+            .parse()
+            .map_err(UnitParseError::InvalidMagnitude)?;
+        self.unit(suffix.trim_start())
+            .ok_or(UnitParseError::UnknownUnit)?
+            .checked_scale(magnitude)
+            .ok_or(UnitParseError::Overflow)
+    }
+
+    /// Parses an [`Interval`] written in interval notation, with each of its
+    /// points written as a magnitude followed by a unit suffix. Whitespace
+    /// around the points is ignored.
+    ///
+    /// [`Interval`]: ../interval/struct.Interval.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IntervalParseError`] if the text is not in interval
+    /// notation or one of its points could not be parsed.
+    ///
+    /// [`IntervalParseError`]: ../raw_interval/enum.IntervalParseError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::units::UnitTable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let units = UnitTable::bytes();
+    ///
+    /// assert_eq!(units.parse_interval("[4k, 64k)").ok(),
+    ///     Some(Interval::closed(4096, 65535)));
+    /// assert_eq!(units.parse_interval("[1MiB,∞)").ok(),
+    ///     Some(Interval::unbounded_from(1 << 20)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn parse_interval(&self, s: &str)
+        -> Result<Interval<T>, IntervalParseError<UnitParseError>>
+        where
+            T: Ord + Clone,
+            RawInterval<T>: Normalize,
+    {
+        RawInterval::parse_with(s.trim(), |point| self.parse_value(point))
+            .map(Interval::from)
+    }
+}
+
+impl UnitTable<u64> {
+    /// Constructs a `UnitTable` of byte sizes with binary multiples. Values
+    /// may be given without a suffix or with `B`, and the suffixes `k`, `K`,
+    /// and `KiB` denote 1024 bytes. `M`, `G`, `T`, `P`, and `E` and their
+    /// `MiB`-style forms denote the succeeding powers of 1024.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::units::UnitTable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let units = UnitTable::bytes();
+    ///
+    /// assert_eq!(units.parse_value("512")?, 512);
+    /// assert_eq!(units.parse_value("4k")?, 4096);
+    /// assert_eq!(units.parse_value("2 GiB")?, 2 << 30);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn bytes() -> Self {
+        let mut table = Self::new()
+            .with_unit("", 1)
+            .with_unit("B", 1)
+            .with_unit("k", 1 << 10);
+        for (i, prefix) in vec!['K', 'M', 'G', 'T', 'P', 'E']
+            .into_iter()
+            .enumerate()
+        {
+            let unit = 1 << (10 * (i + 1));
+            table = table
+                .with_unit(&prefix.to_string(), unit)
+                .with_unit(&format!("{prefix}iB"), unit);
+        }
+        table
+    }
+}
+
+impl UnitTable<Duration> {
+    /// Constructs a `UnitTable` of durations. The suffixes `ns`, `us`, `µs`,
+    /// `ms`, `s`, `m`, `min`, `h`, and `d` are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::time::Duration;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::units::UnitTable;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let units = UnitTable::durations();
+    ///
+    /// assert_eq!(units.parse_interval("(10ms, 1s]").ok(),
+    ///     Some(Interval::left_open(
+    ///         Duration::from_millis(10),
+    ///         Duration::from_secs(1))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn durations() -> Self {
+        Self::new()
+            .with_unit("ns", Duration::from_nanos(1))
+            .with_unit("us", Duration::from_micros(1))
+            .with_unit("µs", Duration::from_micros(1))
+            .with_unit("ms", Duration::from_millis(1))
+            .with_unit("s", Duration::from_secs(1))
+            .with_unit("m", Duration::from_mins(1))
+            .with_unit("min", Duration::from_mins(1))
+            .with_unit("h", Duration::from_hours(1))
+            .with_unit("d", Duration::from_hours(24))
+    }
+}
+
+impl<T> Default for UnitTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// UnitParseError
+////////////////////////////////////////////////////////////////////////////////
+/// Error type returned by failure to parse a value using a `UnitTable`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitParseError {
+    /// The magnitude of the value is not a whole number.
+    InvalidMagnitude(ParseIntError),
+    /// The suffix of the value is not in the `UnitTable`.
+    UnknownUnit,
+    /// The value is not representable.
+    Overflow,
+}
+
+impl std::fmt::Display for UnitParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagnitude(e) => write!(f, "invalid magnitude: {}", e),
+            Self::UnknownUnit         => write!(f, "unknown unit suffix"),
+            Self::Overflow            => write!(f, "value is too large"),
+        }
+    }
+}
+
+impl std::error::Error for UnitParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidMagnitude(e) => Some(e),
+            _                         => None,
+        }
+    }
+}
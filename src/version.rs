@@ -0,0 +1,231 @@
+// Copyright 2024 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides conversions from semantic version requirements to `Interval`s and
+//! `Selection`s of [`Version`]s. Requires the `semver` feature.
+//!
+//! Each comparator of a requirement such as `>=1.2, <1.5` denotes an
+//! `Interval` of versions, and the requirement denotes their intersection.
+//! Combining requirements is then a matter of `Selection` algebra: the
+//! versions satisfying two requirements are the intersection of their
+//! `Selection`s.
+//!
+//! Build metadata is ignored, so `=1.2.3` also contains `1.2.3+build`. An
+//! upper bound which excludes a release also excludes its pre-releases, so
+//! `^1.2.3` does not contain `2.0.0-alpha`. Pre-releases between the bounds
+//! of an `Interval` are contained, unlike in Cargo's version matching.
+//!
+//! [`Version`]: https://docs.rs/semver/1/semver/struct.Version.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use semver::BuildMetadata;
+use semver::Comparator;
+use semver::Op;
+use semver::Prerelease;
+use semver::Version;
+use semver::VersionReq;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalize implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// Versions are not `Finite`, so their intervals are already normalized.
+impl Normalize for RawInterval<Version> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Requirement conversions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a version requirement, such as `^1.2.3`, `~1.2`, or `>=1, <2`, into
+/// the `Selection` of versions satisfying it.
+///
+/// # Errors
+///
+/// Returns a [`semver::Error`] if the requirement could not be parsed.
+///
+/// [`semver::Error`]: https://docs.rs/semver/1/semver/struct.Error.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::version::parse_requirement;
+/// # use semver::Version;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let app = parse_requirement("^1.2")?;
+/// let lib = parse_requirement(">=1.4.1, <1.7")?;
+/// let both = app.intersect(&lib);
+///
+/// assert!(both.contains(&Version::parse("1.6.9")?));
+/// assert!(!both.contains(&Version::parse("1.7.0-rc.1")?));
+/// assert!(!both.contains(&Version::parse("1.3.0")?));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn parse_requirement(s: &str) -> Result<Selection<Version>, semver::Error> {
+    VersionReq::parse(s).map(|req| requirement(&req))
+}
+
+/// Returns the `Selection` of versions satisfying the given requirement. A
+/// requirement with no comparators is satisfied by every version.
+#[must_use]
+pub fn requirement(req: &VersionReq) -> Selection<Version> {
+    let mut selection = Selection::full();
+    for cmp in &req.comparators {
+        selection.intersect_in_place(comparator(cmp));
+    }
+    selection
+}
+
+/// Returns the `Interval` of versions satisfying the given comparator.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::version::comparator;
+/// # use semver::Comparator;
+/// # use semver::Version;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let tilde = comparator(&Comparator::parse("~1.2")?);
+///
+/// assert_eq!(tilde, Interval::right_open(
+///     Version::parse("1.2.0")?,
+///     Version::parse("1.3.0-0")?));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn comparator(cmp: &Comparator) -> Interval<Version> {
+    let (major, minor, patch) = (cmp.major, cmp.minor, cmp.patch);
+    let lower = || match (minor, patch) {
+        (Some(minor), Some(patch)) => {
+            let mut version = Version::new(major, minor, patch);
+            version.pre = cmp.pre.clone();
+            version
+        },
+        _ => Version::new(major, minor.unwrap_or(0), patch.unwrap_or(0)),
+    };
+    // The least version not matched by an exact comparator.
+    let exact_end = || match (minor, patch) {
+        (Some(_), Some(_)) => after(&lower()),
+        _                  => bump(major, minor, patch),
+    };
+
+    match cmp.op {
+        Op::Exact | Op::Wildcard => between(lower(), exact_end()),
+        Op::Greater => exact_end()
+            .map_or_else(Interval::empty, Interval::unbounded_from),
+        Op::GreaterEq => Interval::unbounded_from(lower()),
+        Op::Less => Interval::unbounded_up_to(pre_release_floor(lower())),
+        Op::LessEq => exact_end()
+            .map_or_else(Interval::full, Interval::unbounded_up_to),
+        Op::Tilde => between(lower(), bump(major, minor, None)),
+        Op::Caret => match (major, minor) {
+            (0, Some(0)) => between(lower(), bump(0, Some(0), patch)),
+            (0, Some(_)) => between(lower(), bump(0, minor, None)),
+            _            => between(lower(), bump(major, None, None)),
+        },
+        // Operators added to `semver` in the future are not understood, so
+        // they are conservatively assumed to match nothing.
+        _ => Interval::empty(),
+    }
+}
+
+/// Returns the `Interval` of versions matching `^version`.
+#[must_use]
+pub fn caret(version: &Version) -> Interval<Version> {
+    comparator(&full_comparator(Op::Caret, version))
+}
+
+/// Returns the `Interval` of versions matching `~version`.
+#[must_use]
+pub fn tilde(version: &Version) -> Interval<Version> {
+    comparator(&full_comparator(Op::Tilde, version))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Version helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a `Comparator` with the given operator and full version.
+fn full_comparator(op: Op, version: &Version) -> Comparator {
+    Comparator {
+        op,
+        major: version.major,
+        minor: Some(version.minor),
+        patch: Some(version.patch),
+        pre: version.pre.clone(),
+    }
+}
+
+/// Returns the `Interval` from `lower` up to `upper`, which is unbounded above
+/// if `upper` is `None`.
+fn between(lower: Version, upper: Option<Version>) -> Interval<Version> {
+    match upper {
+        Some(upper) => Interval::right_open(lower, upper),
+        None        => Interval::unbounded_from(lower),
+    }
+}
+
+/// Returns the least pre-release of the version, which precedes all of its
+/// other pre-releases.
+fn pre_release_floor(mut version: Version) -> Version {
+    if version.pre.is_empty() {
+        version.pre = Prerelease::new("0").expect("valid pre-release");
+    }
+    version.build = BuildMetadata::EMPTY;
+    version
+}
+
+/// Returns the least version greater than the given version when build
+/// metadata is ignored, or `None` if there is no such version.
+fn after(version: &Version) -> Option<Version> {
+    if version.pre.is_empty() {
+        return bump(version.major, Some(version.minor), Some(version.patch));
+    }
+    let pre = format!("{}.0", version.pre);
+    let mut next = Version::new(version.major, version.minor, version.patch);
+    next.pre = Prerelease::new(&pre).expect("valid pre-release");
+    Some(next)
+}
+
+/// Returns the least pre-release of the version after the given partial
+/// version, incrementing its last given component, or `None` if the component
+/// would overflow.
+fn bump(major: u64, minor: Option<u64>, patch: Option<u64>)
+    -> Option<Version>
+{
+    let version = match (minor, patch) {
+        (Some(minor), Some(patch)) =>
+            Version::new(major, minor, patch.checked_add(1)?),
+        (Some(minor), None) => Version::new(major, minor.checked_add(1)?, 0),
+        (None, _)           => Version::new(major.checked_add(1)?, 0, 0),
+    };
+    Some(pre_release_floor(version))
+}